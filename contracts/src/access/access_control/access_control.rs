@@ -36,6 +36,7 @@ use openbrush::{
         AccountId,
         DefaultEnv,
         Storage,
+        Timestamp,
     },
 };
 
@@ -44,6 +45,7 @@ use openbrush::{
 pub struct Data {
     pub admin_roles: Mapping<RoleType, RoleType, ValueGuard<RoleType>>,
     pub members: Mapping<(RoleType, Option<AccountId>), (), MembersKey>,
+    pub expirations: Mapping<(RoleType, Option<AccountId>), Timestamp, MembersKey>,
 }
 
 pub struct MembersKey;
@@ -87,9 +89,35 @@ pub trait AccessControlImpl: Internal + MembersManager + Sized {
         Ok(())
     }
 
+    #[modifiers(only_role(self.get_role_admin(role)))]
+    fn grant_role_until(
+        &mut self,
+        role: RoleType,
+        account: Option<AccountId>,
+        expires_at: Timestamp,
+    ) -> Result<(), AccessControlError> {
+        if expires_at <= Self::env().block_timestamp() {
+            return Err(AccessControlError::InvalidExpiration)
+        }
+        if self._has_role(role, &account) {
+            return Err(AccessControlError::RoleRedundant)
+        }
+        self._add(role, &account);
+        self._set_expiration(role, &account, Some(expires_at));
+        self._emit_role_granted(role, account, Some(Self::env().caller()));
+        Ok(())
+    }
+
     #[modifiers(only_role(self.get_role_admin(role)))]
     fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-        self._check_role(role, account)?;
+        // the expired grants are still stored, so they are removed too
+        let is_expired = self
+            ._get_expiration(role, &account)
+            .is_some_and(|expires_at| expires_at <= Self::env().block_timestamp());
+
+        if !is_expired {
+            self._check_role(role, account)?;
+        }
         self._do_revoke_role(role, account);
         Ok(())
     }
@@ -105,12 +133,24 @@ pub trait AccessControlImpl: Internal + MembersManager + Sized {
 }
 
 pub trait MembersManager {
+    /// Returns `true` if `address` has `role` and the grant is not expired.
     fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool;
 
+    /// Adds `member` to `role` without expiration.
     fn _add(&mut self, role: RoleType, member: &Option<AccountId>);
 
     fn _remove(&mut self, role: RoleType, member: &Option<AccountId>);
 
+    /// Returns the timestamp when the `role` of `member` expires, `None` if it never expires.
+    ///
+    /// Managers that don't store expirations can keep the default implementation, then the roles never expire.
+    fn _get_expiration(&self, _role: RoleType, _member: &Option<AccountId>) -> Option<Timestamp> {
+        None
+    }
+
+    /// Sets the timestamp when the `role` of `member` expires, `None` removes the expiration.
+    fn _set_expiration(&mut self, _role: RoleType, _member: &Option<AccountId>, _expires_at: Option<Timestamp>) {}
+
     fn _get_role_admin(&self, role: RoleType) -> Option<RoleType>;
 
     fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType);
@@ -118,15 +158,34 @@ pub trait MembersManager {
 
 pub trait MembersManagerImpl: Storage<Data> {
     fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
-        self.data().members.contains(&(role, address))
+        if !self.data().members.contains(&(role, address)) {
+            return false
+        }
+        match self.data().expirations.get(&(role, address)) {
+            Some(expires_at) => expires_at > Self::env().block_timestamp(),
+            None => true,
+        }
     }
 
     fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
         self.data().members.insert(&(role, member), &());
+        self.data().expirations.remove(&(role, member));
     }
 
     fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
         self.data().members.remove(&(role, member));
+        self.data().expirations.remove(&(role, member));
+    }
+
+    fn _get_expiration(&self, role: RoleType, member: &Option<AccountId>) -> Option<Timestamp> {
+        self.data().expirations.get(&(role, member))
+    }
+
+    fn _set_expiration(&mut self, role: RoleType, member: &Option<AccountId>, expires_at: Option<Timestamp>) {
+        match expires_at {
+            Some(expires_at) => self.data().expirations.insert(&(role, member), &expires_at),
+            None => self.data().expirations.remove(&(role, member)),
+        }
     }
 
     fn _get_role_admin(&self, role: RoleType) -> Option<RoleType> {
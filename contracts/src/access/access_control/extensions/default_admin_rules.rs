@@ -0,0 +1,320 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    access_control,
+    access_control::extensions::default_admin_rules,
+    traits::access_control::{
+        extensions::default_admin_rules::*,
+        *,
+    },
+};
+pub use access_control::{
+    only_role,
+    AccessControlImpl,
+    Internal as _,
+    InternalImpl as _,
+    DEFAULT_ADMIN_ROLE,
+};
+pub use default_admin_rules::Internal as _;
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+        Timestamp,
+    },
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub current_default_admin: Option<AccountId>,
+    #[lazy]
+    pub pending_default_admin: Option<AccountId>,
+    #[lazy]
+    pub pending_default_admin_schedule: Timestamp,
+    #[lazy]
+    pub current_delay: Timestamp,
+    #[lazy]
+    pub pending_delay: Timestamp,
+    #[lazy]
+    pub pending_delay_schedule: Timestamp,
+}
+
+/// The maximum time to wait before an increase of the default admin delay takes effect, 5 days.
+pub const DEFAULT_ADMIN_DELAY_INCREASE_WAIT: Timestamp = 5 * 24 * 60 * 60 * 1000;
+
+pub trait AccessControlDefaultAdminRulesImpl: Storage<Data> + Internal + AccessControlImpl {
+    fn default_admin(&self) -> Option<AccountId> {
+        self.data().current_default_admin.get_or_default()
+    }
+
+    fn pending_default_admin(&self) -> (Option<AccountId>, Timestamp) {
+        (
+            self.data().pending_default_admin.get_or_default(),
+            self.data().pending_default_admin_schedule.get_or_default(),
+        )
+    }
+
+    fn default_admin_delay(&self) -> Timestamp {
+        if Internal::_has_schedule_passed(self, self.data().pending_delay_schedule.get_or_default()) {
+            self.data().pending_delay.get_or_default()
+        } else {
+            self.data().current_delay.get_or_default()
+        }
+    }
+
+    fn pending_default_admin_delay(&self) -> (Timestamp, Timestamp) {
+        let schedule = self.data().pending_delay_schedule.get_or_default();
+
+        if schedule == 0 || Internal::_has_schedule_passed(self, schedule) {
+            (0, 0)
+        } else {
+            (self.data().pending_delay.get_or_default(), schedule)
+        }
+    }
+
+    #[modifiers(access_control::only_role(<Self as access_control::Internal>::_default_admin()))]
+    fn begin_default_admin_transfer(&mut self, new_admin: Option<AccountId>) -> Result<(), AccessControlError> {
+        let schedule = Self::env().block_timestamp().saturating_add(self.default_admin_delay());
+
+        self.data().pending_default_admin.set(&new_admin);
+        self.data().pending_default_admin_schedule.set(&schedule);
+        Internal::_emit_default_admin_transfer_scheduled(self, new_admin, schedule);
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(<Self as access_control::Internal>::_default_admin()))]
+    fn cancel_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+        self.data().pending_default_admin.set(&None);
+        self.data().pending_default_admin_schedule.set(&0);
+        Internal::_emit_default_admin_transfer_canceled(self);
+        Ok(())
+    }
+
+    fn accept_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+        let caller = Some(Self::env().caller());
+        let (new_admin, schedule) = self.pending_default_admin();
+
+        if new_admin != caller {
+            return Err(AccessControlError::InvalidDefaultAdmin)
+        }
+        if !Internal::_has_schedule_passed(self, schedule) {
+            return Err(AccessControlError::EnforcedDefaultAdminDelay)
+        }
+
+        let role = <Self as access_control::Internal>::_default_admin();
+        if let Some(old_admin) = self.data().current_default_admin.get_or_default() {
+            access_control::Internal::_do_revoke_role(self, role, Some(old_admin));
+        }
+        Internal::_setup_role_with_rules(self, role, new_admin)?;
+
+        self.data().pending_default_admin.set(&None);
+        self.data().pending_default_admin_schedule.set(&0);
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(<Self as access_control::Internal>::_default_admin()))]
+    fn change_default_admin_delay(&mut self, new_delay: Timestamp) -> Result<(), AccessControlError> {
+        let current_delay = self.default_admin_delay();
+        let schedule = Self::env()
+            .block_timestamp()
+            .saturating_add(Internal::_delay_change_wait(self, current_delay, new_delay));
+
+        self.data().current_delay.set(&current_delay);
+        self.data().pending_delay.set(&new_delay);
+        self.data().pending_delay_schedule.set(&schedule);
+        Internal::_emit_default_admin_delay_change_scheduled(self, new_delay, schedule);
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(<Self as access_control::Internal>::_default_admin()))]
+    fn rollback_default_admin_delay(&mut self) -> Result<(), AccessControlError> {
+        let current_delay = self.default_admin_delay();
+
+        self.data().current_delay.set(&current_delay);
+        self.data().pending_delay.set(&0);
+        self.data().pending_delay_schedule.set(&0);
+        Internal::_emit_default_admin_delay_change_canceled(self);
+        Ok(())
+    }
+
+    /// Replaces `AccessControl::grant_role`, the default admin role can't be granted.
+    fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        if role == <Self as access_control::Internal>::_default_admin() {
+            return Err(AccessControlError::EnforcedDefaultAdminRules)
+        }
+        AccessControlImpl::grant_role(self, role, account)
+    }
+
+    /// Replaces `AccessControl::grant_role_until`, the default admin role can't be granted.
+    fn grant_role_until(
+        &mut self,
+        role: RoleType,
+        account: Option<AccountId>,
+        expires_at: Timestamp,
+    ) -> Result<(), AccessControlError> {
+        if role == <Self as access_control::Internal>::_default_admin() {
+            return Err(AccessControlError::EnforcedDefaultAdminRules)
+        }
+        AccessControlImpl::grant_role_until(self, role, account, expires_at)
+    }
+
+    /// Replaces `AccessControl::revoke_role`, the default admin role can't be revoked.
+    fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        if role == <Self as access_control::Internal>::_default_admin() {
+            return Err(AccessControlError::EnforcedDefaultAdminRules)
+        }
+        AccessControlImpl::revoke_role(self, role, account)
+    }
+
+    /// Replaces `AccessControl::renounce_role`. The default admin can renounce the role only
+    /// after a transfer to `None` was started and its delay passed.
+    fn renounce_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        let is_default_admin =
+            role == <Self as access_control::Internal>::_default_admin() && account == self.default_admin();
+
+        if is_default_admin {
+            let (new_admin, schedule) = self.pending_default_admin();
+
+            if new_admin.is_some() || !Internal::_has_schedule_passed(self, schedule) {
+                return Err(AccessControlError::EnforcedDefaultAdminDelay)
+            }
+        }
+
+        AccessControlImpl::renounce_role(self, role, account)?;
+
+        if is_default_admin {
+            self.data().pending_default_admin_schedule.set(&0);
+        }
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// The user must override those methods using their event definition.
+    fn _emit_default_admin_transfer_scheduled(&self, new_admin: Option<AccountId>, accept_schedule: Timestamp);
+
+    fn _emit_default_admin_transfer_canceled(&self);
+
+    fn _emit_default_admin_delay_change_scheduled(&self, new_delay: Timestamp, effect_schedule: Timestamp);
+
+    fn _emit_default_admin_delay_change_canceled(&self);
+
+    /// Grants the default admin role to `admin` and sets the initial default admin delay.
+    ///
+    /// Must be used instead of `access_control::Internal::_init_with_admin`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DefaultAdminAlreadyGranted` error if another account is the default admin.
+    fn _init_with_default_admin(
+        &mut self,
+        admin: Option<AccountId>,
+        initial_delay: Timestamp,
+    ) -> Result<(), AccessControlError>;
+
+    /// Replaces `access_control::Internal::_setup_role`. The default admin role can be granted
+    /// only if there is no default admin, so the contract always has at most one of them.
+    ///
+    /// `access_control::Internal::_setup_role` can't return the error, so it doesn't grant
+    /// the second default admin.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DefaultAdminAlreadyGranted` error if the default admin role is granted
+    /// to an account while another account holds it.
+    fn _setup_role_with_rules(&mut self, role: RoleType, member: Option<AccountId>) -> Result<(), AccessControlError>;
+
+    /// Returns the maximum time to wait before an increase of the default admin delay takes effect.
+    fn _default_admin_delay_increase_wait() -> Timestamp;
+
+    /// Returns the time to wait before changing the delay from `current_delay` to `new_delay`.
+    fn _delay_change_wait(&self, current_delay: Timestamp, new_delay: Timestamp) -> Timestamp;
+
+    /// Returns `true` if `schedule` is set and has passed.
+    fn _has_schedule_passed(&self, schedule: Timestamp) -> bool;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + access_control::InternalImpl {
+    fn _emit_default_admin_transfer_scheduled(&self, _new_admin: Option<AccountId>, _accept_schedule: Timestamp) {}
+
+    fn _emit_default_admin_transfer_canceled(&self) {}
+
+    fn _emit_default_admin_delay_change_scheduled(&self, _new_delay: Timestamp, _effect_schedule: Timestamp) {}
+
+    fn _emit_default_admin_delay_change_canceled(&self) {}
+
+    fn _init_with_default_admin(
+        &mut self,
+        admin: Option<AccountId>,
+        initial_delay: Timestamp,
+    ) -> Result<(), AccessControlError> {
+        Internal::_setup_role_with_rules(self, <Self as access_control::Internal>::_default_admin(), admin)?;
+        self.data().current_delay.set(&initial_delay);
+        Ok(())
+    }
+
+    fn _setup_role_with_rules(&mut self, role: RoleType, member: Option<AccountId>) -> Result<(), AccessControlError> {
+        if role == <Self as access_control::Internal>::_default_admin() {
+            let current_default_admin = self.data().current_default_admin.get_or_default();
+
+            if current_default_admin.is_some() && current_default_admin != member {
+                return Err(AccessControlError::DefaultAdminAlreadyGranted)
+            }
+            self.data().current_default_admin.set(&member);
+        }
+
+        access_control::InternalImpl::_setup_role(self, role, member);
+        Ok(())
+    }
+
+    /// Replaces `access_control::Internal::_do_revoke_role`, clearing the default admin
+    /// if its role is revoked.
+    fn _do_revoke_role_with_rules(&mut self, role: RoleType, account: Option<AccountId>) {
+        if role == <Self as access_control::Internal>::_default_admin()
+            && account == self.data().current_default_admin.get_or_default()
+        {
+            self.data().current_default_admin.set(&None);
+        }
+
+        access_control::InternalImpl::_do_revoke_role(self, role, account)
+    }
+
+    fn _default_admin_delay_increase_wait() -> Timestamp {
+        DEFAULT_ADMIN_DELAY_INCREASE_WAIT
+    }
+
+    fn _delay_change_wait(&self, current_delay: Timestamp, new_delay: Timestamp) -> Timestamp {
+        if new_delay > current_delay {
+            new_delay.min(<Self as Internal>::_default_admin_delay_increase_wait())
+        } else {
+            current_delay - new_delay
+        }
+    }
+
+    fn _has_schedule_passed(&self, schedule: Timestamp) -> bool {
+        schedule != 0 && schedule <= Self::env().block_timestamp()
+    }
+}
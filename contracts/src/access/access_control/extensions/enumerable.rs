@@ -32,6 +32,7 @@ pub use access_control::{
     Internal as _,
     InternalImpl as _,
 };
+use ink::prelude::vec::Vec;
 use openbrush::{
     storage::{
         Mapping,
@@ -41,6 +42,7 @@ use openbrush::{
     traits::{
         AccountId,
         Storage,
+        Timestamp,
     },
 };
 
@@ -49,19 +51,39 @@ use openbrush::{
 pub struct Data {
     pub admin_roles: Mapping<RoleType, RoleType, ValueGuard<RoleType>>,
     pub role_members: MultiMapping<RoleType, Option<AccountId>, ValueGuard<RoleType>>,
+    pub expirations: Mapping<(RoleType, Option<AccountId>), Timestamp, access_control::MembersKey>,
 }
 
 pub trait MembersManagerImpl: Storage<Data> {
     fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
-        self.data().role_members.contains_value(role, address)
+        if !self.data().role_members.contains_value(role, address) {
+            return false
+        }
+        match self.data().expirations.get(&(role, address)) {
+            Some(expires_at) => expires_at > Self::env().block_timestamp(),
+            None => true,
+        }
     }
 
     fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
         self.data().role_members.insert(role, member);
+        self.data().expirations.remove(&(role, member));
     }
 
     fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
         self.data().role_members.remove_value(role, member);
+        self.data().expirations.remove(&(role, member));
+    }
+
+    fn _get_expiration(&self, role: RoleType, member: &Option<AccountId>) -> Option<Timestamp> {
+        self.data().expirations.get(&(role, member))
+    }
+
+    fn _set_expiration(&mut self, role: RoleType, member: &Option<AccountId>, expires_at: Option<Timestamp>) {
+        match expires_at {
+            Some(expires_at) => self.data().expirations.insert(&(role, member), &expires_at),
+            None => self.data().expirations.remove(&(role, member)),
+        }
     }
 
     fn _get_role_admin(&self, role: RoleType) -> Option<RoleType> {
@@ -73,15 +95,45 @@ pub trait MembersManagerImpl: Storage<Data> {
     }
 }
 
-pub trait AccessControlEnumerableImpl: Storage<Data> {
+pub trait AccessControlEnumerableImpl:
+    Storage<Data> + access_control::MembersManager + access_control::Internal
+{
     fn get_role_member(&self, role: RoleType, index: u32) -> Option<AccountId> {
-        self.data()
-            .role_members
-            .get_value(role, &(index as u128))
-            .unwrap_or(None)
+        _unexpired_members(self, role).nth(index as usize).unwrap_or(None)
     }
 
     fn get_role_member_count(&self, role: RoleType) -> u32 {
-        self.data().role_members.count(role) as u32
+        _unexpired_members(self, role).count() as u32
     }
+
+    fn prune_expired_role_members(&mut self, role: RoleType) -> u32 {
+        let now = Self::env().block_timestamp();
+        let mut expired = Vec::new();
+
+        for index in 0..self.data().role_members.count(role) {
+            if let Some(member) = self.data().role_members.get_value(role, &index) {
+                match self.data().expirations.get(&(role, &member)) {
+                    Some(expires_at) if expires_at <= now => expired.push(member),
+                    _ => {}
+                }
+            }
+        }
+
+        for member in expired.iter() {
+            access_control::MembersManager::_remove(self, role, member);
+            access_control::Internal::_emit_role_revoked(self, role, *member, Self::env().caller());
+        }
+        expired.len() as u32
+    }
+}
+
+/// Returns the members of `role` whose grant hasn't expired. The expired members are skipped
+/// until they are removed by `prune_expired_role_members`.
+fn _unexpired_members<T: Storage<Data>>(instance: &T, role: RoleType) -> impl Iterator<Item = Option<AccountId>> + '_ {
+    let now = T::env().block_timestamp();
+    let data: &Data = instance.data();
+
+    (0..data.role_members.count(role))
+        .filter_map(move |index| data.role_members.get_value(role, &index))
+        .filter(move |member| !matches!(data.expirations.get(&(role, member)), Some(expires_at) if expires_at <= now))
 }
@@ -20,6 +20,7 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub mod extensions {
+    pub mod default_admin_rules;
     pub mod enumerable;
 }
 
@@ -33,6 +33,8 @@ use openbrush::{
 };
 pub use ownable::Internal as _;
 
+pub mod ownable_2_step;
+
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
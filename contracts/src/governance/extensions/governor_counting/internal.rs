@@ -36,6 +36,7 @@ use crate::{
         },
     },
 };
+use ink::prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
     Balance,
@@ -70,7 +71,7 @@ pub trait CountingInternal: Storage<Data> + QuorumImpl + GovernorStorageGetters
         account: AccountId,
         support: VoteType,
         weight: Balance,
-        // params: Vec<u8>,
+        _params: Vec<u8>,
     ) -> Result<(), GovernanceError> {
         let mut proposal_vote = self.data::<Data>().proposal_votes.get(&proposal_id).unwrap_or_default();
 
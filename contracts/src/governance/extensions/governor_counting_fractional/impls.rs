@@ -0,0 +1,44 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::governance::extensions::governor_counting_fractional::Data;
+pub use crate::{
+    governance::extensions::governor_counting_fractional,
+    traits::governance::extensions::governor_counting_fractional::*,
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+
+/// Extension of `Governor` for fractional vote counting, where an account can split its weight
+/// between the `for`, `against` and `abstain` options in one or several votes.
+pub trait GovernorCountingFractionalImpl: Storage<Data> {
+    /// Returns the amount of votes that the account has already cast for the proposal
+    fn used_votes(&self, proposal_id: ProposalId, account: AccountId) -> Balance {
+        self.data::<Data>()
+            .used_votes
+            .get(&(proposal_id, account))
+            .unwrap_or_default()
+    }
+}
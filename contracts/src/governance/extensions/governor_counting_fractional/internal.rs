@@ -0,0 +1,123 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::{
+    governance::extensions::{
+        governor_counting,
+        governor_counting_fractional::Data,
+    },
+    traits::{
+        errors::GovernanceError,
+        governance::{
+            ProposalId,
+            ProposalVote,
+            VoteType,
+        },
+    },
+};
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+use scale::DecodeAll;
+
+pub trait CountingFractionalInternal: Storage<Data> + Storage<governor_counting::Data> {
+    /// Adds a `account`'s vote to `proposal_id` using at most `weight` votes in total.
+    ///
+    /// If `params` is empty, all remaining votes of the account are added to the `support` side.
+    /// Otherwise `params` must be an encoded `ProposalVote` with the amounts for each side, whose sum
+    /// must not exceed the remaining votes of the account, and `support` is ignored.
+    fn _count_vote_fractional(
+        &mut self,
+        proposal_id: ProposalId,
+        account: AccountId,
+        support: VoteType,
+        weight: Balance,
+        params: Vec<u8>,
+    ) -> Result<(), GovernanceError> {
+        let used_votes = self
+            .data::<Data>()
+            .used_votes
+            .get(&(proposal_id, account))
+            .unwrap_or_default();
+        let remaining_votes = weight.saturating_sub(used_votes);
+
+        if remaining_votes == 0 {
+            return Err(GovernanceError::AlreadyCastVote)
+        }
+
+        let vote = if params.is_empty() {
+            let mut vote = ProposalVote::default();
+            match support {
+                VoteType::Against => vote.against_votes = remaining_votes,
+                VoteType::For => vote.for_votes = remaining_votes,
+                VoteType::Abstain => vote.abstain_votes = remaining_votes,
+            }
+            vote
+        } else {
+            ProposalVote::decode_all(&mut params.as_slice()).map_err(|_| GovernanceError::InvalidVoteParams)?
+        };
+
+        let vote_weight = vote
+            .against_votes
+            .checked_add(vote.for_votes)
+            .and_then(|votes| votes.checked_add(vote.abstain_votes))
+            .ok_or(GovernanceError::Overflow)?;
+
+        if vote_weight > remaining_votes {
+            return Err(GovernanceError::ExceedsRemainingVotes)
+        }
+
+        let mut proposal_vote = self
+            .data::<governor_counting::Data>()
+            .proposal_votes
+            .get(&proposal_id)
+            .unwrap_or_default();
+
+        proposal_vote.against_votes = proposal_vote
+            .against_votes
+            .checked_add(vote.against_votes)
+            .ok_or(GovernanceError::Overflow)?;
+        proposal_vote.for_votes = proposal_vote
+            .for_votes
+            .checked_add(vote.for_votes)
+            .ok_or(GovernanceError::Overflow)?;
+        proposal_vote.abstain_votes = proposal_vote
+            .abstain_votes
+            .checked_add(vote.abstain_votes)
+            .ok_or(GovernanceError::Overflow)?;
+
+        self.data::<governor_counting::Data>()
+            .proposal_votes
+            .insert(&proposal_id, &proposal_vote);
+        self.data::<governor_counting::Data>()
+            .has_votes
+            .insert(&(proposal_id, account), &());
+        self.data::<Data>()
+            .used_votes
+            .insert(&(proposal_id, account), &(used_votes + vote_weight));
+
+        Ok(())
+    }
+}
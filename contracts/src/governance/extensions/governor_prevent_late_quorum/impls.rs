@@ -0,0 +1,54 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::governance::{
+    extensions::governor_prevent_late_quorum::{
+        Data,
+        GovernorPreventLateQuorumInternal,
+    },
+    governor::only_governance,
+};
+pub use crate::{
+    governance::extensions::governor_prevent_late_quorum,
+    traits::governance::extensions::governor_prevent_late_quorum::*,
+};
+use openbrush::{
+    modifiers,
+    traits::{
+        Storage,
+        Timestamp,
+    },
+};
+
+/// Extension of `Governor` that ensures there is a minimum voting period after quorum is reached.
+pub trait GovernorPreventLateQuorumImpl: Storage<Data> + GovernorPreventLateQuorumInternal {
+    /// Returns the current value of the vote extension parameter
+    fn late_quorum_vote_extension(&self) -> Timestamp {
+        self.data::<Data>().vote_extension.get_or_default()
+    }
+
+    /// Changes the vote extension parameter, which can only be done through a governance proposal
+    #[modifiers(only_governance)]
+    fn set_late_quorum_vote_extension(&mut self, new_vote_extension: Timestamp) -> Result<(), GovernanceError> {
+        self._set_late_quorum_vote_extension(new_vote_extension)
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::{
+    governance::{
+        extensions::{
+            governor_counting::CountingInternal,
+            governor_prevent_late_quorum::{
+                Data,
+                GovernorPreventLateQuorumEvents,
+            },
+        },
+        governor,
+        governor::{
+            GovernorEvents,
+            TimestampProvider,
+        },
+    },
+    traits::{
+        errors::GovernanceError,
+        governance::ProposalId,
+    },
+};
+use openbrush::traits::{
+    Storage,
+    Timestamp,
+};
+
+pub trait GovernorPreventLateQuorumInternal:
+    Storage<Data>
+    + Storage<governor::Data>
+    + GovernorPreventLateQuorumEvents
+    + GovernorEvents
+    + CountingInternal
+    + TimestampProvider
+{
+    /// Initializes the prevent late quorum extension
+    fn _init_prevent_late_quorum(&mut self, vote_extension: Timestamp) -> Result<(), GovernanceError> {
+        self._set_late_quorum_vote_extension(vote_extension)
+    }
+
+    /// Sets the vote extension parameter
+    fn _set_late_quorum_vote_extension(&mut self, new_vote_extension: Timestamp) -> Result<(), GovernanceError> {
+        let old_vote_extension = self.data::<Data>().vote_extension.get();
+        self.data::<Data>().vote_extension.set(&new_vote_extension);
+        self.emit_late_quorum_vote_extension_set(old_vote_extension, new_vote_extension);
+        Ok(())
+    }
+
+    /// Returns the deadline of the proposal, which may have been extended beyond the original one
+    /// if the proposal reached quorum late in the voting period
+    fn _extended_proposal_deadline(&self, proposal_id: ProposalId) -> Result<Timestamp, GovernanceError> {
+        let deadline = self
+            .data::<governor::Data>()
+            .proposals
+            .get(&proposal_id)
+            .ok_or(GovernanceError::ProposalNotFound)?
+            .deadline()?;
+        let extended_deadline = self
+            .data::<Data>()
+            .extended_deadlines
+            .get(&proposal_id)
+            .unwrap_or_default();
+
+        Ok(deadline.max(extended_deadline))
+    }
+
+    /// Extends the deadline of the proposal when the quorum is reached for the first time,
+    /// so that at least the vote extension is left for the voting
+    fn _extend_deadline_on_quorum(&mut self, proposal_id: ProposalId) -> Result<(), GovernanceError> {
+        if self.data::<Data>().extended_deadlines.contains(&proposal_id) || !self._quorum_reached(proposal_id)? {
+            return Ok(())
+        }
+
+        let deadline = self._extended_proposal_deadline(proposal_id)?;
+        let extended_deadline = TimestampProvider::block_timestamp(self)
+            .checked_add(self.data::<Data>().vote_extension.get_or_default())
+            .ok_or(GovernanceError::DeadlineOverflow)?;
+
+        if extended_deadline > deadline {
+            self.emit_proposal_extended(proposal_id, extended_deadline);
+        }
+
+        self.data::<Data>()
+            .extended_deadlines
+            .insert(&proposal_id, &extended_deadline);
+
+        Ok(())
+    }
+}
@@ -20,12 +20,11 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-pub use openbrush::utils::checkpoints::Checkpoints;
+pub use openbrush::storage::CheckpointsMapping;
 
 #[derive(Debug, Default)]
 #[openbrush::storage_item]
 pub struct Data {
     /// Stores the quorum numerator history of the governor
-    #[lazy]
-    pub quorum_numerator_history: Checkpoints,
+    pub quorum_numerator_history: CheckpointsMapping<()>,
 }
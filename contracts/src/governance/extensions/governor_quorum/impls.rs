@@ -59,12 +59,10 @@ pub trait QuorumImpl:
 
     /// Returns the current quorum numerator
     fn quorum_numerator(&self) -> u128 {
-        let history = self
+        let (exist, _, last_value) = self
             .data::<governor_quorum::Data>()
             .quorum_numerator_history
-            .get_or_default();
-
-        let (exist, _, last_value) = history.latest_checkpoint();
+            .latest_checkpoint(&());
 
         if exist {
             last_value
@@ -75,9 +73,9 @@ pub trait QuorumImpl:
 
     /// Returns the quorum numerator at a given timestamp
     fn quorum_numerator_at(&self, time_point: Timestamp) -> u128 {
-        let history = self.data::<Data>().quorum_numerator_history.get_or_default();
+        let history = &self.data::<Data>().quorum_numerator_history;
 
-        let (exist, timestamp, value) = history.latest_checkpoint();
+        let (exist, timestamp, value) = history.latest_checkpoint(&());
 
         if !exist {
             return self.quorum_numerator()
@@ -87,7 +85,7 @@ pub trait QuorumImpl:
             return value
         }
 
-        history.upper_lookup_recent(time_point).unwrap_or(0)
+        history.upper_lookup_recent(&(), time_point).unwrap_or(0)
     }
 
     /// Returns the current quorum denominator. May be overridden by a derived contract.
@@ -127,12 +125,10 @@ pub trait QuorumImpl:
         }
 
         let old_quorum_numerator = self.quorum_numerator();
-        let mut history = self.data::<Data>().quorum_numerator_history.get_or_default();
-
         let timestamp = TimestampProvider::block_timestamp(self);
-        history.push(timestamp, new_quorum_numerator.clone())?;
-
-        self.data::<Data>().quorum_numerator_history.set(&history);
+        self.data::<Data>()
+            .quorum_numerator_history
+            .push(&(), timestamp, new_quorum_numerator)?;
 
         self.emit_quorum_numerator_updated(old_quorum_numerator, new_quorum_numerator);
 
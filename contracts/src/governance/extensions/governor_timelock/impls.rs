@@ -0,0 +1,81 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::governance::{
+    extensions::governor_timelock::GovernorTimelockInternal,
+    governor::{
+        only_governance,
+        GovernorInternal,
+    },
+};
+pub use crate::{
+    governance::extensions::governor_timelock,
+    traits::governance::{
+        extensions::governor_timelock::*,
+        ProposalState,
+    },
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+
+/// Extension of `Governor` that binds the execution process to an instance of `TimelockController`.
+pub trait GovernorTimelockImpl: GovernorTimelockInternal + GovernorInternal {
+    /// Returns the address of the timelock
+    fn timelock(&self) -> Option<AccountId> {
+        self._timelock()
+    }
+
+    /// Returns the timestamp at which a queued proposal can be executed, or 0 if the proposal is not queued
+    fn proposal_eta(&self, proposal_id: ProposalId) -> Timestamp {
+        self._timelock_eta(proposal_id)
+    }
+
+    /// Queues a succeeded proposal to the timelock.
+    /// Returns the id of the queued proposal
+    fn queue(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: HashType,
+    ) -> Result<ProposalId, GovernanceError> {
+        let proposal_id = self._hash_proposal(transactions.clone(), description_hash)?;
+
+        if self._state(proposal_id)? != ProposalState::Succeeded {
+            return Err(GovernanceError::UnexpectedProposalState)
+        }
+
+        self._queue_operations(proposal_id, transactions, description_hash)?;
+
+        Ok(proposal_id)
+    }
+
+    /// Updates the timelock, which can only be done through a governance proposal
+    #[modifiers(only_governance)]
+    fn update_timelock(&mut self, new_timelock: AccountId) -> Result<(), GovernanceError> {
+        self._update_timelock(new_timelock)
+    }
+}
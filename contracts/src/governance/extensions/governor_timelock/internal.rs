@@ -0,0 +1,174 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::{
+    governance::{
+        extensions::governor_timelock::{
+            Data,
+            GovernorTimelockEvents,
+        },
+        governor,
+    },
+    traits::{
+        errors::GovernanceError,
+        governance::{
+            extensions::timelock_controller::TimelockControllerRef,
+            HashType,
+            OperationId,
+            ProposalId,
+            Transaction,
+        },
+    },
+};
+use ink::{
+    env::CallFlags,
+    prelude::vec::Vec,
+};
+use openbrush::traits::{
+    AccountId,
+    Storage,
+    Timestamp,
+};
+
+pub trait GovernorTimelockInternal {
+    /// Initializes the governor timelock extension
+    fn _init_governor_timelock(&mut self, timelock: AccountId) -> Result<(), GovernanceError>;
+
+    /// Sets the timelock as the executor of the governor
+    fn _update_timelock(&mut self, new_timelock: AccountId) -> Result<(), GovernanceError>;
+
+    /// Returns the address of the timelock
+    fn _timelock(&self) -> Option<AccountId>;
+
+    /// Returns the id of the timelock operation of the proposal, if it was queued
+    fn _timelock_id(&self, proposal_id: ProposalId) -> Option<OperationId>;
+
+    /// Returns the salt of the timelock operation, so that the same proposal of
+    /// different governors sharing the same timelock doesn't collide.
+    fn _timelock_salt(&self, description_hash: HashType) -> [u8; 32];
+
+    /// Returns the timestamp at which a queued proposal can be executed, or 0 if the proposal is not queued
+    fn _timelock_eta(&self, proposal_id: ProposalId) -> Timestamp;
+
+    /// Schedules the transactions of the proposal as a batch on the timelock
+    fn _queue_operations(
+        &mut self,
+        proposal_id: ProposalId,
+        transactions: Vec<Transaction>,
+        description_hash: HashType,
+    ) -> Result<Timestamp, GovernanceError>;
+
+    /// Executes the transactions of the queued proposal through the timelock
+    fn _execute_operations(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: HashType,
+    ) -> Result<(), GovernanceError>;
+}
+
+pub trait GovernorTimelockInternalImpl:
+    GovernorTimelockInternal + Storage<Data> + Storage<governor::Data> + GovernorTimelockEvents
+{
+    fn _init_governor_timelock(&mut self, timelock: AccountId) -> Result<(), GovernanceError> {
+        GovernorTimelockInternal::_update_timelock(self, timelock)
+    }
+
+    fn _update_timelock(&mut self, new_timelock: AccountId) -> Result<(), GovernanceError> {
+        let old_timelock = GovernorTimelockInternal::_timelock(self);
+        self.data::<governor::Data>().executor.set(&new_timelock);
+        self.emit_timelock_change(old_timelock, new_timelock);
+        Ok(())
+    }
+
+    fn _timelock(&self) -> Option<AccountId> {
+        self.data::<governor::Data>().executor.get()
+    }
+
+    fn _timelock_id(&self, proposal_id: ProposalId) -> Option<OperationId> {
+        self.data::<Data>().timelock_ids.get(&proposal_id)
+    }
+
+    fn _timelock_salt(&self, description_hash: HashType) -> [u8; 32] {
+        let mut salt: [u8; 32] = *Self::env().account_id().as_ref();
+
+        for (byte, hash_byte) in salt.iter_mut().zip(description_hash.iter()) {
+            *byte ^= hash_byte;
+        }
+
+        salt
+    }
+
+    fn _timelock_eta(&self, proposal_id: ProposalId) -> Timestamp {
+        match (
+            GovernorTimelockInternal::_timelock(self),
+            GovernorTimelockInternal::_timelock_id(self, proposal_id),
+        ) {
+            (Some(timelock), Some(id)) => {
+                let eta = TimelockControllerRef::get_timestamp(&timelock, id);
+                // The timelock returns 1 for the done operations
+                if eta == 1 {
+                    0
+                } else {
+                    eta
+                }
+            }
+            _ => 0,
+        }
+    }
+
+    fn _queue_operations(
+        &mut self,
+        proposal_id: ProposalId,
+        transactions: Vec<Transaction>,
+        description_hash: HashType,
+    ) -> Result<Timestamp, GovernanceError> {
+        let timelock = GovernorTimelockInternal::_timelock(self).ok_or(GovernanceError::TimelockNotSet)?;
+        let salt = GovernorTimelockInternal::_timelock_salt(self, description_hash);
+
+        let delay = TimelockControllerRef::get_min_delay(&timelock);
+        let id = TimelockControllerRef::hash_operation_batch(&timelock, transactions.clone(), None, salt);
+
+        self.data::<Data>().timelock_ids.insert(&proposal_id, &id);
+
+        TimelockControllerRef::schedule_batch_builder(&timelock, transactions, None, salt, delay).try_invoke()???;
+
+        let eta = GovernorTimelockInternal::_timelock_eta(self, proposal_id);
+        self.emit_proposal_queued(proposal_id, eta);
+
+        Ok(eta)
+    }
+
+    fn _execute_operations(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: HashType,
+    ) -> Result<(), GovernanceError> {
+        let timelock = GovernorTimelockInternal::_timelock(self).ok_or(GovernanceError::TimelockNotSet)?;
+        let salt = GovernorTimelockInternal::_timelock_salt(self, description_hash);
+
+        TimelockControllerRef::execute_batch_builder(&timelock, transactions, None, salt)
+            .call_flags(CallFlags::default().set_allow_reentry(true))
+            .try_invoke()???;
+
+        Ok(())
+    }
+}
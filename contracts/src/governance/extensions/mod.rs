@@ -21,7 +21,11 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub mod governor_counting;
+pub mod governor_counting_fractional;
+pub mod governor_prevent_late_quorum;
 pub mod governor_quorum;
 pub mod governor_settings;
+pub mod governor_timelock;
 pub mod governor_votes;
+pub mod multisig;
 pub mod timelock_controller;
@@ -0,0 +1,337 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    governance::governor::CallInput,
+    multisig,
+    traits::{
+        errors::MultisigError,
+        governance::extensions::multisig::*,
+    },
+};
+use ink::{
+    env::{
+        call::{
+            build_call,
+            Call,
+            ExecutionInput,
+        },
+        CallFlags,
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+};
+pub use multisig::Internal as _;
+use openbrush::{
+    modifier_definition,
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub owners: Vec<AccountId>,
+    #[lazy]
+    pub threshold: u32,
+    #[lazy]
+    pub next_transaction_id: TransactionId,
+    pub transactions: Mapping<TransactionId, Transaction>,
+    pub approvals: Mapping<(TransactionId, AccountId), ()>,
+}
+
+/// Throws if called by any account other than one of the owners.
+#[modifier_definition]
+pub fn only_multisig_owner<T, F, R, E>(instance: &mut T, body: F) -> Result<R, E>
+where
+    T: Storage<Data>,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<MultisigError>,
+{
+    if !instance.data().owners.get_or_default().contains(&T::env().caller()) {
+        return Err(From::from(MultisigError::CallerIsNotOwner))
+    }
+    body(instance)
+}
+
+/// Throws if called by any account other than the multisig itself.
+#[modifier_definition]
+pub fn only_multisig<T, F, R, E>(instance: &mut T, body: F) -> Result<R, E>
+where
+    T: Storage<Data>,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<MultisigError>,
+{
+    if T::env().account_id() != T::env().caller() {
+        return Err(From::from(MultisigError::CallerMustBeMultisig))
+    }
+    body(instance)
+}
+
+pub trait MultisigImpl: Internal + Storage<Data> {
+    fn owners(&self) -> Vec<AccountId> {
+        self.data().owners.get_or_default()
+    }
+
+    fn is_owner(&self, account: AccountId) -> bool {
+        self._is_owner(&account)
+    }
+
+    fn threshold(&self) -> u32 {
+        self.data().threshold.get_or_default()
+    }
+
+    fn transaction(&self, id: TransactionId) -> Option<Transaction> {
+        self.data().transactions.get(&id)
+    }
+
+    fn has_approved(&self, id: TransactionId, owner: AccountId) -> bool {
+        self.data().approvals.contains(&(id, owner))
+    }
+
+    fn approval_count(&self, id: TransactionId) -> u32 {
+        self._approval_count(id)
+    }
+
+    #[modifiers(only_multisig_owner)]
+    fn propose(&mut self, transaction: Transaction) -> Result<TransactionId, MultisigError> {
+        let id = self.data().next_transaction_id.get_or_default();
+        self.data().next_transaction_id.set(&(id + 1));
+        self.data().transactions.insert(&id, &transaction);
+
+        self._emit_transaction_proposed_event(id, transaction, Self::env().caller());
+        self._approve(id, Self::env().caller())?;
+        Ok(id)
+    }
+
+    #[modifiers(only_multisig_owner)]
+    fn approve(&mut self, id: TransactionId) -> Result<(), MultisigError> {
+        self._approve(id, Self::env().caller())
+    }
+
+    #[modifiers(only_multisig_owner)]
+    fn revoke(&mut self, id: TransactionId) -> Result<(), MultisigError> {
+        let caller = Self::env().caller();
+
+        if !self.data().transactions.contains(&id) {
+            return Err(MultisigError::TransactionNotFound)
+        }
+        if !self.data().approvals.contains(&(id, caller)) {
+            return Err(MultisigError::NotApproved)
+        }
+        self.data().approvals.remove(&(id, caller));
+
+        self._emit_approval_revoked_event(id, caller);
+        Ok(())
+    }
+
+    #[modifiers(only_multisig_owner)]
+    fn execute(&mut self, id: TransactionId) -> Result<(), MultisigError> {
+        let transaction = self
+            .data()
+            .transactions
+            .get(&id)
+            .ok_or(MultisigError::TransactionNotFound)?;
+
+        if self._approval_count(id) < self.data().threshold.get_or_default() {
+            return Err(MultisigError::NotEnoughApprovals)
+        }
+
+        // The transaction is removed before the call, so it can't be executed twice by reentrancy.
+        self.data().transactions.remove(&id);
+        self._call(id, transaction)
+    }
+
+    #[modifiers(only_multisig)]
+    fn add_owner(&mut self, owner: AccountId) -> Result<(), MultisigError> {
+        self._add_owner(owner)
+    }
+
+    #[modifiers(only_multisig)]
+    fn remove_owner(&mut self, owner: AccountId) -> Result<(), MultisigError> {
+        self._remove_owner(owner)
+    }
+
+    #[modifiers(only_multisig)]
+    fn change_threshold(&mut self, threshold: u32) -> Result<(), MultisigError> {
+        self._change_threshold(threshold)
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_owner_added_event(&self, owner: AccountId);
+
+    fn _emit_owner_removed_event(&self, owner: AccountId);
+
+    fn _emit_threshold_changed_event(&self, old_threshold: u32, new_threshold: u32);
+
+    fn _emit_transaction_proposed_event(&self, id: TransactionId, transaction: Transaction, proposer: AccountId);
+
+    fn _emit_transaction_approved_event(&self, id: TransactionId, owner: AccountId);
+
+    fn _emit_approval_revoked_event(&self, id: TransactionId, owner: AccountId);
+
+    fn _emit_transaction_executed_event(&self, id: TransactionId);
+
+    /// Initializes the multisig with `owners` and `threshold`.
+    fn _init(&mut self, owners: Vec<AccountId>, threshold: u32) -> Result<(), MultisigError>;
+
+    fn _is_owner(&self, account: &AccountId) -> bool;
+
+    /// Returns the number of approvals of the transaction given by current owners.
+    fn _approval_count(&self, id: TransactionId) -> u32;
+
+    /// Approves the transaction on behalf of `owner`.
+    ///
+    /// Emits a `TransactionApproved` event.
+    fn _approve(&mut self, id: TransactionId, owner: AccountId) -> Result<(), MultisigError>;
+
+    fn _add_owner(&mut self, owner: AccountId) -> Result<(), MultisigError>;
+
+    fn _remove_owner(&mut self, owner: AccountId) -> Result<(), MultisigError>;
+
+    fn _change_threshold(&mut self, threshold: u32) -> Result<(), MultisigError>;
+
+    /// Execute a transaction's call.
+    ///
+    /// Emits a `TransactionExecuted` event.
+    fn _call(&mut self, id: TransactionId, transaction: Transaction) -> Result<(), MultisigError>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _emit_owner_added_event(&self, _owner: AccountId) {}
+
+    fn _emit_owner_removed_event(&self, _owner: AccountId) {}
+
+    fn _emit_threshold_changed_event(&self, _old_threshold: u32, _new_threshold: u32) {}
+
+    fn _emit_transaction_proposed_event(&self, _id: TransactionId, _transaction: Transaction, _proposer: AccountId) {}
+
+    fn _emit_transaction_approved_event(&self, _id: TransactionId, _owner: AccountId) {}
+
+    fn _emit_approval_revoked_event(&self, _id: TransactionId, _owner: AccountId) {}
+
+    fn _emit_transaction_executed_event(&self, _id: TransactionId) {}
+
+    fn _init(&mut self, owners: Vec<AccountId>, threshold: u32) -> Result<(), MultisigError> {
+        for owner in owners {
+            Internal::_add_owner(self, owner)?;
+        }
+        Internal::_change_threshold(self, threshold)
+    }
+
+    fn _is_owner(&self, account: &AccountId) -> bool {
+        self.data().owners.get_or_default().contains(account)
+    }
+
+    fn _approval_count(&self, id: TransactionId) -> u32 {
+        self.data()
+            .owners
+            .get_or_default()
+            .into_iter()
+            .filter(|owner| self.data().approvals.contains(&(id, *owner)))
+            .count() as u32
+    }
+
+    fn _approve(&mut self, id: TransactionId, owner: AccountId) -> Result<(), MultisigError> {
+        if !self.data().transactions.contains(&id) {
+            return Err(MultisigError::TransactionNotFound)
+        }
+        if self.data().approvals.contains(&(id, owner)) {
+            return Err(MultisigError::AlreadyApproved)
+        }
+        self.data().approvals.insert(&(id, owner), &());
+
+        Internal::_emit_transaction_approved_event(self, id, owner);
+        Ok(())
+    }
+
+    fn _add_owner(&mut self, owner: AccountId) -> Result<(), MultisigError> {
+        let mut owners = self.data().owners.get_or_default();
+
+        if owners.contains(&owner) {
+            return Err(MultisigError::OwnerAlreadyExists)
+        }
+        owners.push(owner);
+        self.data().owners.set(&owners);
+
+        Internal::_emit_owner_added_event(self, owner);
+        Ok(())
+    }
+
+    fn _remove_owner(&mut self, owner: AccountId) -> Result<(), MultisigError> {
+        let mut owners = self.data().owners.get_or_default();
+
+        let index = owners
+            .iter()
+            .position(|account| account == &owner)
+            .ok_or(MultisigError::OwnerNotFound)?;
+        owners.swap_remove(index);
+
+        if (owners.len() as u32) < self.data().threshold.get_or_default() {
+            return Err(MultisigError::InvalidThreshold)
+        }
+        self.data().owners.set(&owners);
+
+        Internal::_emit_owner_removed_event(self, owner);
+        Ok(())
+    }
+
+    fn _change_threshold(&mut self, threshold: u32) -> Result<(), MultisigError> {
+        if threshold == 0 || threshold > self.data().owners.get_or_default().len() as u32 {
+            return Err(MultisigError::InvalidThreshold)
+        }
+
+        let old_threshold = self.data().threshold.get_or_default();
+        self.data().threshold.set(&threshold);
+
+        Internal::_emit_threshold_changed_event(self, old_threshold, threshold);
+        Ok(())
+    }
+
+    fn _call(&mut self, id: TransactionId, transaction: Transaction) -> Result<(), MultisigError> {
+        if let Some(callee) = transaction.callee {
+            build_call::<DefaultEnvironment>()
+                .call_type(
+                    Call::new(callee)
+                        .gas_limit(transaction.gas_limit)
+                        .transferred_value(transaction.transferred_value),
+                )
+                .exec_input(ExecutionInput::new(transaction.selector.into()).push_arg(CallInput(&transaction.input)))
+                .returns::<()>()
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .try_invoke()
+                .map_err(|_| MultisigError::UnderlyingTransactionReverted)?
+                .map_err(|_| MultisigError::UnderlyingTransactionReverted)?;
+
+            Internal::_emit_transaction_executed_event(self, id);
+            return Ok(())
+        }
+        Err(MultisigError::CalleeMustExist)
+    }
+}
@@ -32,6 +32,7 @@ pub use ink::prelude::collections::VecDeque;
 pub use openbrush::{
     storage::Mapping,
     traits::{
+        AccountId,
         Storage,
         Timestamp,
     },
@@ -46,6 +47,9 @@ pub struct Data {
     /// Stored the cross-contract calls that are executed when a proposal is approved
     #[lazy]
     pub governance_call: VecDeque<Transaction>,
+    /// Stores the executor of the proposals, if it is not the governor itself
+    #[lazy]
+    pub executor: AccountId,
 }
 
 /// A wrapper that allows us to encode a blob of bytes.
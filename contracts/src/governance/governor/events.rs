@@ -74,4 +74,7 @@ pub trait GovernorEvents {
         _params: Vec<u8>,
     ) {
     }
+
+    /// Emitted when the deadline of a proposal is extended
+    fn emit_proposal_extended(&self, _proposal_id: ProposalId, _extended_deadline: Timestamp) {}
 }
@@ -83,7 +83,9 @@ where
     F: FnOnce(&mut T) -> Result<R, E>,
     E: From<GovernanceError>,
 {
-    if T::env().caller() != T::env().account_id() {
+    let executor = instance.data().executor.get().unwrap_or(T::env().account_id());
+
+    if T::env().caller() != executor {
         return Err(GovernanceError::OnlyExecutor.into())
     }
 
@@ -128,11 +130,7 @@ pub trait GovernorImpl:
 
     /// Returns timestamp at which votes for a proposal ends
     fn proposal_deadline(&self, proposal_id: ProposalId) -> Result<Timestamp, GovernanceError> {
-        self.data::<Data>()
-            .proposals
-            .get(&proposal_id)
-            .ok_or(GovernanceError::ProposalNotFound)?
-            .deadline()
+        self._proposal_deadline(proposal_id)
     }
 
     /// Returns the AccountId of the proposer of a proposal
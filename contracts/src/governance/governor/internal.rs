@@ -70,6 +70,7 @@ use openbrush::{
         DefaultEnv,
         Storage,
         String,
+        Timestamp,
     },
     utils::crypto,
 };
@@ -113,13 +114,16 @@ pub trait GovernorInternal:
             return Ok(ProposalState::Pending)
         }
 
-        let deadline = proposal.deadline()?;
+        let deadline = self._proposal_deadline(proposal_id)?;
 
         if deadline >= current_time {
             return Ok(ProposalState::Active)
         }
 
         if self._vote_succeeded(proposal_id.clone()) && self._quorum_reached(proposal_id.clone())? {
+            if self._proposal_eta(proposal_id) != 0 {
+                return Ok(ProposalState::Queued)
+            }
             Ok(ProposalState::Succeeded)
         } else {
             Ok(ProposalState::Defeated)
@@ -250,7 +254,15 @@ pub trait GovernorInternal:
         let snapshot = self._proposal_snapshot(proposal_id.clone())?;
         let weight = self._get_votes(account.clone(), snapshot, params.clone())?;
 
-        self._count_vote(proposal_id.clone(), account.clone(), support.clone(), weight.clone())?;
+        self._count_vote(
+            proposal_id.clone(),
+            account.clone(),
+            support.clone(),
+            weight.clone(),
+            params.clone(),
+        )?;
+
+        self._after_cast_vote(proposal_id)?;
 
         if params.len() == 0 {
             self.emit_vote_cast(proposal_id.clone(), account.clone(), support, weight.clone(), reason);
@@ -270,7 +282,32 @@ pub trait GovernorInternal:
 
     /// Returns the AccountId of the executor.
     fn _executor(&self) -> AccountId {
-        Self::env().account_id()
+        self.data::<Data>().executor.get().unwrap_or(Self::env().account_id())
+    }
+
+    /// Returns the timestamp at which voting on a proposal ends.
+    ///
+    /// Extensions that extend the voting period, like `GovernorPreventLateQuorum`, override this function.
+    fn _proposal_deadline(&self, proposal_id: ProposalId) -> Result<Timestamp, GovernanceError> {
+        self.data::<Data>()
+            .proposals
+            .get(&proposal_id)
+            .ok_or(GovernanceError::ProposalNotFound)?
+            .deadline()
+    }
+
+    /// Hook that is called after a vote is counted.
+    ///
+    /// Extensions that react to the votes, like `GovernorPreventLateQuorum`, override this function.
+    fn _after_cast_vote(&mut self, _proposal_id: ProposalId) -> Result<(), GovernanceError> {
+        Ok(())
+    }
+
+    /// Returns the timestamp at which a queued proposal can be executed, or 0 if the proposal is not queued.
+    ///
+    /// Extensions that queue the proposals, like `GovernorTimelock`, override this function.
+    fn _proposal_eta(&self, _proposal_id: ProposalId) -> Timestamp {
+        0
     }
 
     /// Checks if the `description` is valid for the `proposer`.
@@ -20,6 +20,8 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+#[cfg(feature = "multisig")]
+pub use extensions::multisig;
 #[cfg(feature = "timelock_controller")]
 pub use extensions::timelock_controller;
 
@@ -27,11 +29,19 @@ pub mod extensions {
     #[cfg(feature = "governance")]
     pub mod governor_counting;
     #[cfg(feature = "governance")]
+    pub mod governor_counting_fractional;
+    #[cfg(feature = "governance")]
+    pub mod governor_prevent_late_quorum;
+    #[cfg(feature = "governance")]
     pub mod governor_quorum;
     #[cfg(feature = "governance")]
     pub mod governor_settings;
     #[cfg(feature = "governance")]
+    pub mod governor_timelock;
+    #[cfg(feature = "governance")]
     pub mod governor_votes;
+    #[cfg(feature = "multisig")]
+    pub mod multisig;
     #[cfg(feature = "timelock_controller")]
     pub mod timelock_controller;
 }
@@ -21,9 +21,11 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use openbrush::{
-    storage::Mapping,
+    storage::{
+        CheckpointsMapping,
+        Mapping,
+    },
     traits::AccountId,
-    utils::checkpoints::Checkpoints,
 };
 
 #[derive(Default, Debug)]
@@ -33,9 +35,8 @@ pub struct Data {
     /// The key is the delegator and the value is the delegatee
     pub delegation: Mapping<Option<AccountId>, AccountId>,
     /// Stores the checkpoints of the delegations of the governor
-    /// The key is the delegator and the value is the checkpoints of the delegatee
-    pub delegate_checkpoints: Mapping<AccountId, Checkpoints>,
+    /// The key is the delegatee and the value is its checkpoints
+    pub delegate_checkpoints: CheckpointsMapping<AccountId>,
     /// Stores the checkpoints of the total delegations of the governor
-    #[lazy]
-    pub total_checkpoints: Checkpoints,
+    pub total_checkpoints: CheckpointsMapping<()>,
 }
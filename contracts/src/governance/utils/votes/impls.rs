@@ -47,11 +47,7 @@ use scale::Encode;
 pub trait VotesImpl: Storage<Data> + VotesInternal + nonces::NoncesImpl + VotesEvents + TimestampProvider {
     /// The amount of votes owned by `account`.
     fn get_votes(&self, account: AccountId) -> Balance {
-        self.data::<Data>()
-            .delegate_checkpoints
-            .get(&account)
-            .unwrap_or_default()
-            .latest()
+        self.data::<Data>().delegate_checkpoints.latest(&account)
     }
 
     /// The amount of votes delegated to `account` at the time `timestamp`.
@@ -63,9 +59,7 @@ pub trait VotesImpl: Storage<Data> + VotesInternal + nonces::NoncesImpl + VotesE
         match self
             .data::<Data>()
             .delegate_checkpoints
-            .get(&account)
-            .unwrap_or_default()
-            .upper_lookup_recent(timestamp)
+            .upper_lookup_recent(&account, timestamp)
         {
             Some(value) => Ok(value),
             None => Ok(0),
@@ -79,8 +73,11 @@ pub trait VotesImpl: Storage<Data> + VotesInternal + nonces::NoncesImpl + VotesE
             return Err(GovernanceError::FutureLookup)
         }
 
-        let checkpoints = &self.data::<Data>().total_checkpoints.get_or_default();
-        match checkpoints.upper_lookup_recent(timestamp) {
+        match self
+            .data::<Data>()
+            .total_checkpoints
+            .upper_lookup_recent(&(), timestamp)
+        {
             Some(value) => Ok(value),
             None => Ok(0),
         }
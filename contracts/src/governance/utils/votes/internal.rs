@@ -36,17 +36,13 @@ use openbrush::{
         Balance,
         Storage,
     },
-    utils::checkpoints::{
-        Checkpoint,
-        Checkpoints,
-        CheckpointsError,
-    },
+    utils::checkpoints::Checkpoint,
 };
 
 pub trait VotesInternal: Storage<Data> + VotesEvents + TimestampProvider {
     /// Returns the total number of votes.
     fn _get_total_supply(&self) -> Balance {
-        self.data::<Data>().total_checkpoints.get_or_default().latest()
+        self.data::<Data>().total_checkpoints.latest(&())
     }
 
     /// Returns the address delegated to by `delegator`.
@@ -82,12 +78,11 @@ pub trait VotesInternal: Storage<Data> + VotesEvents + TimestampProvider {
         to: &Option<AccountId>,
         amount: Balance,
     ) -> Result<(), GovernanceError> {
-        let mut store = self.data::<Data>().total_checkpoints.get_or_default();
         if from.is_none() {
-            self._push(&mut store, Self::_add, amount)?;
+            self._push_total(Self::_add, amount)?;
         }
         if to.is_none() {
-            self._push(&mut store, Self::_sub, amount)?;
+            self._push_total(Self::_sub, amount)?;
         }
         self._move_delegate_votes(&self._delegates(from), &self._delegates(to), amount)
     }
@@ -101,25 +96,11 @@ pub trait VotesInternal: Storage<Data> + VotesEvents + TimestampProvider {
     ) -> Result<(), GovernanceError> {
         if from != to && amount > 0 {
             if let Some(from_addr) = from {
-                let mut store = self
-                    .data::<Data>()
-                    .delegate_checkpoints
-                    .get(&from_addr)
-                    .unwrap_or_default();
-
-                let (old_value, new_value) = self._push(&mut store, Self::_sub, amount)?;
-                self.data::<Data>().delegate_checkpoints.insert(&from_addr, &store);
+                let (old_value, new_value) = self._push_delegate(from_addr, Self::_sub, amount)?;
                 self.emit_delegate_votes_changed_event(&from_addr, old_value, new_value);
             }
             if let Some(to_addr) = to {
-                let mut store = self
-                    .data::<Data>()
-                    .delegate_checkpoints
-                    .get(&to_addr)
-                    .unwrap_or_default();
-
-                let (old_value, new_value) = self._push(&mut store, Self::_add, amount)?;
-                self.data::<Data>().delegate_checkpoints.insert(&to_addr, &store);
+                let (old_value, new_value) = self._push_delegate(to_addr, Self::_add, amount)?;
                 self.emit_delegate_votes_changed_event(&to_addr, old_value, new_value);
             }
         }
@@ -128,35 +109,42 @@ pub trait VotesInternal: Storage<Data> + VotesEvents + TimestampProvider {
 
     /// Returns number of checkpoints for `account`.
     fn _num_checkpoints(&self, account: &AccountId) -> u32 {
-        self.data::<Data>()
-            .delegate_checkpoints
-            .get(&account)
-            .unwrap_or_default()
-            .len() as u32
+        self.data::<Data>().delegate_checkpoints.len(account)
     }
 
     /// Returns the checkpoint for `account` at the given `pos`.
     fn _checkpoints(&self, account: &AccountId, pos: u32) -> Result<Checkpoint, GovernanceError> {
         self.data::<Data>()
             .delegate_checkpoints
-            .get(&account)
-            .unwrap_or_default()
-            .at(pos as usize)
+            .at(account, pos)
             .ok_or(GovernanceError::IndexOutOfRange)
-            .cloned()
     }
 
     /// Creates a new checkpoint for `account` and returns its `old_value` and `new_value`.
-    fn _push(
+    fn _push_delegate(
         &mut self,
-        store: &mut Checkpoints,
+        account: &AccountId,
         op: fn(u128, u128) -> Result<u128, GovernanceError>,
         delta: Balance,
     ) -> Result<(u128, u128), GovernanceError> {
-        let (old_value, new_value) = store
-            .push(TimestampProvider::block_timestamp(self), op(store.latest(), delta)?)
-            .map_err(|err| <CheckpointsError as Into<GovernanceError>>::into(err))?;
-        Ok((old_value, new_value))
+        let timestamp = TimestampProvider::block_timestamp(self);
+        let store = &mut self.data::<Data>().delegate_checkpoints;
+        let new_value = op(store.latest(account), delta)?;
+
+        Ok(store.push(account, timestamp, new_value)?)
+    }
+
+    /// Creates a new checkpoint for the total supply and returns its `old_value` and `new_value`.
+    fn _push_total(
+        &mut self,
+        op: fn(u128, u128) -> Result<u128, GovernanceError>,
+        delta: Balance,
+    ) -> Result<(u128, u128), GovernanceError> {
+        let timestamp = TimestampProvider::block_timestamp(self);
+        let store = &mut self.data::<Data>().total_checkpoints;
+        let new_value = op(store.latest(&()), delta)?;
+
+        Ok(store.push(&(), timestamp, new_value)?)
     }
 
     fn _add(a: u128, b: u128) -> Result<u128, GovernanceError> {
@@ -39,6 +39,8 @@ pub use access::access_control;
 pub use access::ownable;
 #[cfg(feature = "payment_splitter")]
 pub use finance::payment_splitter;
+#[cfg(feature = "multisig")]
+pub use governance::multisig;
 #[cfg(feature = "timelock_controller")]
 pub use governance::timelock_controller;
 #[cfg(feature = "governance")]
@@ -57,6 +59,8 @@ pub use token::psp34;
 pub use token::psp37;
 #[cfg(feature = "diamond")]
 pub use upgradeability::diamond;
+#[cfg(feature = "initializable")]
+pub use upgradeability::initializable;
 #[cfg(feature = "proxy")]
 pub use upgradeability::proxy;
 #[cfg(feature = "upgradeable")]
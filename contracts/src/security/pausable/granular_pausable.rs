@@ -0,0 +1,147 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::access_control;
+pub use crate::{
+    pausable,
+    pausable::granular_pausable,
+    traits::pausable::{
+        granular_pausable::*,
+        *,
+    },
+};
+pub use access_control::{
+    only_role,
+    RoleType,
+};
+pub use granular_pausable::{
+    GranularPausableImpl as _,
+    Internal as _,
+    InternalImpl as _,
+};
+use openbrush::{
+    modifier_definition,
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+/// The role that is allowed to pause and unpause the features.
+pub const PAUSER_ROLE: RoleType = ink::selector_id!("PAUSER_ROLE");
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub paused_features: Mapping<Feature, ()>,
+}
+
+/// Modifier to make a function callable only when the `feature` is paused.
+#[modifier_definition]
+pub fn when_paused_feature<T, F, R, E>(instance: &mut T, body: F, feature: Feature) -> Result<R, E>
+where
+    T: Internal,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<PausableError>,
+{
+    if !instance._feature_paused(feature) {
+        return Err(From::from(PausableError::NotPaused))
+    }
+    body(instance)
+}
+
+/// Modifier to make a function callable only when the `feature` is not paused.
+#[modifier_definition]
+pub fn when_not_paused_feature<T, F, R, E>(instance: &mut T, body: F, feature: Feature) -> Result<R, E>
+where
+    T: Internal,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<PausableError>,
+{
+    if instance._feature_paused(feature) {
+        return Err(From::from(PausableError::Paused))
+    }
+    body(instance)
+}
+
+pub trait GranularPausableImpl: Internal + access_control::Internal + Sized {
+    fn feature_paused(&self, feature: Feature) -> bool {
+        self._feature_paused(feature)
+    }
+
+    #[modifiers(only_role(PAUSER_ROLE))]
+    fn pause_feature(&mut self, feature: Feature) -> Result<(), GranularPausableError> {
+        Ok(self._pause_feature(feature)?)
+    }
+
+    #[modifiers(only_role(PAUSER_ROLE))]
+    fn unpause_feature(&mut self, feature: Feature) -> Result<(), GranularPausableError> {
+        Ok(self._unpause_feature(feature)?)
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_feature_paused_event(&self, _feature: Feature, _account: AccountId);
+
+    fn _emit_feature_unpaused_event(&self, _feature: Feature, _account: AccountId);
+
+    /// Returns true if the `feature` is paused. Can be overridden to take
+    /// the global pause of the contract into account.
+    fn _feature_paused(&self, feature: Feature) -> bool;
+
+    /// Triggers stopped state of the `feature`.
+    ///
+    /// On success a `FeaturePaused` event is emitted.
+    fn _pause_feature(&mut self, feature: Feature) -> Result<(), PausableError>;
+
+    /// Returns the `feature` to normal state.
+    ///
+    /// On success a `FeatureUnpaused` event is emitted.
+    fn _unpause_feature(&mut self, feature: Feature) -> Result<(), PausableError>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal {
+    fn _emit_feature_paused_event(&self, _feature: Feature, _account: AccountId) {}
+
+    fn _emit_feature_unpaused_event(&self, _feature: Feature, _account: AccountId) {}
+
+    fn _feature_paused(&self, feature: Feature) -> bool {
+        self.data().paused_features.contains(&feature)
+    }
+
+    #[modifiers(when_not_paused_feature(feature))]
+    fn _pause_feature(&mut self, feature: Feature) -> Result<(), PausableError> {
+        self.data().paused_features.insert(&feature, &());
+        Internal::_emit_feature_paused_event(self, feature, Self::env().caller());
+        Ok(())
+    }
+
+    #[modifiers(when_paused_feature(feature))]
+    fn _unpause_feature(&mut self, feature: Feature) -> Result<(), PausableError> {
+        self.data().paused_features.remove(&feature);
+        Internal::_emit_feature_unpaused_event(self, feature, Self::env().caller());
+        Ok(())
+    }
+}
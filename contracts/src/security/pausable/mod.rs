@@ -37,6 +37,9 @@ pub use pausable::{
     PausableImpl as _,
 };
 
+#[cfg(feature = "access_control")]
+pub mod granular_pausable;
+
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
@@ -21,13 +21,14 @@
 
 pub use crate::{
     reentrancy_guard,
-    traits::errors::ReentrancyGuardError,
+    traits::reentrancy_guard::*,
 };
 use ink::storage::traits::Storable;
 use openbrush::{
     modifier_definition,
     traits::Storage,
 };
+pub use reentrancy_guard::ReentrancyGuardImpl as _;
 
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
@@ -65,3 +66,25 @@ where
 
     result
 }
+
+/// Prevents reading the state of a contract while a `non_reentrant` function is executing,
+/// e.g. from a callback of a cross-contract call. It is the `&self` version of `non_reentrant`,
+/// so it only checks the status without changing it.
+#[modifier_definition]
+pub fn non_reentrant_view<T, F, R, E>(instance: &T, body: F) -> Result<R, E>
+where
+    T: Storage<Data>,
+    F: FnOnce(&T) -> Result<R, E>,
+    E: From<ReentrancyGuardError>,
+{
+    if instance.data().status.get_or_default() == ENTERED {
+        return Err(From::from(ReentrancyGuardError::ReentrantCall))
+    }
+    body(instance)
+}
+
+pub trait ReentrancyGuardImpl: Storage<Data> {
+    fn reentrancy_guard_entered(&self) -> bool {
+        self.data().status.get_or_default() == ENTERED
+    }
+}
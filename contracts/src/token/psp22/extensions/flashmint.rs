@@ -112,9 +112,7 @@ pub trait InternalImpl: Storage<psp22::Data> + Internal {
                 .call_flags(CallFlags::default().set_allow_reentry(true));
         let result = match builder.try_invoke() {
             Ok(Ok(Ok(_))) => Ok(()),
-            Ok(Ok(Err(FlashBorrowerError::FlashloanRejected(message)))) => {
-                Err(FlashLenderError::BorrowerRejected(message))
-            }
+            Ok(Ok(Err(error))) => Err(error.into()),
             // Means unknown method
             Ok(Err(ink::LangError::CouldNotReadInput)) => Ok(()),
             // `NotCallable` means that the receiver is not a contract.
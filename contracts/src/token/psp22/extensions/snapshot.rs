@@ -0,0 +1,186 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::snapshot,
+    traits::psp22::{
+        extensions::snapshot::*,
+        *,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        String,
+    },
+    utils::checkpoints::Checkpoints,
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+pub use snapshot::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub current_snapshot_id: SnapshotId,
+    /// The key is the account and the value is the history of its balance,
+    /// where the key of each checkpoint is the snapshot id.
+    pub account_balance_snapshots: Mapping<AccountId, Checkpoints>,
+    #[lazy]
+    pub total_supply_snapshots: Checkpoints,
+}
+
+pub trait PSP22SnapshotImpl: Internal {
+    fn balance_of_at(&self, account: AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+        self._balance_of_at(&account, snapshot_id)
+    }
+
+    fn total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+        self._total_supply_at(snapshot_id)
+    }
+
+    fn current_snapshot_id(&self) -> SnapshotId {
+        self._current_snapshot_id()
+    }
+}
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_snapshot_event(&self, _id: SnapshotId);
+
+    /// Creates a new snapshot and returns its id.
+    ///
+    /// Balances are not copied at this point, they are recorded lazily
+    /// the first time they change after the snapshot.
+    fn _snapshot(&mut self) -> SnapshotId;
+
+    fn _current_snapshot_id(&self) -> SnapshotId;
+
+    fn _balance_of_at(&self, account: &AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error>;
+
+    fn _total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error>;
+
+    /// Records the values that are about to change. It is called from `psp22::Internal::_before_token_transfer`
+    /// generated by `#[openbrush::implementation(PSP22Snapshot)]`, so it must be called from the overrider of it.
+    fn _update_snapshots(&mut self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal + psp22::Internal {
+    fn _emit_snapshot_event(&self, _id: SnapshotId) {}
+
+    fn _snapshot(&mut self) -> SnapshotId {
+        let id = Internal::_current_snapshot_id(self) + 1;
+        self.data().current_snapshot_id.set(&id);
+        Internal::_emit_snapshot_event(self, id);
+        id
+    }
+
+    fn _current_snapshot_id(&self) -> SnapshotId {
+        self.data().current_snapshot_id.get_or_default()
+    }
+
+    fn _balance_of_at(&self, account: &AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+        let snapshots = self.data().account_balance_snapshots.get(account).unwrap_or_default();
+
+        match _value_at(&snapshots, snapshot_id, Internal::_current_snapshot_id(self))? {
+            Some(value) => Ok(value),
+            None => Ok(psp22::Internal::_balance_of(self, account)),
+        }
+    }
+
+    fn _total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+        let snapshots = self.data().total_supply_snapshots.get_or_default();
+
+        match _value_at(&snapshots, snapshot_id, Internal::_current_snapshot_id(self))? {
+            Some(value) => Ok(value),
+            None => Ok(psp22::Internal::_total_supply(self)),
+        }
+    }
+
+    fn _update_snapshots(&mut self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error> {
+        let current_id = Internal::_current_snapshot_id(self);
+
+        if current_id == 0 {
+            return Ok(())
+        }
+
+        for account in [from, to].into_iter().flatten() {
+            let mut snapshots = self.data().account_balance_snapshots.get(account).unwrap_or_default();
+            let balance = psp22::Internal::_balance_of(self, account);
+
+            if _update_snapshot(&mut snapshots, current_id, balance)? {
+                self.data().account_balance_snapshots.insert(account, &snapshots);
+            }
+        }
+
+        // Total supply changes only on mint and burn
+        if from.is_none() || to.is_none() {
+            let mut snapshots = self.data().total_supply_snapshots.get_or_default();
+            let supply = psp22::Internal::_total_supply(self);
+
+            if _update_snapshot(&mut snapshots, current_id, supply)? {
+                self.data().total_supply_snapshots.set(&snapshots);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the value recorded for `snapshot_id`, or `None` if the value didn't change since it was taken.
+fn _value_at(
+    snapshots: &Checkpoints,
+    snapshot_id: SnapshotId,
+    current_id: SnapshotId,
+) -> Result<Option<Balance>, PSP22Error> {
+    if snapshot_id == 0 {
+        return Err(PSP22Error::Custom(String::from("Snapshot id is 0")))
+    }
+    if snapshot_id > current_id {
+        return Err(PSP22Error::Custom(String::from("Nonexistent snapshot id")))
+    }
+
+    // The value at `snapshot_id` is stored in the first checkpoint taken after it
+    Ok(snapshots.lower_lookup(snapshot_id))
+}
+
+/// Stores `value` for `current_id` if it wasn't stored yet. Returns `true` if `snapshots` were modified.
+fn _update_snapshot(snapshots: &mut Checkpoints, current_id: SnapshotId, value: Balance) -> Result<bool, PSP22Error> {
+    let (exists, last_id, _) = snapshots.latest_checkpoint();
+
+    if exists && last_id >= current_id {
+        return Ok(false)
+    }
+
+    snapshots
+        .push(current_id, value)
+        .map_err(|_| PSP22Error::Custom(String::from("Unordered snapshot")))?;
+    Ok(true)
+}
@@ -0,0 +1,404 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::vault,
+    traits::psp22::{
+        extensions::vault::*,
+        *,
+    },
+};
+use ink::{
+    env::CallFlags,
+    prelude::vec::Vec,
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+    String,
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+pub use vault::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub asset: Option<AccountId>,
+}
+
+/// Direction of rounding used during the conversion between assets and shares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+pub trait PSP22VaultImpl: Storage<Data> + Internal + psp22::Internal + PSP22Vault {
+    fn asset(&self) -> Option<AccountId> {
+        self._asset()
+    }
+
+    fn total_assets(&self) -> Balance {
+        self._total_assets()
+    }
+
+    fn convert_to_shares(&self, assets: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_shares(assets, Rounding::Down)
+    }
+
+    fn convert_to_assets(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_assets(shares, Rounding::Down)
+    }
+
+    fn max_deposit(&self, _receiver: AccountId) -> Balance {
+        Balance::MAX
+    }
+
+    fn max_mint(&self, _receiver: AccountId) -> Balance {
+        Balance::MAX
+    }
+
+    fn max_withdraw(&self, owner: AccountId) -> Result<Balance, PSP22Error> {
+        self._convert_to_assets(psp22::Internal::_balance_of(self, &owner), Rounding::Down)
+    }
+
+    fn max_redeem(&self, owner: AccountId) -> Balance {
+        psp22::Internal::_balance_of(self, &owner)
+    }
+
+    fn preview_deposit(&self, assets: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_shares(assets, Rounding::Down)
+    }
+
+    fn preview_mint(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_assets(shares, Rounding::Up)
+    }
+
+    fn preview_withdraw(&self, assets: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_shares(assets, Rounding::Up)
+    }
+
+    fn preview_redeem(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+        self._convert_to_assets(shares, Rounding::Down)
+    }
+
+    fn deposit(&mut self, assets: Balance, receiver: AccountId) -> Result<Balance, PSP22Error> {
+        if assets > PSP22Vault::max_deposit(self, receiver) {
+            return Err(PSP22Error::Custom(String::from("Deposit more than max")))
+        }
+
+        let shares = PSP22Vault::preview_deposit(self, assets)?;
+        self._deposit(Self::env().caller(), receiver, assets, shares)?;
+        Ok(shares)
+    }
+
+    fn mint(&mut self, shares: Balance, receiver: AccountId) -> Result<Balance, PSP22Error> {
+        if shares > PSP22Vault::max_mint(self, receiver) {
+            return Err(PSP22Error::Custom(String::from("Mint more than max")))
+        }
+
+        let assets = PSP22Vault::preview_mint(self, shares)?;
+        self._deposit(Self::env().caller(), receiver, assets, shares)?;
+        Ok(assets)
+    }
+
+    fn withdraw(&mut self, assets: Balance, receiver: AccountId, owner: AccountId) -> Result<Balance, PSP22Error> {
+        if assets > PSP22Vault::max_withdraw(self, owner)? {
+            return Err(PSP22Error::Custom(String::from("Withdraw more than max")))
+        }
+
+        let shares = PSP22Vault::preview_withdraw(self, assets)?;
+        self._withdraw(Self::env().caller(), receiver, owner, assets, shares)?;
+        Ok(shares)
+    }
+
+    fn redeem(&mut self, shares: Balance, receiver: AccountId, owner: AccountId) -> Result<Balance, PSP22Error> {
+        if shares > PSP22Vault::max_redeem(self, owner) {
+            return Err(PSP22Error::Custom(String::from("Redeem more than max")))
+        }
+
+        let assets = PSP22Vault::preview_redeem(self, shares)?;
+        self._withdraw(Self::env().caller(), receiver, owner, assets, shares)?;
+        Ok(assets)
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_deposit_event(&self, _sender: AccountId, _owner: AccountId, _assets: Balance, _shares: Balance);
+    fn _emit_withdraw_event(
+        &self,
+        _sender: AccountId,
+        _receiver: AccountId,
+        _owner: AccountId,
+        _assets: Balance,
+        _shares: Balance,
+    );
+
+    /// Initalize the vault with defining the underlying PSP22 token
+    ///
+    /// `asset` is the token to be managed by the vault
+    fn _init(&mut self, asset: AccountId);
+
+    /// Getter for the underlying PSP22 token
+    fn _asset(&self) -> Option<AccountId>;
+
+    /// Returns the amount of the underlying asset managed by the vault.
+    ///
+    /// By default it is the balance of the vault in the underlying token.
+    /// Override it if the vault invests the assets somewhere else.
+    fn _total_assets(&self) -> Balance;
+
+    /// Offset between the decimals of the underlying asset and the decimals of the shares.
+    ///
+    /// Virtual shares and assets derived from it make the inflation attack unprofitable
+    /// for the attacker, the bigger offset - the bigger the cost of the attack.
+    fn _decimals_offset(&self) -> u8;
+
+    /// Converts `assets` to shares with the given `rounding` direction
+    fn _convert_to_shares(&self, assets: Balance, rounding: Rounding) -> Result<Balance, PSP22Error>;
+
+    /// Converts `shares` to assets with the given `rounding` direction
+    fn _convert_to_assets(&self, shares: Balance, rounding: Rounding) -> Result<Balance, PSP22Error>;
+
+    /// Takes `assets` from `caller` and mints `shares` to `receiver`
+    fn _deposit(
+        &mut self,
+        caller: AccountId,
+        receiver: AccountId,
+        assets: Balance,
+        shares: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// Burns `shares` of `owner` and sends `assets` to `receiver`, spending the allowance of `caller` if needed
+    fn _withdraw(
+        &mut self,
+        caller: AccountId,
+        receiver: AccountId,
+        owner: AccountId,
+        assets: Balance,
+        shares: Balance,
+    ) -> Result<(), PSP22Error>;
+
+    /// helper function to transfer the underlying token from `from` to the contract
+    fn _transfer_asset_in(&mut self, from: AccountId, amount: Balance) -> Result<(), PSP22Error>;
+
+    /// helper function to transfer the underlying token from the contract to `to`
+    fn _transfer_asset_out(&mut self, to: AccountId, amount: Balance) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal + psp22::Internal {
+    fn _emit_deposit_event(&self, _sender: AccountId, _owner: AccountId, _assets: Balance, _shares: Balance) {}
+
+    fn _emit_withdraw_event(
+        &self,
+        _sender: AccountId,
+        _receiver: AccountId,
+        _owner: AccountId,
+        _assets: Balance,
+        _shares: Balance,
+    ) {
+    }
+
+    fn _init(&mut self, asset: AccountId) {
+        self.data().asset.set(&Some(asset));
+    }
+
+    fn _asset(&self) -> Option<AccountId> {
+        self.data().asset.get_or_default()
+    }
+
+    fn _total_assets(&self) -> Balance {
+        if let Some(asset) = Internal::_asset(self) {
+            PSP22Ref::balance_of(&asset, Self::env().account_id())
+        } else {
+            0
+        }
+    }
+
+    fn _decimals_offset(&self) -> u8 {
+        0
+    }
+
+    fn _convert_to_shares(&self, assets: Balance, rounding: Rounding) -> Result<Balance, PSP22Error> {
+        let virtual_shares = psp22::Internal::_total_supply(self)
+            .checked_add(_offset(Internal::_decimals_offset(self))?)
+            .ok_or(PSP22Error::Custom(String::from("Overflow")))?;
+        let virtual_assets = Internal::_total_assets(self)
+            .checked_add(1)
+            .ok_or(PSP22Error::Custom(String::from("Overflow")))?;
+
+        _mul_div(assets, virtual_shares, virtual_assets, rounding)
+    }
+
+    fn _convert_to_assets(&self, shares: Balance, rounding: Rounding) -> Result<Balance, PSP22Error> {
+        let virtual_shares = psp22::Internal::_total_supply(self)
+            .checked_add(_offset(Internal::_decimals_offset(self))?)
+            .ok_or(PSP22Error::Custom(String::from("Overflow")))?;
+        let virtual_assets = Internal::_total_assets(self)
+            .checked_add(1)
+            .ok_or(PSP22Error::Custom(String::from("Overflow")))?;
+
+        _mul_div(shares, virtual_assets, virtual_shares, rounding)
+    }
+
+    fn _deposit(
+        &mut self,
+        caller: AccountId,
+        receiver: AccountId,
+        assets: Balance,
+        shares: Balance,
+    ) -> Result<(), PSP22Error> {
+        Internal::_transfer_asset_in(self, caller, assets)?;
+        psp22::Internal::_mint_to(self, receiver, shares)?;
+
+        Internal::_emit_deposit_event(self, caller, receiver, assets, shares);
+        Ok(())
+    }
+
+    fn _withdraw(
+        &mut self,
+        caller: AccountId,
+        receiver: AccountId,
+        owner: AccountId,
+        assets: Balance,
+        shares: Balance,
+    ) -> Result<(), PSP22Error> {
+        if caller != owner {
+            let allowance = psp22::Internal::_allowance(self, &owner, &caller);
+
+            if allowance < shares {
+                return Err(PSP22Error::InsufficientAllowance)
+            }
+
+            psp22::Internal::_approve_from_to(self, owner, caller, allowance - shares)?;
+        }
+
+        psp22::Internal::_burn_from(self, owner, shares)?;
+        Internal::_transfer_asset_out(self, receiver, assets)?;
+
+        Internal::_emit_withdraw_event(self, caller, receiver, owner, assets, shares);
+        Ok(())
+    }
+
+    fn _transfer_asset_in(&mut self, from: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        if let Some(asset) = Internal::_asset(self) {
+            PSP22Ref::transfer_from_builder(&asset, from, Self::env().account_id(), amount, Vec::<u8>::new())
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .try_invoke()??
+        } else {
+            Err(PSP22Error::Custom(String::from("Asset not initialized")))
+        }
+    }
+
+    fn _transfer_asset_out(&mut self, to: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        if let Some(asset) = Internal::_asset(self) {
+            PSP22Ref::transfer_builder(&asset, to, amount, Vec::<u8>::new())
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .try_invoke()??
+        } else {
+            Err(PSP22Error::Custom(String::from("Asset not initialized")))
+        }
+    }
+}
+
+/// Returns `10 ^ decimals_offset`, the amount of virtual shares.
+fn _offset(decimals_offset: u8) -> Result<Balance, PSP22Error> {
+    (10 as Balance)
+        .checked_pow(decimals_offset as u32)
+        .ok_or(PSP22Error::Custom(String::from("Overflow")))
+}
+
+/// Calculates `x * y / denominator` with the given `rounding` direction.
+///
+/// The product is computed with 256 bits of precision, so the call fails only if the
+/// result doesn't fit into `Balance`.
+fn _mul_div(x: Balance, y: Balance, denominator: Balance, rounding: Rounding) -> Result<Balance, PSP22Error> {
+    let (high, low) = _full_mul(x, y);
+
+    // The quotient fits into `Balance` only if the high half is less than the denominator
+    if high >= denominator {
+        return Err(PSP22Error::Custom(String::from("Overflow")))
+    }
+
+    let (result, remainder) = _div_rem(high, low, denominator);
+
+    if rounding == Rounding::Up && remainder > 0 {
+        result
+            .checked_add(1)
+            .ok_or(PSP22Error::Custom(String::from("Overflow")))
+    } else {
+        Ok(result)
+    }
+}
+
+/// Returns the high and low halves of the 256-bit product `x * y`.
+fn _full_mul(x: Balance, y: Balance) -> (Balance, Balance) {
+    const HALF: u32 = 64;
+    const MASK: Balance = u64::MAX as Balance;
+
+    let (x_low, x_high) = (x & MASK, x >> HALF);
+    let (y_low, y_high) = (y & MASK, y >> HALF);
+
+    let low_low = x_low * y_low;
+    let high_low = x_high * y_low;
+    let low_high = x_low * y_high;
+    let high_high = x_high * y_high;
+
+    // Each term is less than `2 ^ 64`, so the sum can't overflow
+    let middle = (low_low >> HALF) + (high_low & MASK) + (low_high & MASK);
+
+    let low = (middle << HALF) | (low_low & MASK);
+    let high = high_high + (high_low >> HALF) + (low_high >> HALF) + (middle >> HALF);
+
+    (high, low)
+}
+
+/// Divides the 256-bit number `high * 2 ^ 128 + low` by `denominator`, returning the quotient and the remainder.
+///
+/// `high` must be less than `denominator`, so the quotient fits into `Balance`.
+fn _div_rem(high: Balance, low: Balance, denominator: Balance) -> (Balance, Balance) {
+    let mut remainder = high;
+    let mut quotient: Balance = 0;
+
+    for bit in (0..Balance::BITS).rev() {
+        // The remainder is less than the denominator, so the shifted value is less than
+        // `2 * denominator` and one subtraction is enough, even if the top bit was shifted out
+        let carry = remainder >> (Balance::BITS - 1);
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+
+    (quotient, remainder)
+}
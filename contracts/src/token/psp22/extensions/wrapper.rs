@@ -103,9 +103,7 @@ pub trait InternalImpl: Storage<Data> + Internal + psp22::Internal + PSP22 {
                 Vec::<u8>::new(),
             )
             .call_flags(CallFlags::default().set_allow_reentry(true))
-            .try_invoke()
-            .unwrap()
-            .unwrap()
+            .try_invoke()??
         } else {
             Err(PSP22Error::Custom(String::from("Underlying not initialized")))
         }
@@ -115,9 +113,7 @@ pub trait InternalImpl: Storage<Data> + Internal + psp22::Internal + PSP22 {
         if let Some(underlying) = Internal::_underlying(self) {
             PSP22Ref::transfer_builder(&underlying, account, amount, Vec::<u8>::new())
                 .call_flags(CallFlags::default().set_allow_reentry(true))
-                .try_invoke()
-                .unwrap()
-                .unwrap()
+                .try_invoke()??
         } else {
             Err(PSP22Error::Custom(String::from("Underlying not initialized")))
         }
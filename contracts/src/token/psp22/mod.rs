@@ -30,6 +30,8 @@ pub mod extensions {
     pub mod metadata;
     pub mod mintable;
     pub mod permit;
+    pub mod snapshot;
+    pub mod vault;
     #[cfg(feature = "governance")]
     pub mod votes;
     pub mod wrapper;
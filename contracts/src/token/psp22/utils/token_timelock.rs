@@ -116,8 +116,8 @@ pub trait InternalImpl: Storage<Data> + Internal {
                 PSP22Ref::transfer_builder(&token, beneficiary, amount, Vec::<u8>::new())
                     .call_flags(CallFlags::default().set_allow_reentry(true))
                     .try_invoke()
-                    .unwrap()
-                    .unwrap()?;
+                    .map_err(PSP22Error::from)?
+                    .map_err(PSP22Error::from)??;
                 Ok(())
             } else {
                 Err(PSP22TokenTimelockError::TokenZeroAddress)
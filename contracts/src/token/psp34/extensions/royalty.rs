@@ -0,0 +1,155 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::royalty,
+    traits::psp34::{
+        extensions::royalty::*,
+        *,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        String,
+    },
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+pub use royalty::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub default_royalty: Option<RoyaltyInfo>,
+    pub token_royalty: Mapping<Id, RoyaltyInfo>,
+}
+
+/// Royalty of the token, where `royalty_fraction` is expressed in basis points of the sale price
+/// (with the default fee denominator of 10000).
+#[derive(scale::Decode, scale::Encode, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct RoyaltyInfo {
+    pub receiver: AccountId,
+    pub royalty_fraction: u16,
+}
+
+pub trait PSP34RoyaltyImpl: Internal {
+    fn royalty_info(&self, id: Id, sale_price: Balance) -> Option<(AccountId, Balance)> {
+        self._royalty_info(&id, sale_price)
+    }
+}
+
+pub trait Internal {
+    /// The denominator with which to interpret the fee set in `_set_token_royalty` and `_set_default_royalty`
+    /// as a fraction of the sale price. Defaults to 10000 so fees are expressed in basis points.
+    fn _fee_denominator(&self) -> u16;
+
+    fn _royalty_info(&self, id: &Id, sale_price: Balance) -> Option<(AccountId, Balance)>;
+
+    /// Sets the royalty information that all tokens will default to.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error if `fee_numerator` is greater than the fee denominator.
+    fn _set_default_royalty(&mut self, receiver: AccountId, fee_numerator: u16) -> Result<(), PSP34Error>;
+
+    /// Removes default royalty information.
+    fn _delete_default_royalty(&mut self);
+
+    /// Sets the royalty information for the token `id`, overriding the default royalty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error if `fee_numerator` is greater than the fee denominator.
+    fn _set_token_royalty(&mut self, id: Id, receiver: AccountId, fee_numerator: u16) -> Result<(), PSP34Error>;
+
+    /// Resets royalty information for the token `id` back to the default royalty.
+    fn _delete_token_royalty(&mut self, id: Id);
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _fee_denominator(&self) -> u16 {
+        10000
+    }
+
+    fn _royalty_info(&self, id: &Id, sale_price: Balance) -> Option<(AccountId, Balance)> {
+        let royalty = match self.data().token_royalty.get(id) {
+            Some(royalty) => royalty,
+            None => self.data().default_royalty.get_or_default()?,
+        };
+
+        let denominator = Balance::from(Internal::_fee_denominator(self));
+        let fraction = Balance::from(royalty.royalty_fraction);
+
+        // `sale_price * fraction / denominator` without overflow, as `fraction` is not greater than `denominator`
+        let amount = sale_price / denominator * fraction + sale_price % denominator * fraction / denominator;
+
+        Some((royalty.receiver, amount))
+    }
+
+    fn _set_default_royalty(&mut self, receiver: AccountId, fee_numerator: u16) -> Result<(), PSP34Error> {
+        if fee_numerator > Internal::_fee_denominator(self) {
+            return Err(PSP34Error::Custom(String::from("Royalty fee will exceed sale price")))
+        }
+
+        self.data().default_royalty.set(&Some(RoyaltyInfo {
+            receiver,
+            royalty_fraction: fee_numerator,
+        }));
+        Ok(())
+    }
+
+    fn _delete_default_royalty(&mut self) {
+        self.data().default_royalty.set(&None);
+    }
+
+    fn _set_token_royalty(&mut self, id: Id, receiver: AccountId, fee_numerator: u16) -> Result<(), PSP34Error> {
+        if fee_numerator > Internal::_fee_denominator(self) {
+            return Err(PSP34Error::Custom(String::from("Royalty fee will exceed sale price")))
+        }
+
+        self.data().token_royalty.insert(
+            &id,
+            &RoyaltyInfo {
+                receiver,
+                royalty_fraction: fee_numerator,
+            },
+        );
+        Ok(())
+    }
+
+    fn _delete_token_royalty(&mut self, id: Id) {
+        self.data().token_royalty.remove(&id);
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp37,
+    psp37::extensions::capped,
+    traits::psp37::{
+        extensions::capped::*,
+        *,
+    },
+};
+pub use capped::Internal as _;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        Balance,
+        Storage,
+        String,
+    },
+};
+pub use psp37::{
+    BalancesManager as _,
+    BalancesManagerImpl as _,
+    Internal as _,
+    InternalImpl as _,
+    PSP37Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub max_supply: Mapping<Id, Balance>,
+}
+
+pub trait PSP37CappedImpl: Internal {
+    fn exists(&self, id: Id) -> bool {
+        self._exists(&id)
+    }
+
+    fn max_supply(&self, id: Id) -> Option<Balance> {
+        self._max_supply(&id)
+    }
+}
+
+pub trait Internal {
+    fn _exists(&self, id: &Id) -> bool;
+
+    fn _max_supply(&self, id: &Id) -> Option<Balance>;
+
+    /// Sets the max supply of token type `id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error if the current supply of `id` is already above `max_supply`.
+    fn _set_max_supply(&mut self, id: &Id, max_supply: Balance) -> Result<(), PSP37Error>;
+
+    /// Checks that minting `ids_amounts` doesn't exceed the max supply of any token type.
+    /// It is called on mint from `psp37::Internal::_before_token_transfer` generated by
+    /// `#[openbrush::implementation(PSP37Capped)]`, so it must be called from the overrider of it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MaxSupplyExceeded` error if the max supply of any token type is exceeded.
+    fn _check_max_supply(&self, ids_amounts: &[(Id, Balance)]) -> Result<(), PSP37Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> + psp37::BalancesManager {
+    fn _exists(&self, id: &Id) -> bool {
+        psp37::BalancesManager::_total_supply(self, &Some(id)) > 0
+    }
+
+    fn _max_supply(&self, id: &Id) -> Option<Balance> {
+        self.data().max_supply.get(id)
+    }
+
+    fn _set_max_supply(&mut self, id: &Id, max_supply: Balance) -> Result<(), PSP37Error> {
+        if psp37::BalancesManager::_total_supply(self, &Some(id)) > max_supply {
+            return Err(PSP37Error::Custom(String::from("Max supply is below total supply")))
+        }
+
+        self.data().max_supply.insert(id, &max_supply);
+        Ok(())
+    }
+
+    fn _check_max_supply(&self, ids_amounts: &[(Id, Balance)]) -> Result<(), PSP37Error> {
+        for (index, (id, _)) in ids_amounts.iter().enumerate() {
+            let max_supply = match Internal::_max_supply(self, id) {
+                Some(max_supply) => max_supply,
+                None => continue,
+            };
+
+            // The same id can be minted several times within a batch
+            let mut supply = psp37::BalancesManager::_total_supply(self, &Some(id));
+            for (_, amount) in ids_amounts[index..].iter().filter(|(other, _)| other == id) {
+                supply = supply.checked_add(*amount).ok_or(PSP37Error::MaxSupplyExceeded)?;
+            }
+
+            if supply > max_supply {
+                return Err(PSP37Error::MaxSupplyExceeded)
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -20,7 +20,10 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub use crate::traits::errors::AccessControlError;
-use openbrush::traits::AccountId;
+use openbrush::traits::{
+    AccountId,
+    Timestamp,
+};
 
 pub type RoleType = u32;
 
@@ -55,7 +58,25 @@ pub trait AccessControl {
     #[ink(message)]
     fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError>;
 
-    /// Revokes `role` from `account`.
+    /// Grants `role` to `account` until `expires_at`. After that the grant is
+    /// treated as absent.
+    ///
+    /// On success a `RoleGranted` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller can't grant the role.
+    /// Returns with `RoleRedundant` error `account` has `role`.
+    /// Returns with `InvalidExpiration` error if `expires_at` is not in the future.
+    #[ink(message)]
+    fn grant_role_until(
+        &mut self,
+        role: RoleType,
+        account: Option<AccountId>,
+        expires_at: Timestamp,
+    ) -> Result<(), AccessControlError>;
+
+    /// Revokes `role` from `account`. An expired grant of `role` is removed as well.
     ///
     /// On success a `RoleRevoked` event is emitted.
     ///
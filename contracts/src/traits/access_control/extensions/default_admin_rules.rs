@@ -0,0 +1,110 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::access_control::*;
+use openbrush::traits::{
+    AccountId,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type AccessControlDefaultAdminRulesRef = dyn AccessControlDefaultAdminRules + AccessControl;
+
+/// Extension of AccessControl that adds rules for the management of the default admin role.
+///
+/// There is only one account holding the default admin role at any time, and it can't be
+/// granted or revoked through `grant_role` and `revoke_role`. Instead, the default admin is
+/// changed in two steps: the current admin begins the transfer and the new admin accepts it
+/// after the default admin delay passes. The delay itself can only be changed with a delay.
+#[openbrush::trait_definition]
+pub trait AccessControlDefaultAdminRules {
+    /// Returns the account holding the default admin role.
+    #[ink(message)]
+    fn default_admin(&self) -> Option<AccountId>;
+
+    /// Returns the pending default admin and the timestamp after which it can accept the role.
+    /// `None` means that the pending transfer renounces the role.
+    #[ink(message)]
+    fn pending_default_admin(&self) -> (Option<AccountId>, Timestamp);
+
+    /// Returns the delay that is waited between beginning and accepting a default admin transfer.
+    #[ink(message)]
+    fn default_admin_delay(&self) -> Timestamp;
+
+    /// Returns the pending new delay and the timestamp after which it takes effect.
+    /// Both are zero if there is no pending delay change.
+    #[ink(message)]
+    fn pending_default_admin_delay(&self) -> (Timestamp, Timestamp);
+
+    /// Starts a default admin transfer to `new_admin`, which can accept it after the delay.
+    /// Passing `None` allows the current admin to renounce the role after the delay.
+    ///
+    /// On success a `DefaultAdminTransferScheduled` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller is not the default admin.
+    #[ink(message)]
+    fn begin_default_admin_transfer(&mut self, new_admin: Option<AccountId>) -> Result<(), AccessControlError>;
+
+    /// Cancels the pending default admin transfer.
+    ///
+    /// On success a `DefaultAdminTransferCanceled` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller is not the default admin.
+    #[ink(message)]
+    fn cancel_default_admin_transfer(&mut self) -> Result<(), AccessControlError>;
+
+    /// Completes the default admin transfer, the caller becomes the default admin.
+    ///
+    /// On success `RoleRevoked` and `RoleGranted` events are emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `InvalidDefaultAdmin` error if caller is not the pending default admin.
+    /// Returns with `EnforcedDefaultAdminDelay` error if the delay has not passed yet.
+    #[ink(message)]
+    fn accept_default_admin_transfer(&mut self) -> Result<(), AccessControlError>;
+
+    /// Schedules a change of the default admin delay. Increasing the delay takes effect after
+    /// `new_delay` (capped by the increase wait), decreasing it after the difference of the delays.
+    ///
+    /// On success a `DefaultAdminDelayChangeScheduled` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller is not the default admin.
+    #[ink(message)]
+    fn change_default_admin_delay(&mut self, new_delay: Timestamp) -> Result<(), AccessControlError>;
+
+    /// Cancels the pending change of the default admin delay.
+    ///
+    /// On success a `DefaultAdminDelayChangeCanceled` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller is not the default admin.
+    #[ink(message)]
+    fn rollback_default_admin_delay(&mut self) -> Result<(), AccessControlError>;
+}
@@ -31,13 +31,22 @@ pub trait AccessControlEnumerable {
     /// Returns one of the accounts that have `role`.
     ///
     /// Role bearers are not sorted in any particular way, and their
-    /// ordering may change at any point.
+    /// ordering may change at any point. The members whose grant has
+    /// expired are skipped.
     #[ink(message)]
     fn get_role_member(&self, role: RoleType, index: u32) -> Option<AccountId>;
 
     /// Returns the number of accounts that have `role`.
     /// Can be used together with {get_role_member} to enumerate
-    /// all bearers of a role.
+    /// all bearers of a role. The members whose grant has expired
+    /// are not counted.
     #[ink(message)]
     fn get_role_member_count(&self, role: RoleType) -> u32;
+
+    /// Removes the members of `role` whose grant has expired, so they are
+    /// not stored and skipped anymore. Returns the number of removed members.
+    ///
+    /// On success a `RoleRevoked` event is emitted for each removed member.
+    #[ink(message)]
+    fn prune_expired_role_members(&mut self, role: RoleType) -> u32;
 }
@@ -24,5 +24,6 @@ mod access_control;
 pub use access_control::*;
 
 pub mod extensions {
+    pub mod default_admin_rules;
     pub mod enumerable;
 }
@@ -44,6 +44,16 @@ pub struct FacetCut {
     pub selectors: Vec<Selector>,
 }
 
+/// Struct which a facet publishes to describe itself to the diamond
+#[derive(Default, Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct FacetInfo {
+    /// The selectors of all messages exposed by the facet.
+    pub selectors: Vec<Selector>,
+    /// The storage keys owned by the facet.
+    pub storage_keys: Vec<u32>,
+}
+
 /// Struct which we use to initialize the diamond contract
 #[derive(Default, Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -66,6 +76,11 @@ pub trait Diamond {
     /// If `cuts` is empty, we will remove this facet from diamond
     /// If `cuts` contains a selector which already exists for a different facet we will return an error (user should remove this facet first)
     /// If `cuts` does not contain some selectors which are already registered for this facet, those selectors will be removed from diamond
+    /// Before registering a facet, the diamond queries its `FacetInfo` and returns an error if the facet does not expose
+    /// one of the selectors or if one of its storage keys is already owned by another facet
+    /// Facets which don't implement `DiamondFacet` are registered without these checks, unless the diamond
+    /// overrides `diamond::Internal::_require_facet_info` to return `true`, then the cut returns `FacetInfoUnavailable`
+    /// Immutable selectors can't be replaced or removed, such cuts will return an error
     /// `init` optional struct which identifies a call to be executed, this struct contains the code hash
     /// of the executed contract, selector of the executed function and input data to be passed to the called
     #[ink(message)]
@@ -24,5 +24,6 @@ mod diamond;
 
 pub use diamond::*;
 pub mod extensions {
+    pub mod diamond_facet;
     pub mod diamond_loupe;
 }
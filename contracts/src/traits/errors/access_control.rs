@@ -27,4 +27,15 @@ pub enum AccessControlError {
     InvalidCaller,
     MissingRole,
     RoleRedundant,
+    /// Returned if the default admin rules are violated, e.g. the default admin role
+    /// is granted or revoked through `grant_role`/`revoke_role`.
+    EnforcedDefaultAdminRules,
+    /// Returned if the default admin transfer or renounce is not ready yet.
+    EnforcedDefaultAdminDelay,
+    /// Returned if the caller is not the pending default admin.
+    InvalidDefaultAdmin,
+    /// Returned if the expiration of a role grant is not in the future.
+    InvalidExpiration,
+    /// Returned if the default admin role is granted while another account holds it.
+    DefaultAdminAlreadyGranted,
 }
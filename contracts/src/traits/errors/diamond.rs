@@ -19,21 +19,38 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use super::OwnableError;
-use openbrush::traits::Hash;
+use super::{
+    AccessControlError,
+    OwnableError,
+    PausableError,
+    ReentrancyGuardError,
+};
+use openbrush::traits::{
+    ErrorFrom,
+    Hash,
+};
 
 /// The Diamond error type. Contract will throw one of this errors.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum DiamondError {
-    OwnableError(OwnableError),
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
     FunctionDoesNotExist,
     EmptyCodeHash,
     ReplaceExisting(Hash),
-}
-
-impl From<OwnableError> for DiamondError {
-    fn from(error: OwnableError) -> Self {
-        DiamondError::OwnableError(error)
-    }
+    FacetInfoUnavailable(Hash),
+    SelectorNotExposed([u8; 4]),
+    StorageCollision(u32, Hash),
+    ImmutableSelector([u8; 4]),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
 }
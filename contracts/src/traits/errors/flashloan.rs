@@ -21,62 +21,34 @@
 
 use super::{
     AccessControlError,
+    InitializableError,
     OwnableError,
     PSP22Error,
     PSP22ReceiverError,
     PausableError,
     ReentrancyGuardError,
 };
-use openbrush::traits::String;
+use openbrush::traits::{
+    ErrorFrom,
+    String,
+};
 
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum FlashBorrowerError {
     FlashloanRejected(String),
-}
-
-impl From<OwnableError> for FlashBorrowerError {
-    fn from(ownable: OwnableError) -> Self {
-        match ownable {
-            OwnableError::CallerIsNotOwner => {
-                FlashBorrowerError::FlashloanRejected(String::from("O::CallerIsNotOwner"))
-            }
-            OwnableError::NewOwnerIsZero => FlashBorrowerError::FlashloanRejected(String::from("O::NewOwnerIsZero")),
-        }
-    }
-}
-
-impl From<AccessControlError> for FlashBorrowerError {
-    fn from(access: AccessControlError) -> Self {
-        match access {
-            AccessControlError::MissingRole => FlashBorrowerError::FlashloanRejected(String::from("AC::MissingRole")),
-            AccessControlError::RoleRedundant => {
-                FlashBorrowerError::FlashloanRejected(String::from("AC::RoleRedundant"))
-            }
-            AccessControlError::InvalidCaller => {
-                FlashBorrowerError::FlashloanRejected(String::from("AC::InvalidCaller"))
-            }
-        }
-    }
-}
-
-impl From<PausableError> for FlashBorrowerError {
-    fn from(pausable: PausableError) -> Self {
-        match pausable {
-            PausableError::Paused => FlashBorrowerError::FlashloanRejected(String::from("P::Paused")),
-            PausableError::NotPaused => FlashBorrowerError::FlashloanRejected(String::from("P::NotPaused")),
-        }
-    }
-}
-
-impl From<ReentrancyGuardError> for FlashBorrowerError {
-    fn from(guard: ReentrancyGuardError) -> Self {
-        match guard {
-            ReentrancyGuardError::ReentrantCall => {
-                FlashBorrowerError::FlashloanRejected(String::from("RG::ReentrantCall"))
-            }
-        }
-    }
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
 }
 
 impl From<PSP22ReceiverError> for FlashBorrowerError {
@@ -87,78 +59,43 @@ impl From<PSP22ReceiverError> for FlashBorrowerError {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum FlashLenderError {
     Custom(String),
-    /// Returned if we our flashlendner does not support lending of this token
+    /// Returned if the flash lender does not support lending of this token
     WrongTokenAddress,
     /// Returned if the contract does not have enough allowance to transfer borrowed amount and fees
     AllowanceDoesNotAllowRefund,
     /// Callee contract rejected the flashloan
     BorrowerRejected(String),
-}
-
-impl From<PSP22Error> for FlashLenderError {
-    fn from(error: PSP22Error) -> Self {
-        match error {
-            PSP22Error::Custom(message) => FlashLenderError::Custom(message),
-            PSP22Error::InsufficientBalance => FlashLenderError::Custom(String::from("PSP22: Insufficient Balance")),
-            PSP22Error::InsufficientAllowance => {
-                FlashLenderError::Custom(String::from("PSP22: Insufficient Allowance"))
-            }
-            PSP22Error::ZeroRecipientAddress => FlashLenderError::Custom(String::from("PSP22: Zero Recipient Address")),
-            PSP22Error::ZeroSenderAddress => FlashLenderError::Custom(String::from("PSP22: Zero Sender Address")),
-            PSP22Error::SafeTransferCheckFailed(message) => FlashLenderError::Custom(message),
-            PSP22Error::PermitInvalidSignature => {
-                FlashLenderError::Custom(String::from("PSP22: Permit Invalid Signature"))
-            }
-            PSP22Error::PermitExpired => FlashLenderError::Custom(String::from("PSP22: Permit Expired")),
-            PSP22Error::NoncesError(_) => FlashLenderError::Custom(String::from("PSP22: Nonces Error")),
-        }
-    }
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
+    /// Returned if the contract can't be initialized
+    #[from]
+    Initializable(InitializableError),
+    /// Returned if the minting, burning or transfer of the token failed
+    #[from]
+    PSP22(PSP22Error),
+    /// Callee contract failed with an error of OpenBrush module
+    Borrower(FlashBorrowerError),
 }
 
 impl From<FlashBorrowerError> for FlashLenderError {
     fn from(error: FlashBorrowerError) -> Self {
         match error {
             FlashBorrowerError::FlashloanRejected(message) => FlashLenderError::BorrowerRejected(message),
-        }
-    }
-}
-
-impl From<OwnableError> for FlashLenderError {
-    fn from(ownable: OwnableError) -> Self {
-        match ownable {
-            OwnableError::CallerIsNotOwner => FlashLenderError::Custom(String::from("O::CallerIsNotOwner")),
-            OwnableError::NewOwnerIsZero => FlashLenderError::Custom(String::from("O::NewOwnerIsZero")),
-        }
-    }
-}
-
-impl From<AccessControlError> for FlashLenderError {
-    fn from(access: AccessControlError) -> Self {
-        match access {
-            AccessControlError::MissingRole => FlashLenderError::Custom(String::from("AC::MissingRole")),
-            AccessControlError::RoleRedundant => FlashLenderError::Custom(String::from("AC::RoleRedundant")),
-            AccessControlError::InvalidCaller => FlashLenderError::Custom(String::from("AC::InvalidCaller")),
-        }
-    }
-}
-
-impl From<PausableError> for FlashLenderError {
-    fn from(pausable: PausableError) -> Self {
-        match pausable {
-            PausableError::Paused => FlashLenderError::Custom(String::from("P::Paused")),
-            PausableError::NotPaused => FlashLenderError::Custom(String::from("P::NotPaused")),
-        }
-    }
-}
-
-impl From<ReentrancyGuardError> for FlashLenderError {
-    fn from(guard: ReentrancyGuardError) -> Self {
-        match guard {
-            ReentrancyGuardError::ReentrantCall => FlashLenderError::Custom(String::from("RG::ReentrantCall")),
+            error => FlashLenderError::Borrower(error),
         }
     }
 }
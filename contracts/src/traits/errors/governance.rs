@@ -20,14 +20,25 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
-use crate::traits::errors::NoncesError;
-use openbrush::utils::{
-    checkpoints::CheckpointsError,
-    crypto::CryptoError,
+use crate::traits::errors::{
+    AccessControlError,
+    NoncesError,
+    OwnableError,
+    PausableError,
+    ReentrancyGuardError,
+    TimelockControllerError,
+};
+use ink::LangError;
+use openbrush::{
+    traits::ErrorFrom,
+    utils::{
+        checkpoints::CheckpointsError,
+        crypto::CryptoError,
+    },
 };
 
 /// The Governor error type. Contract will throw one of this errors.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum GovernanceError {
     TokenNotSet,
@@ -54,28 +65,41 @@ pub enum GovernanceError {
     ErrorParsingDescription,
     FutureLookup,
     ExpiredSignature,
+    #[from]
     CryptoError(CryptoError),
+    #[from]
     NoncesError(NoncesError),
     ExecutionFailed,
+    #[from]
     CheckpointsError(CheckpointsError),
     IndexOutOfRange,
     Overflow,
+    InvalidVoteParams,
+    ExceedsRemainingVotes,
+    TimelockNotSet,
+    #[from]
+    TimelockControllerError(TimelockControllerError),
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
+    /// Returned if the cross-contract call failed in the environment
+    CallFailed,
+    /// Returned if the callee couldn't dispatch the cross-contract call
+    #[from]
+    LangError(LangError),
 }
 
-impl From<CryptoError> for GovernanceError {
-    fn from(err: CryptoError) -> Self {
-        GovernanceError::CryptoError(err)
-    }
-}
-
-impl From<NoncesError> for GovernanceError {
-    fn from(err: NoncesError) -> Self {
-        GovernanceError::NoncesError(err)
-    }
-}
-
-impl From<CheckpointsError> for GovernanceError {
-    fn from(err: CheckpointsError) -> Self {
-        GovernanceError::CheckpointsError(err)
+impl From<ink::env::Error> for GovernanceError {
+    fn from(_: ink::env::Error) -> Self {
+        GovernanceError::CallFailed
     }
 }
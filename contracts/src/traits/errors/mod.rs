@@ -24,10 +24,13 @@ mod access_control;
 mod diamond;
 mod flashloan;
 mod governance;
+mod initializable;
+mod multisig;
 mod nonces;
 mod ownable;
 mod pausable;
 mod payment_splitter;
+mod proxy;
 mod psp22;
 mod psp34;
 mod psp37;
@@ -42,10 +45,16 @@ pub use flashloan::{
     FlashLenderError,
 };
 pub use governance::GovernanceError;
+pub use initializable::InitializableError;
+pub use multisig::MultisigError;
 pub use nonces::NoncesError;
 pub use ownable::OwnableError;
-pub use pausable::PausableError;
+pub use pausable::{
+    GranularPausableError,
+    PausableError,
+};
 pub use payment_splitter::PaymentSplitterError;
+pub use proxy::ProxyError;
 pub use psp22::{
     PSP22Error,
     PSP22ReceiverError,
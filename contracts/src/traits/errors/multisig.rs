@@ -0,0 +1,71 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::{
+    AccessControlError,
+    OwnableError,
+    PausableError,
+    ReentrancyGuardError,
+};
+use openbrush::traits::{
+    ErrorFrom,
+    String,
+};
+
+/// The Multisig error type. Contract will throw one of this errors.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum MultisigError {
+    Custom(String),
+    /// Returned if the caller is not one of the owners.
+    CallerIsNotOwner,
+    /// Returned if owner management is not called by the multisig itself.
+    CallerMustBeMultisig,
+    /// Returned if the account is already an owner.
+    OwnerAlreadyExists,
+    /// Returned if the account is not an owner.
+    OwnerNotFound,
+    /// Returned if the threshold is zero or bigger than the number of owners.
+    InvalidThreshold,
+    /// Returned if the transaction does not exist or was already executed.
+    TransactionNotFound,
+    /// Returned if the owner already approved the transaction.
+    AlreadyApproved,
+    /// Returned if the owner did not approve the transaction.
+    NotApproved,
+    /// Returned if the transaction does not have enough approvals to be executed.
+    NotEnoughApprovals,
+    UnderlyingTransactionReverted,
+    CalleeMustExist,
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
+}
@@ -19,6 +19,9 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use super::AccessControlError;
+use openbrush::traits::ErrorFrom;
+
 /// The Pausable error type. Contract will throw one of this errors.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -26,3 +29,15 @@ pub enum PausableError {
     Paused,
     NotPaused,
 }
+
+/// The GranularPausable error type. Contract will throw one of this errors.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum GranularPausableError {
+    /// Returned if the feature is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the caller is missing the `PAUSER_ROLE` role
+    #[from]
+    AccessControl(AccessControlError),
+}
@@ -25,10 +25,13 @@ use super::{
     PausableError,
     ReentrancyGuardError,
 };
-use openbrush::traits::String;
+use openbrush::traits::{
+    ErrorFrom,
+    String,
+};
 
 /// The PaymentSplitter error type. Contract will throw one of this errors.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum PaymentSplitterError {
     Custom(String),
@@ -39,40 +42,16 @@ pub enum PaymentSplitterError {
     SharesAreZero,
     AlreadyHasShares,
     TransferFailed,
-}
-
-impl From<AccessControlError> for PaymentSplitterError {
-    fn from(access: AccessControlError) -> Self {
-        match access {
-            AccessControlError::MissingRole => PaymentSplitterError::Custom(String::from("AC::MissingRole")),
-            AccessControlError::RoleRedundant => PaymentSplitterError::Custom(String::from("AC::RoleRedundant")),
-            AccessControlError::InvalidCaller => PaymentSplitterError::Custom(String::from("AC::InvalidCaller")),
-        }
-    }
-}
-
-impl From<OwnableError> for PaymentSplitterError {
-    fn from(ownable: OwnableError) -> Self {
-        match ownable {
-            OwnableError::CallerIsNotOwner => PaymentSplitterError::Custom(String::from("O::CallerIsNotOwner")),
-            OwnableError::NewOwnerIsZero => PaymentSplitterError::Custom(String::from("O::NewOwnerIsZero")),
-        }
-    }
-}
-
-impl From<PausableError> for PaymentSplitterError {
-    fn from(pausable: PausableError) -> Self {
-        match pausable {
-            PausableError::Paused => PaymentSplitterError::Custom(String::from("P::Paused")),
-            PausableError::NotPaused => PaymentSplitterError::Custom(String::from("P::NotPaused")),
-        }
-    }
-}
-
-impl From<ReentrancyGuardError> for PaymentSplitterError {
-    fn from(guard: ReentrancyGuardError) -> Self {
-        match guard {
-            ReentrancyGuardError::ReentrantCall => PaymentSplitterError::Custom(String::from("RG::ReentrantCall")),
-        }
-    }
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
 }
@@ -21,15 +21,20 @@
 
 use super::{
     AccessControlError,
+    InitializableError,
     NoncesError,
     OwnableError,
     PausableError,
     ReentrancyGuardError,
 };
-use openbrush::traits::String;
+use ink::LangError;
+use openbrush::traits::{
+    ErrorFrom,
+    String,
+};
 
 /// The PSP22 error type. Contract will throw one of this errors.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum PSP22Error {
     /// Custom error type for cases if writer of traits added own restrictions
@@ -49,42 +54,33 @@ pub enum PSP22Error {
     /// Returned if permit deadline is expired
     PermitExpired,
     /// Returned if permit nonce is invalid
+    #[from]
     NoncesError(NoncesError),
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
+    /// Returned if the contract can't be initialized
+    #[from]
+    Initializable(InitializableError),
+    /// Returned if the cross-contract call failed in the environment
+    CallFailed,
+    /// Returned if the callee couldn't dispatch the cross-contract call
+    #[from]
+    LangError(LangError),
 }
 
-impl From<OwnableError> for PSP22Error {
-    fn from(ownable: OwnableError) -> Self {
-        match ownable {
-            OwnableError::CallerIsNotOwner => PSP22Error::Custom(String::from("O::CallerIsNotOwner")),
-            OwnableError::NewOwnerIsZero => PSP22Error::Custom(String::from("O::NewOwnerIsZero")),
-        }
-    }
-}
-
-impl From<AccessControlError> for PSP22Error {
-    fn from(access: AccessControlError) -> Self {
-        match access {
-            AccessControlError::MissingRole => PSP22Error::Custom(String::from("AC::MissingRole")),
-            AccessControlError::RoleRedundant => PSP22Error::Custom(String::from("AC::RoleRedundant")),
-            AccessControlError::InvalidCaller => PSP22Error::Custom(String::from("AC::InvalidCaller")),
-        }
-    }
-}
-
-impl From<PausableError> for PSP22Error {
-    fn from(pausable: PausableError) -> Self {
-        match pausable {
-            PausableError::Paused => PSP22Error::Custom(String::from("P::Paused")),
-            PausableError::NotPaused => PSP22Error::Custom(String::from("P::NotPaused")),
-        }
-    }
-}
-
-impl From<ReentrancyGuardError> for PSP22Error {
-    fn from(guard: ReentrancyGuardError) -> Self {
-        match guard {
-            ReentrancyGuardError::ReentrantCall => PSP22Error::Custom(String::from("RG::ReentrantCall")),
-        }
+impl From<ink::env::Error> for PSP22Error {
+    fn from(_: ink::env::Error) -> Self {
+        PSP22Error::CallFailed
     }
 }
 
@@ -147,9 +143,3 @@ impl From<ReentrancyGuardError> for PSP22TokenTimelockError {
         PSP22TokenTimelockError::PSP22Error(guard.into())
     }
 }
-
-impl From<NoncesError> for PSP22Error {
-    fn from(error: NoncesError) -> Self {
-        PSP22Error::NoncesError(error)
-    }
-}
@@ -21,14 +21,18 @@
 
 use super::{
     AccessControlError,
+    InitializableError,
     OwnableError,
     PausableError,
     ReentrancyGuardError,
 };
-use openbrush::traits::String;
+use openbrush::traits::{
+    ErrorFrom,
+    String,
+};
 
 /// The PSP34 error type. Contract will throw one of this errors.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum PSP34Error {
     /// Custom error type for cases if writer of traits added own restrictions
@@ -43,42 +47,21 @@ pub enum PSP34Error {
     TokenNotExists,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
-}
-
-impl From<OwnableError> for PSP34Error {
-    fn from(ownable: OwnableError) -> Self {
-        match ownable {
-            OwnableError::CallerIsNotOwner => PSP34Error::Custom(String::from("O::CallerIsNotOwner")),
-            OwnableError::NewOwnerIsZero => PSP34Error::Custom(String::from("O::NewOwnerIsZero")),
-        }
-    }
-}
-
-impl From<AccessControlError> for PSP34Error {
-    fn from(access: AccessControlError) -> Self {
-        match access {
-            AccessControlError::MissingRole => PSP34Error::Custom(String::from("AC::MissingRole")),
-            AccessControlError::RoleRedundant => PSP34Error::Custom(String::from("AC::RoleRedundant")),
-            AccessControlError::InvalidCaller => PSP34Error::Custom(String::from("AC::InvalidCaller")),
-        }
-    }
-}
-
-impl From<PausableError> for PSP34Error {
-    fn from(pausable: PausableError) -> Self {
-        match pausable {
-            PausableError::Paused => PSP34Error::Custom(String::from("P::Paused")),
-            PausableError::NotPaused => PSP34Error::Custom(String::from("P::NotPaused")),
-        }
-    }
-}
-
-impl From<ReentrancyGuardError> for PSP34Error {
-    fn from(guard: ReentrancyGuardError) -> Self {
-        match guard {
-            ReentrancyGuardError::ReentrantCall => PSP34Error::Custom(String::from("RG::ReentrantCall")),
-        }
-    }
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
+    /// Returned if the contract can't be initialized
+    #[from]
+    Initializable(InitializableError),
 }
 
 /// The PSP34Receiver error types.
@@ -21,14 +21,18 @@
 
 use super::{
     AccessControlError,
+    InitializableError,
     OwnableError,
     PausableError,
     ReentrancyGuardError,
 };
-use openbrush::traits::String;
+use openbrush::traits::{
+    ErrorFrom,
+    String,
+};
 
 /// The PSP37 error type. Contract will throw one of this errors.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum PSP37Error {
     /// Custom error type for cases if writer of traits added own restrictions
@@ -45,42 +49,23 @@ pub enum PSP37Error {
     SelfApprove,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
-}
-
-impl From<OwnableError> for PSP37Error {
-    fn from(ownable: OwnableError) -> Self {
-        match ownable {
-            OwnableError::CallerIsNotOwner => PSP37Error::Custom(String::from("O::CallerIsNotOwner")),
-            OwnableError::NewOwnerIsZero => PSP37Error::Custom(String::from("O::NewOwnerIsZero")),
-        }
-    }
-}
-
-impl From<AccessControlError> for PSP37Error {
-    fn from(access: AccessControlError) -> Self {
-        match access {
-            AccessControlError::MissingRole => PSP37Error::Custom(String::from("AC::MissingRole")),
-            AccessControlError::RoleRedundant => PSP37Error::Custom(String::from("AC::RoleRedundant")),
-            AccessControlError::InvalidCaller => PSP37Error::Custom(String::from("AC::InvalidCaller")),
-        }
-    }
-}
-
-impl From<PausableError> for PSP37Error {
-    fn from(pausable: PausableError) -> Self {
-        match pausable {
-            PausableError::Paused => PSP37Error::Custom(String::from("P::Paused")),
-            PausableError::NotPaused => PSP37Error::Custom(String::from("P::NotPaused")),
-        }
-    }
-}
-
-impl From<ReentrancyGuardError> for PSP37Error {
-    fn from(guard: ReentrancyGuardError) -> Self {
-        match guard {
-            ReentrancyGuardError::ReentrantCall => PSP37Error::Custom(String::from("RG::ReentrantCall")),
-        }
-    }
+    /// Returned if minting exceeds the max supply of the token
+    MaxSupplyExceeded,
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
+    /// Returned if the contract can't be initialized
+    #[from]
+    Initializable(InitializableError),
 }
 
 /// The PSP37Receiver error types.
@@ -25,14 +25,19 @@ use super::{
     PausableError,
     ReentrancyGuardError,
 };
-use openbrush::traits::String;
+use openbrush::traits::{
+    ErrorFrom,
+    String,
+};
 
 /// The TimelockController error type. Contract will throw one of this errors.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum TimelockControllerError {
     Custom(String),
-    AccessControlError(AccessControlError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
     InsufficientDelay,
     OperationAlreadyScheduled,
     OperationCannonBeCanceled,
@@ -41,46 +46,13 @@ pub enum TimelockControllerError {
     UnderlyingTransactionReverted,
     CallerMustBeTimeLock,
     CalleeMustExist,
-}
-
-impl From<AccessControlError> for TimelockControllerError {
-    fn from(access: AccessControlError) -> Self {
-        match access {
-            AccessControlError::MissingRole => {
-                TimelockControllerError::AccessControlError(AccessControlError::MissingRole)
-            }
-            AccessControlError::RoleRedundant => {
-                TimelockControllerError::AccessControlError(AccessControlError::RoleRedundant)
-            }
-            AccessControlError::InvalidCaller => {
-                TimelockControllerError::AccessControlError(AccessControlError::InvalidCaller)
-            }
-        }
-    }
-}
-
-impl From<OwnableError> for TimelockControllerError {
-    fn from(ownable: OwnableError) -> Self {
-        match ownable {
-            OwnableError::CallerIsNotOwner => TimelockControllerError::Custom(String::from("O::CallerIsNotOwner")),
-            OwnableError::NewOwnerIsZero => TimelockControllerError::Custom(String::from("O::NewOwnerIsZero")),
-        }
-    }
-}
-
-impl From<PausableError> for TimelockControllerError {
-    fn from(pausable: PausableError) -> Self {
-        match pausable {
-            PausableError::Paused => TimelockControllerError::Custom(String::from("P::Paused")),
-            PausableError::NotPaused => TimelockControllerError::Custom(String::from("P::NotPaused")),
-        }
-    }
-}
-
-impl From<ReentrancyGuardError> for TimelockControllerError {
-    fn from(guard: ReentrancyGuardError) -> Self {
-        match guard {
-            ReentrancyGuardError::ReentrantCall => TimelockControllerError::Custom(String::from("RG::ReentrantCall")),
-        }
-    }
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
 }
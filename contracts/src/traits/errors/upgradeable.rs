@@ -1,29 +1,32 @@
 use super::{
     AccessControlError,
     OwnableError,
+    PausableError,
+    ReentrancyGuardError,
+};
+use openbrush::traits::{
+    ErrorFrom,
+    String,
 };
-use openbrush::traits::String;
 
 /// The PSP34 error type. Contract will throw one of this errors.
-#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum UpgradeableError {
     /// Custom error type for cases if writer of traits added own restrictions
     Custom(String),
     /// Returned if the upgrade failed
     SetCodeHashFailed,
-    OwnableError(OwnableError),
-    AccessControlError(AccessControlError),
-}
-
-impl From<OwnableError> for UpgradeableError {
-    fn from(error: OwnableError) -> Self {
-        UpgradeableError::OwnableError(error)
-    }
-}
-
-impl From<AccessControlError> for UpgradeableError {
-    fn from(error: AccessControlError) -> Self {
-        UpgradeableError::AccessControlError(error)
-    }
+    /// Returned if the caller is not the owner
+    #[from]
+    Ownable(OwnableError),
+    /// Returned if the caller is missing the role or the role can't be changed
+    #[from]
+    AccessControl(AccessControlError),
+    /// Returned if the contract is paused or not paused
+    #[from]
+    Pausable(PausableError),
+    /// Returned if the call is reentrant
+    #[from]
+    ReentrancyGuard(ReentrancyGuardError),
 }
@@ -0,0 +1,45 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::GovernanceError;
+use openbrush::traits::Timestamp;
+
+/// Extension of `Governor` that ensures there is a minimum voting period after quorum is reached.
+/// This prevents a large voter from swaying a vote and triggering quorum at the last moment, by ensuring
+/// there is always time for other voters to react and try to oppose the decision.
+///
+/// If a vote causes quorum to be reached, the proposal's voting period may be extended so that it does not
+/// end before at least a specified time has passed (the "vote extension" parameter).
+#[openbrush::trait_definition]
+pub trait GovernorPreventLateQuorum {
+    /// Returns the current value of the vote extension parameter: the time that is required to pass
+    /// from the moment a proposal reaches quorum until its voting period ends
+    #[ink(message)]
+    fn late_quorum_vote_extension(&self) -> Timestamp;
+
+    /// Changes the vote extension parameter, which can only be done through a governance proposal
+    #[ink(message)]
+    fn set_late_quorum_vote_extension(&mut self, new_vote_extension: Timestamp) -> Result<(), GovernanceError>;
+}
+
+#[openbrush::wrapper]
+pub type GovernorPreventLateQuorumRef = dyn GovernorPreventLateQuorum;
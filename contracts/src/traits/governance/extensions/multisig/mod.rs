@@ -0,0 +1,123 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::{
+    errors::MultisigError,
+    governance::Transaction,
+};
+use ink::prelude::vec::Vec;
+use openbrush::traits::AccountId;
+
+pub type TransactionId = u128;
+
+#[openbrush::wrapper]
+pub type MultisigRef = dyn Multisig;
+
+/// Contract module which implements a multi-signature wallet. A set of owners
+/// proposes transactions and approves them, and a transaction can be executed
+/// by any owner once it was approved by at least `threshold` owners.
+///
+/// Owners and the threshold are managed by the multisig itself, so changing
+/// them requires proposing and executing a transaction that calls
+/// `add_owner`, `remove_owner` or `change_threshold` on the multisig.
+#[openbrush::trait_definition]
+pub trait Multisig {
+    /// Returns the list of owners.
+    #[ink(message)]
+    fn owners(&self) -> Vec<AccountId>;
+
+    /// Returns `true` if `account` is one of the owners.
+    #[ink(message)]
+    fn is_owner(&self, account: AccountId) -> bool;
+
+    /// Returns the number of approvals required to execute a transaction.
+    #[ink(message)]
+    fn threshold(&self) -> u32;
+
+    /// Returns the pending transaction with `id` if it exists.
+    #[ink(message)]
+    fn transaction(&self, id: TransactionId) -> Option<Transaction>;
+
+    /// Returns `true` if `owner` approved the transaction with `id`.
+    #[ink(message)]
+    fn has_approved(&self, id: TransactionId, owner: AccountId) -> bool;
+
+    /// Returns the number of approvals of the transaction with `id` given by current owners.
+    #[ink(message)]
+    fn approval_count(&self, id: TransactionId) -> u32;
+
+    /// Proposes a new transaction and approves it on behalf of the caller.
+    ///
+    /// On success a `TransactionProposed` and a `TransactionApproved` events are emitted.
+    ///
+    /// Note: The caller must be an owner.
+    #[ink(message)]
+    fn propose(&mut self, transaction: Transaction) -> Result<TransactionId, MultisigError>;
+
+    /// Approves the transaction with `id`.
+    ///
+    /// On success a `TransactionApproved` event is emitted.
+    ///
+    /// Note: The caller must be an owner.
+    #[ink(message)]
+    fn approve(&mut self, id: TransactionId) -> Result<(), MultisigError>;
+
+    /// Revokes the approval of the caller for the transaction with `id`.
+    ///
+    /// On success a `ApprovalRevoked` event is emitted.
+    ///
+    /// Note: The caller must be an owner.
+    #[ink(message)]
+    fn revoke(&mut self, id: TransactionId) -> Result<(), MultisigError>;
+
+    /// Executes the transaction with `id` if it has enough approvals.
+    ///
+    /// On success a `TransactionExecuted` event is emitted.
+    ///
+    /// Note: The caller must be an owner.
+    #[ink(message, payable)]
+    fn execute(&mut self, id: TransactionId) -> Result<(), MultisigError>;
+
+    /// Adds a new owner.
+    ///
+    /// On success a `OwnerAdded` event is emitted.
+    ///
+    /// Note: Can only be called by the multisig itself.
+    #[ink(message)]
+    fn add_owner(&mut self, owner: AccountId) -> Result<(), MultisigError>;
+
+    /// Removes an owner. The threshold can't become bigger than the number of owners.
+    ///
+    /// On success a `OwnerRemoved` event is emitted.
+    ///
+    /// Note: Can only be called by the multisig itself.
+    #[ink(message)]
+    fn remove_owner(&mut self, owner: AccountId) -> Result<(), MultisigError>;
+
+    /// Changes the number of approvals required to execute a transaction.
+    ///
+    /// On success a `ThresholdChanged` event is emitted.
+    ///
+    /// Note: Can only be called by the multisig itself.
+    #[ink(message)]
+    fn change_threshold(&mut self, threshold: u32) -> Result<(), MultisigError>;
+}
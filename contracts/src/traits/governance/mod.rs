@@ -34,8 +34,12 @@ pub use governor::*;
 
 pub mod extensions {
     pub mod governor_counting;
+    pub mod governor_counting_fractional;
+    pub mod governor_prevent_late_quorum;
     pub mod governor_quorum;
     pub mod governor_settings;
+    pub mod governor_timelock;
+    pub mod multisig;
     pub mod timelock_controller;
 }
 
@@ -25,6 +25,7 @@ pub mod diamond;
 pub mod errors;
 pub mod flashloan;
 pub mod governance;
+pub mod initializable;
 pub mod nonces;
 pub mod ownable;
 pub mod pausable;
@@ -34,6 +35,7 @@ pub mod psp22;
 pub mod psp34;
 pub mod psp37;
 pub mod psp61;
+pub mod reentrancy_guard;
 pub mod upgradeable;
 
 pub mod types;
@@ -22,6 +22,8 @@
 pub use crate::traits::errors::OwnableError;
 use openbrush::traits::AccountId;
 
+pub mod ownable_2_step;
+
 #[openbrush::wrapper]
 pub type OwnableRef = dyn Ownable;
 
@@ -0,0 +1,67 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::{
+    errors::GranularPausableError,
+    pausable::*,
+};
+
+/// Identifier of a pausable feature. It can be any unique number,
+/// e.g. `ink::selector_id!("MINT")` or the selector of the guarded message.
+pub type Feature = u32;
+
+#[openbrush::wrapper]
+pub type GranularPausableRef = dyn GranularPausable;
+
+/// Extension of Pausable that allows pausing separate features of the contract
+/// instead of the whole contract. Features are paused and unpaused by the accounts
+/// with the `PAUSER_ROLE` role.
+#[openbrush::trait_definition]
+pub trait GranularPausable {
+    /// Returns true if the `feature` is paused, and false otherwise.
+    #[ink(message)]
+    fn feature_paused(&self, feature: Feature) -> bool;
+
+    /// Pauses the `feature`.
+    ///
+    /// On success a `FeaturePaused` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller doesn't have the `PAUSER_ROLE` role.
+    ///
+    /// Returns with `Paused` error if the `feature` is already paused.
+    #[ink(message)]
+    fn pause_feature(&mut self, feature: Feature) -> Result<(), GranularPausableError>;
+
+    /// Unpauses the `feature`.
+    ///
+    /// On success a `FeatureUnpaused` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller doesn't have the `PAUSER_ROLE` role.
+    ///
+    /// Returns with `NotPaused` error if the `feature` is not paused.
+    #[ink(message)]
+    fn unpause_feature(&mut self, feature: Feature) -> Result<(), GranularPausableError>;
+}
@@ -21,6 +21,8 @@
 
 pub use crate::traits::errors::PausableError;
 
+pub mod granular_pausable;
+
 #[openbrush::wrapper]
 pub type PausableRef = dyn Pausable;
 
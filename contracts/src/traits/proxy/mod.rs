@@ -25,6 +25,8 @@ pub use crate::traits::{
 };
 use openbrush::traits::Hash;
 
+pub mod transparent_proxy;
+
 #[openbrush::wrapper]
 pub type ProxyRef = dyn Proxy + Ownable;
 
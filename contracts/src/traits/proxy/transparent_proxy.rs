@@ -0,0 +1,76 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::{
+    diamond::InitCall,
+    errors::ProxyError,
+};
+use openbrush::traits::{
+    AccountId,
+    Hash,
+};
+
+#[openbrush::wrapper]
+pub type TransparentProxyRef = dyn TransparentProxy;
+
+/// Proxy with a dedicated admin. The messages of the proxy are available only for the admin,
+/// calls from other accounts are forwarded to the implementation, so selectors of the proxy
+/// can't clash with selectors of the implementation. The admin can't call the implementation
+/// through the proxy.
+#[openbrush::trait_definition]
+pub trait TransparentProxy {
+    /// Returns the admin of the proxy.
+    #[ink(message)]
+    fn proxy_admin(&self) -> Option<AccountId>;
+
+    /// Returns the code hash of the implementation.
+    #[ink(message)]
+    fn proxy_implementation(&self) -> Hash;
+
+    /// Transfers the admin rights of the proxy to `new_admin`.
+    ///
+    /// On success a `AdminChanged` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `NewAdminIsZero` error if `new_admin` is the zero account.
+    #[ink(message)]
+    fn change_proxy_admin(&mut self, new_admin: AccountId) -> Result<(), ProxyError>;
+
+    /// Changes the code hash of the implementation.
+    ///
+    /// On success a `DelegateCodeChanged` event is emitted.
+    #[ink(message)]
+    fn upgrade_to(&mut self, code_hash: Hash) -> Result<(), ProxyError>;
+
+    /// Changes the code hash of the implementation and delegates the `init` call in the same
+    /// transaction, e.g. to initialize the storage of the new implementation. Usually the hash
+    /// of the `init` is the same as `code_hash`.
+    ///
+    /// On success a `DelegateCodeChanged` event is emitted.
+    ///
+    /// The `init` call is a tail delegate call, so on success the message never returns: the caller
+    /// receives the output of the `init` message instead of `Ok(())`. If the `init` call fails,
+    /// the whole transaction is reverted, including the upgrade.
+    #[ink(message)]
+    fn upgrade_to_and_call(&mut self, code_hash: Hash, init: InitCall) -> Result<(), ProxyError>;
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that keeps the history of balances and total supply
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+/// Identifier of the snapshot. The first snapshot has id `1`.
+pub type SnapshotId = u64;
+
+#[openbrush::wrapper]
+pub type PSP22SnapshotRef = dyn PSP22Snapshot;
+
+#[openbrush::trait_definition]
+pub trait PSP22Snapshot {
+    /// Returns the balance of `account` at the time `snapshot_id` was created.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error if `snapshot_id` is zero or the snapshot doesn't exist yet.
+    #[ink(message)]
+    fn balance_of_at(&self, account: AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error>;
+
+    /// Returns the total supply at the time `snapshot_id` was created.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error if `snapshot_id` is zero or the snapshot doesn't exist yet.
+    #[ink(message)]
+    fn total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error>;
+
+    /// Returns the id of the most recent snapshot, or `0` if no snapshot was taken.
+    #[ink(message)]
+    fn current_snapshot_id(&self) -> SnapshotId;
+}
@@ -0,0 +1,150 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] which implements a tokenized vault, where the token represents shares of the underlying asset
+pub use crate::traits::errors::PSP22Error;
+pub use crate::traits::psp22::*;
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+/// The vault is PSP22 by itself, its tokens are shares of the underlying `asset` held by the vault.
+/// The conversion rate between shares and assets depends on the `total_assets` of the vault.
+#[openbrush::wrapper]
+pub type PSP22VaultRef = dyn PSP22Vault + PSP22;
+
+#[openbrush::trait_definition]
+pub trait PSP22Vault: PSP22 {
+    /// Returns the address of the underlying PSP22 token used by the vault.
+    #[ink(message)]
+    fn asset(&self) -> Option<AccountId>;
+
+    /// Returns the total amount of the underlying asset managed by the vault.
+    #[ink(message)]
+    fn total_assets(&self) -> Balance;
+
+    /// Returns the amount of shares that the vault would exchange for the `assets` provided.
+    ///
+    /// The result is rounded down.
+    #[ink(message)]
+    fn convert_to_shares(&self, assets: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of assets that the vault would exchange for the `shares` provided.
+    ///
+    /// The result is rounded down.
+    #[ink(message)]
+    fn convert_to_assets(&self, shares: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the maximum amount of the underlying asset that can be deposited for the `receiver`.
+    #[ink(message)]
+    fn max_deposit(&self, receiver: AccountId) -> Balance;
+
+    /// Returns the maximum amount of shares that can be minted for the `receiver`.
+    #[ink(message)]
+    fn max_mint(&self, receiver: AccountId) -> Balance;
+
+    /// Returns the maximum amount of the underlying asset that can be withdrawn from the `owner` balance.
+    #[ink(message)]
+    fn max_withdraw(&self, owner: AccountId) -> Result<Balance, PSP22Error>;
+
+    /// Returns the maximum amount of shares that can be redeemed from the `owner` balance.
+    #[ink(message)]
+    fn max_redeem(&self, owner: AccountId) -> Balance;
+
+    /// Returns the amount of shares that would be minted by depositing `assets` in the current block.
+    ///
+    /// The result is rounded down, so the depositor never receives more than the assets are worth.
+    #[ink(message)]
+    fn preview_deposit(&self, assets: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of assets that would be taken for minting `shares` in the current block.
+    ///
+    /// The result is rounded up, so the minter never pays less than the shares are worth.
+    #[ink(message)]
+    fn preview_mint(&self, shares: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of shares that would be burned by withdrawing `assets` in the current block.
+    ///
+    /// The result is rounded up, so the withdrawer never burns less than the assets are worth.
+    #[ink(message)]
+    fn preview_withdraw(&self, assets: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Returns the amount of assets that would be withdrawn by redeeming `shares` in the current block.
+    ///
+    /// The result is rounded down, so the redeemer never receives more than the shares are worth.
+    #[ink(message)]
+    fn preview_redeem(&self, shares: Balance) -> Result<Balance, PSP22Error>;
+
+    /// Deposits `assets` of the underlying token from the caller and mints the corresponding shares to `receiver`.
+    ///
+    /// Returns the amount of minted shares.
+    ///
+    /// On success a `Deposit` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error if `assets` is more than `max_deposit`.
+    #[ink(message)]
+    fn deposit(&mut self, assets: Balance, receiver: AccountId) -> Result<Balance, PSP22Error>;
+
+    /// Mints exactly `shares` to `receiver` by depositing the corresponding amount of the underlying token from the caller.
+    ///
+    /// Returns the amount of deposited assets.
+    ///
+    /// On success a `Deposit` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error if `shares` is more than `max_mint`.
+    #[ink(message)]
+    fn mint(&mut self, shares: Balance, receiver: AccountId) -> Result<Balance, PSP22Error>;
+
+    /// Burns shares from `owner` and sends exactly `assets` of the underlying token to `receiver`.
+    ///
+    /// Returns the amount of burned shares.
+    ///
+    /// On success a `Withdraw` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error if `assets` is more than `max_withdraw`.
+    ///
+    /// Returns `InsufficientAllowance` error if the caller is not the `owner` and is not allowed to spend the shares.
+    #[ink(message)]
+    fn withdraw(&mut self, assets: Balance, receiver: AccountId, owner: AccountId) -> Result<Balance, PSP22Error>;
+
+    /// Burns exactly `shares` from `owner` and sends the corresponding amount of the underlying token to `receiver`.
+    ///
+    /// Returns the amount of withdrawn assets.
+    ///
+    /// On success a `Withdraw` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Custom` error if `shares` is more than `max_redeem`.
+    ///
+    /// Returns `InsufficientAllowance` error if the caller is not the `owner` and is not allowed to spend the shares.
+    #[ink(message)]
+    fn redeem(&mut self, shares: Balance, receiver: AccountId, owner: AccountId) -> Result<Balance, PSP22Error>;
+}
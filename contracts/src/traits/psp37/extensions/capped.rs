@@ -0,0 +1,42 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP37`] that tracks the existence of tokens and adds a max supply per token type
+pub use crate::traits::psp37::{
+    Id,
+    PSP37Error,
+};
+use openbrush::traits::Balance;
+
+#[openbrush::wrapper]
+pub type PSP37CappedRef = dyn PSP37Capped;
+
+#[openbrush::trait_definition]
+pub trait PSP37Capped {
+    /// Returns `true` if any amount of token type `id` was minted and not burned.
+    #[ink(message)]
+    fn exists(&self, id: Id) -> bool;
+
+    /// Returns the max supply of token type `id`, or `None` if its supply is not capped.
+    #[ink(message)]
+    fn max_supply(&self, id: Id) -> Option<Balance>;
+}
@@ -26,6 +26,7 @@ pub use psp37::*;
 pub mod extensions {
     pub mod batch;
     pub mod burnable;
+    pub mod capped;
     pub mod enumerable;
     pub mod metadata;
     pub mod mintable;
@@ -22,6 +22,10 @@
 // Delegate calls were marked as a possible attack vector in ink!
 // Therefore the proxy and diamond contracts will be disabled within OpenBrush until this is reimplemented in ink! 4.
 
+use crate::traits::{
+    diamond::extensions::diamond_loupe::diamondloupe_external,
+    psp61::psp61_external,
+};
 pub use crate::{
     diamond,
     ownable,
@@ -52,13 +56,21 @@ use openbrush::{
 };
 pub use ownable::Internal as _;
 
-// TODO: Add support of Erc165
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
     pub selector_to_hash: Mapping<Selector, Hash>,
     // Facet mapped to all functions it supports
     pub hash_to_selectors: Mapping<Hash, Vec<Selector>>,
+    // Storage key mapped to the facet which owns it
+    pub storage_key_to_hash: Mapping<u32, Hash>,
+    // Facet mapped to all storage keys it owns
+    pub hash_to_storage_keys: Mapping<Hash, Vec<u32>>,
+    // Selectors which can't be replaced or removed
+    pub immutable_selectors: Mapping<Selector, ()>,
+    // Interfaces supported by the diamond
+    #[lazy]
+    pub supported_interfaces: Vec<u32>,
 }
 
 pub trait DiamondImpl: Internal + Storage<ownable::Data> {
@@ -75,6 +87,18 @@ pub trait Internal {
 
     fn _diamond_cut_facet(&mut self, facet_cut: &FacetCut) -> Result<(), DiamondError>;
 
+    fn _facet_info(&self, code_hash: Hash) -> Result<FacetInfo, DiamondError>;
+
+    fn _require_facet_info(&self) -> bool;
+
+    fn _claim_storage_keys(&mut self, code_hash: Hash, storage_keys: &[u32]);
+
+    fn _make_selectors_immutable(&mut self, selectors: &[Selector]) -> Result<(), DiamondError>;
+
+    fn _register_interfaces(&mut self);
+
+    fn _supported_interfaces(&self) -> Vec<u32>;
+
     fn _fallback(&self) -> !;
 
     fn _init_call(&self, call: InitCall) -> !;
@@ -92,6 +116,7 @@ pub trait InternalImpl: Internal + Storage<Data> + DiamondCut {
             Internal::_diamond_cut_facet(self, facet_cut)?;
         }
 
+        Internal::_register_interfaces(self);
         Internal::_emit_diamond_cut_event(self, &diamond_cut, &init);
 
         if let Some(init) = init {
@@ -106,19 +131,62 @@ pub trait InternalImpl: Internal + Storage<Data> + DiamondCut {
         if code_hash.is_clear() {
             return Err(DiamondError::EmptyCodeHash)
         }
+        let registered_selectors = self
+            .data()
+            .hash_to_selectors
+            .get(&code_hash)
+            .unwrap_or(Vec::<Selector>::new());
+        for selector in registered_selectors.iter() {
+            if !facet_cut.selectors.contains(selector) && self.data().immutable_selectors.contains(selector) {
+                // immutable selector would be removed -> error
+                return Err(DiamondError::ImmutableSelector(*selector))
+            }
+        }
+
         if facet_cut.selectors.is_empty() {
             // means that we want to remove this facet
             Internal::_remove_facet(self, code_hash);
         } else {
+            let facet_info = match Internal::_facet_info(self, code_hash) {
+                Ok(facet_info) => {
+                    if let Some(selector) = facet_cut
+                        .selectors
+                        .iter()
+                        .find(|selector| !facet_info.selectors.contains(selector))
+                    {
+                        // facet doesn't expose the selector -> error
+                        return Err(DiamondError::SelectorNotExposed(*selector))
+                    }
+                    facet_info
+                }
+                // facet doesn't implement `DiamondFacet` -> it is added without validation
+                Err(DiamondError::FacetInfoUnavailable(_)) if !Internal::_require_facet_info(self) => {
+                    FacetInfo::default()
+                }
+                Err(error) => return Err(error),
+            };
+
+            for key in facet_info.storage_keys.iter() {
+                match self.data().storage_key_to_hash.get(key) {
+                    // storage key is owned by another facet -> error
+                    Some(owner) if owner != code_hash => return Err(DiamondError::StorageCollision(*key, owner)),
+                    _ => {}
+                }
+            }
+
             for selector in facet_cut.selectors.iter() {
                 let selector_hash = self.data().selector_to_hash.get(selector);
 
                 if selector_hash.map(|hash| hash == code_hash).unwrap_or(false) {
                     // selector already registered to this hash -> no action
                     continue
-                } else if selector_hash.is_some() {
+                } else if let Some(registered_hash) = selector_hash {
+                    if self.data().immutable_selectors.contains(selector) {
+                        // selector is registered to another hash and can't be replaced -> error
+                        return Err(DiamondError::ImmutableSelector(*selector))
+                    }
                     // selector already registered to another hash -> error
-                    return Err(DiamondError::ReplaceExisting(selector_hash.unwrap()))
+                    return Err(DiamondError::ReplaceExisting(registered_hash))
                 } else {
                     // map selector to its facet
                     self.data().selector_to_hash.insert(selector, &code_hash);
@@ -132,10 +200,72 @@ pub trait InternalImpl: Internal + Storage<Data> + DiamondCut {
             Internal::_remove_selectors(self, facet_cut);
             // map this code hash to its selectors
             self.data().hash_to_selectors.insert(&code_hash, &facet_cut.selectors);
+            Internal::_claim_storage_keys(self, code_hash, &facet_info.storage_keys);
         }
         Ok(())
     }
 
+    fn _facet_info(&self, code_hash: Hash) -> Result<FacetInfo, DiamondError> {
+        ink::env::call::build_call::<ink::env::DefaultEnvironment>()
+            .delegate(code_hash)
+            .exec_input(ExecutionInput::new(InkSelector::new(ink::selector_bytes!(
+                "DiamondFacet::facet_info"
+            ))))
+            .returns::<FacetInfo>()
+            .try_invoke()
+            .map_err(|_| DiamondError::FacetInfoUnavailable(code_hash))?
+            .map_err(|_| DiamondError::FacetInfoUnavailable(code_hash))
+    }
+
+    fn _require_facet_info(&self) -> bool {
+        false
+    }
+
+    fn _claim_storage_keys(&mut self, code_hash: Hash, storage_keys: &[u32]) {
+        let old_keys = self
+            .data()
+            .hash_to_storage_keys
+            .get(&code_hash)
+            .unwrap_or(Vec::<u32>::new());
+        for key in old_keys.iter() {
+            if !storage_keys.contains(key) {
+                self.data().storage_key_to_hash.remove(key);
+            }
+        }
+        for key in storage_keys.iter() {
+            self.data().storage_key_to_hash.insert(key, &code_hash);
+        }
+        self.data()
+            .hash_to_storage_keys
+            .insert(&code_hash, &storage_keys.to_vec());
+    }
+
+    fn _make_selectors_immutable(&mut self, selectors: &[Selector]) -> Result<(), DiamondError> {
+        for selector in selectors.iter() {
+            if self.data().selector_to_hash.get(selector).is_none() {
+                return Err(DiamondError::FunctionDoesNotExist)
+            }
+        }
+        for selector in selectors.iter() {
+            self.data().immutable_selectors.insert(selector, &());
+        }
+        Ok(())
+    }
+
+    fn _register_interfaces(&mut self) {
+        let mut interfaces = self.data().supported_interfaces.get_or_default();
+        for interface_id in [diamondloupe_external::TRAIT_ID, psp61_external::TRAIT_ID] {
+            if !interfaces.contains(&interface_id) {
+                interfaces.push(interface_id);
+            }
+        }
+        self.data().supported_interfaces.set(&interfaces);
+    }
+
+    fn _supported_interfaces(&self) -> Vec<u32> {
+        self.data().supported_interfaces.get_or_default()
+    }
+
     fn _fallback(&self) -> ! {
         let selector = ink::env::decode_input::<Selector>().unwrap_or_else(|_| panic!("Calldata error"));
 
@@ -183,6 +313,8 @@ pub trait InternalImpl: Internal + Storage<Data> + DiamondCut {
             self.data().selector_to_hash.remove(old_selector);
         });
         self.data().hash_to_selectors.remove(&code_hash);
+        Internal::_claim_storage_keys(self, code_hash, &[]);
+        self.data().hash_to_storage_keys.remove(&code_hash);
         self._on_remove_facet(code_hash);
     }
 
@@ -0,0 +1,58 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    diamond::extensions::diamond_facet,
+    traits::diamond::extensions::diamond_facet::*,
+};
+pub use diamond_facet::{
+    Internal as _,
+    InternalImpl as _,
+};
+use ink::prelude::vec::Vec;
+
+pub trait DiamondFacetImpl: Internal {
+    fn facet_info(&self) -> FacetInfo {
+        FacetInfo {
+            selectors: self._facet_selectors(),
+            storage_keys: self._facet_storage_keys(),
+        }
+    }
+}
+
+pub trait Internal {
+    /// Returns the selectors of all messages of the facet.
+    /// The implementation is generated by `openbrush::implementation(DiamondFacet)`.
+    fn _facet_selectors(&self) -> Vec<Selector>;
+
+    /// Returns the storage keys owned by the facet.
+    /// The implementation generated by `openbrush::implementation(DiamondFacet)` returns the keys of all
+    /// `#[storage_field]`s of the contract, so it should be overridden if the facet shares storage with
+    /// other facets(like `ownable::Data`), because keys of the shared storage should not be listed.
+    fn _facet_storage_keys(&self) -> Vec<u32>;
+}
+
+pub trait InternalImpl: Internal {
+    fn _facet_selectors(&self) -> Vec<Selector> {
+        Vec::new()
+    }
+}
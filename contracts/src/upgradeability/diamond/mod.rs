@@ -23,5 +23,6 @@ pub mod diamond;
 
 pub use diamond::*;
 pub mod extensions {
+    pub mod diamond_facet;
     pub mod diamond_loupe;
 }
@@ -0,0 +1,159 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    initializable,
+    traits::initializable::*,
+};
+pub use initializable::{
+    InitializableImpl as _,
+    Internal as _,
+    InternalImpl as _,
+};
+use openbrush::{
+    modifier_definition,
+    traits::Storage,
+};
+
+/// The storage keys of the fields are generated from the names by `storage_unique_key!`,
+/// so they don't depend on the layout of the contract that uses the module.
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub initialized: u64,
+    #[lazy]
+    pub initializing: bool,
+}
+
+/// Modifier to make a function callable only once, when the contract is not initialized yet.
+/// The version of the contract becomes `1`.
+///
+/// On success a `Initialized` event is emitted.
+#[modifier_definition]
+pub fn initializer<T, F, R, E>(instance: &mut T, body: F) -> Result<R, E>
+where
+    T: Storage<Data> + Internal,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<InitializableError>,
+{
+    initialize(instance, body, 1)
+}
+
+/// Modifier to make a function callable only once for the `version`, if the contract
+/// is initialized with the lower version. It is used to initialize the new storage after
+/// the upgrade of the contract.
+///
+/// On success a `Initialized` event is emitted.
+#[modifier_definition]
+pub fn reinitializer<T, F, R, E>(instance: &mut T, body: F, version: u64) -> Result<R, E>
+where
+    T: Storage<Data> + Internal,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<InitializableError>,
+{
+    initialize(instance, body, version)
+}
+
+/// Modifier to make a function callable only from `initializer` or `reinitializer` functions.
+#[modifier_definition]
+pub fn only_initializing<T, F, R, E>(instance: &mut T, body: F) -> Result<R, E>
+where
+    T: Storage<Data>,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<InitializableError>,
+{
+    if !instance.data().initializing.get_or_default() {
+        return Err(From::from(InitializableError::NotInitializing))
+    }
+    body(instance)
+}
+
+fn initialize<T, F, R, E>(instance: &mut T, body: F, version: u64) -> Result<R, E>
+where
+    T: Storage<Data> + Internal,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<InitializableError>,
+{
+    let previous = instance.data().initialized.get_or_default();
+    if instance.data().initializing.get_or_default() || previous >= version {
+        return Err(From::from(InitializableError::InvalidInitialization))
+    }
+
+    instance.data().initialized.set(&version);
+    instance.data().initializing.set(&true);
+
+    let result = body(instance);
+    instance.data().initializing.set(&false);
+
+    match result {
+        Ok(_) => instance._emit_initialized_event(version),
+        // The contract stays not initialized if the initialization failed.
+        Err(_) => instance.data().initialized.set(&previous),
+    }
+    result
+}
+
+pub trait InitializableImpl: Internal {
+    fn initialized_version(&self) -> u64 {
+        self._get_initialized_version()
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_initialized_event(&self, _version: u64);
+
+    fn _get_initialized_version(&self) -> u64;
+
+    fn _is_initializing(&self) -> bool;
+
+    /// Locks the contract, so it can't be initialized anymore. It is useful for the
+    /// code which is used only through delegate calls.
+    ///
+    /// On success a `Initialized` event is emitted.
+    fn _disable_initializers(&mut self) -> Result<(), InitializableError>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal {
+    fn _emit_initialized_event(&self, _version: u64) {}
+
+    fn _get_initialized_version(&self) -> u64 {
+        self.data().initialized.get_or_default()
+    }
+
+    fn _is_initializing(&self) -> bool {
+        self.data().initializing.get_or_default()
+    }
+
+    fn _disable_initializers(&mut self) -> Result<(), InitializableError> {
+        if self.data().initializing.get_or_default() {
+            return Err(InitializableError::InvalidInitialization)
+        }
+
+        if self.data().initialized.get_or_default() != u64::MAX {
+            self.data().initialized.set(&u64::MAX);
+            Internal::_emit_initialized_event(self, u64::MAX);
+        }
+        Ok(())
+    }
+}
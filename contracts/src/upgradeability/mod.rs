@@ -20,6 +20,8 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 #[cfg(feature = "diamond")]
 pub mod diamond;
+#[cfg(feature = "initializable")]
+pub mod initializable;
 #[cfg(feature = "proxy")]
 pub mod proxy;
 
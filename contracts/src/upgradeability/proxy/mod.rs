@@ -46,6 +46,8 @@ pub use proxy::{
     InternalImpl as _,
 };
 
+pub mod transparent_proxy;
+
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
@@ -0,0 +1,169 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    proxy::transparent_proxy,
+    traits::proxy::transparent_proxy::*,
+};
+use ink::env::call::{
+    ExecutionInput,
+    Selector as InkSelector,
+};
+use openbrush::traits::{
+    AccountId,
+    Hash,
+    Storage,
+};
+pub use transparent_proxy::{
+    Internal as _,
+    InternalImpl as _,
+    TransparentProxyImpl as _,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub admin: Option<AccountId>,
+    #[lazy]
+    pub forward_to: Hash,
+}
+
+pub trait TransparentProxyImpl: Storage<Data> + Internal {
+    fn proxy_admin(&self) -> Option<AccountId> {
+        self._fallback_if_not_admin();
+        self.data().admin.get_or_default()
+    }
+
+    fn proxy_implementation(&self) -> Hash {
+        self._fallback_if_not_admin();
+        self.data().forward_to.get_or_default()
+    }
+
+    fn change_proxy_admin(&mut self, new_admin: AccountId) -> Result<(), ProxyError> {
+        self._fallback_if_not_admin();
+        if new_admin == AccountId::from([0u8; 32]) {
+            return Err(ProxyError::NewAdminIsZero)
+        }
+
+        let previous = self.data().admin.get_or_default();
+        self.data().admin.set(&Some(new_admin));
+        self._emit_admin_changed_event(previous, Some(new_admin));
+        Ok(())
+    }
+
+    fn upgrade_to(&mut self, code_hash: Hash) -> Result<(), ProxyError> {
+        self._fallback_if_not_admin();
+        self._upgrade_to(code_hash);
+        Ok(())
+    }
+
+    fn upgrade_to_and_call(&mut self, code_hash: Hash, init: InitCall) -> Result<(), ProxyError> {
+        self._fallback_if_not_admin();
+        self._upgrade_to(code_hash);
+        // `_init_call` ends the execution of the contract, so `Ok(())` is never returned from here
+        self._init_call(init)
+    }
+}
+
+pub trait Internal {
+    fn _emit_admin_changed_event(&self, _previous: Option<AccountId>, _new: Option<AccountId>);
+
+    fn _emit_delegate_code_changed_event(&self, _previous: Option<Hash>, _new: Option<Hash>);
+
+    fn _init_with_admin(&mut self, admin: AccountId, forward_to: Hash);
+
+    fn _upgrade_to(&mut self, code_hash: Hash);
+
+    /// Forwards the call to the implementation if the caller is not the admin.
+    fn _fallback_if_not_admin(&self);
+
+    /// Forwards the call to the implementation. Panics if the caller is the admin.
+    fn _fallback(&self) -> !;
+
+    fn _init_call(&self, call: InitCall) -> !;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _emit_admin_changed_event(&self, _previous: Option<AccountId>, _new: Option<AccountId>) {}
+
+    fn _emit_delegate_code_changed_event(&self, _previous: Option<Hash>, _new: Option<Hash>) {}
+
+    fn _init_with_admin(&mut self, admin: AccountId, forward_to: Hash) {
+        self.data().admin.set(&Some(admin));
+        self.data().forward_to.set(&forward_to);
+        Internal::_emit_admin_changed_event(self, None, Some(admin));
+        Internal::_emit_delegate_code_changed_event(self, None, Some(forward_to));
+    }
+
+    fn _upgrade_to(&mut self, code_hash: Hash) {
+        let old_code_hash = self.data().forward_to.get_or_default();
+        self.data().forward_to.set(&code_hash);
+        Internal::_emit_delegate_code_changed_event(self, Some(old_code_hash), Some(code_hash));
+    }
+
+    fn _fallback_if_not_admin(&self) {
+        if self.data().admin.get_or_default() != Some(Self::env().caller()) {
+            Internal::_fallback(self)
+        }
+    }
+
+    fn _fallback(&self) -> ! {
+        if self.data().admin.get_or_default() == Some(Self::env().caller()) {
+            panic!("the admin can't fallback to the implementation of the proxy")
+        }
+
+        let forward_to = self.data().forward_to.get_or_default();
+        ink::env::call::build_call::<ink::env::DefaultEnvironment>()
+            .delegate(forward_to)
+            .call_flags(
+                ink::env::CallFlags::default()
+                // We don't plan to use the input data after the delegated call, so the
+                // input data can be forwarded to delegated contract to reduce the gas usage.
+                .set_forward_input(true)
+                // We don't plan to return back to that contract after execution, so we
+                // marked delegated call as "tail", to end the execution of the contract.
+                .set_tail_call(true),
+            )
+            .try_invoke()
+            .unwrap_or_else(|err| panic!("delegate call to {:?} failed due to {:?}", forward_to, err))
+            .unwrap_or_else(|err| panic!("delegate call to {:?} failed due to {:?}", forward_to, err));
+        unreachable!("the _fallback call will never return since `tail_call` was set");
+    }
+
+    fn _init_call(&self, call: InitCall) -> ! {
+        ink::env::call::build_call::<ink::env::DefaultEnvironment>()
+            .delegate(call.hash)
+            .exec_input(ExecutionInput::new(InkSelector::new(call.selector)).push_arg(call.input))
+            .call_flags(
+                ink::env::CallFlags::default()
+                // We don't plan to return back to that contract after execution, so we
+                // marked delegated call as "tail", to end the execution of the contract.
+                .set_tail_call(true),
+            )
+            .returns::<()>()
+            .try_invoke()
+            .unwrap_or_else(|err| panic!("init call failed due to {:?}", err))
+            .unwrap_or_else(|err| panic!("init call failed due to {:?}", err));
+        unreachable!("the _init_call call will never return since `tail_call` was set");
+    }
+}
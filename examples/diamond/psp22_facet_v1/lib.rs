@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-#[openbrush::implementation(PSP22)]
+#[openbrush::implementation(PSP22, DiamondFacet)]
 #[openbrush::contract]
 pub mod my_psp22_facet_v1 {
     use openbrush::{
@@ -19,6 +19,16 @@ pub mod my_psp22_facet_v1 {
         ownable: ownable::Data,
     }
 
+    // Ownable storage is shared with the diamond, so only PSP22 storage is owned by the facet
+    #[overrider(diamond_facet::Internal)]
+    fn _facet_storage_keys(&self) -> Vec<u32> {
+        vec![
+            psp22::STORAGE_KEY_DATA_SUPPLY,
+            psp22::STORAGE_KEY_DATA_BALANCES,
+            psp22::STORAGE_KEY_DATA_ALLOWANCES,
+        ]
+    }
+
     impl PSP22FacetV1 {
         #[ink(constructor)]
         pub fn new() -> Self {
@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-#[openbrush::implementation(PSP22)]
+#[openbrush::implementation(PSP22, DiamondFacet)]
 #[openbrush::contract]
 pub mod my_psp22_facet_v2 {
     use ink::codegen::Env;
@@ -19,6 +19,16 @@ pub mod my_psp22_facet_v2 {
         ownable: ownable::Data,
     }
 
+    // Ownable storage is shared with the diamond, so only PSP22 storage is owned by the facet
+    #[overrider(diamond_facet::Internal)]
+    fn _facet_storage_keys(&self) -> Vec<u32> {
+        vec![
+            psp22::STORAGE_KEY_DATA_SUPPLY,
+            psp22::STORAGE_KEY_DATA_BALANCES,
+            psp22::STORAGE_KEY_DATA_ALLOWANCES,
+        ]
+    }
+
     #[overrider(PSP22)]
     fn transfer(&mut self, to: AccountId, value: Balance, data: Vec<u8>) -> Result<(), PSP22Error> {
         // we will burn 10% of transfer to and from non-zero accounts
@@ -1,6 +1,6 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-#[openbrush::implementation(PSP22Metadata)]
+#[openbrush::implementation(PSP22Metadata, DiamondFacet)]
 #[openbrush::contract]
 pub mod my_psp22_metadata_facet {
     use openbrush::{
@@ -22,6 +22,16 @@ pub mod my_psp22_metadata_facet {
         ownable: ownable::Data,
     }
 
+    // Ownable storage is shared with the diamond, so only metadata storage is owned by the facet
+    #[overrider(diamond_facet::Internal)]
+    fn _facet_storage_keys(&self) -> Vec<u32> {
+        vec![
+            metadata::STORAGE_KEY_DATA_NAME,
+            metadata::STORAGE_KEY_DATA_SYMBOL,
+            metadata::STORAGE_KEY_DATA_DECIMALS,
+        ]
+    }
+
     impl PSP22Facet {
         #[ink(constructor)]
         pub fn new() -> Self {
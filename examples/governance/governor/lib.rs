@@ -66,7 +66,7 @@ pub mod my_governor {
             support: VoteType,
             weight: Balance,
         ) -> Result<(), GovernanceError> {
-            CountingInternal::_count_vote(self, proposal_id, account, support, weight)
+            CountingInternal::_count_vote(self, proposal_id, account, support, weight, Vec::new())
         }
 
         #[ink(message)]
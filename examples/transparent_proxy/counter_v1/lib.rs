@@ -0,0 +1,30 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::contract]
+pub mod counter {
+    use ink::storage::Lazy;
+
+    // The value is stored under its own key, so it doesn't clash with the storage of the proxy
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Counter {
+        value: Lazy<u32>,
+    }
+
+    impl Counter {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn set(&mut self, value: u32) {
+            self.value.set(&value);
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value.get_or_default()
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::contract]
+pub mod counter {
+    use ink::storage::Lazy;
+
+    // The same storage as in `counter_v1`, the new version only adds an initializer
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Counter {
+        value: Lazy<u32>,
+        initialized: Lazy<bool>,
+    }
+
+    impl Counter {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Initializer of the new version, it is called by the proxy in `upgrade_to_and_call`.
+        #[ink(message)]
+        pub fn initialize_v2(&mut self) {
+            assert!(!self.initialized.get_or_default(), "already initialized");
+            self.initialized.set(&true);
+            self.value.set(&(self.value.get_or_default() * 10));
+        }
+
+        #[ink(message)]
+        pub fn set(&mut self, value: u32) {
+            self.value.set(&value);
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> u32 {
+            self.value.get_or_default()
+        }
+
+        #[ink(message)]
+        pub fn initialized(&self) -> bool {
+            self.initialized.get_or_default()
+        }
+    }
+}
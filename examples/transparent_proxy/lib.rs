@@ -0,0 +1,29 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(TransparentProxy)]
+#[openbrush::contract]
+pub mod transparent_proxy {
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        proxy: transparent_proxy::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(forward_to: Hash) -> Self {
+            let mut instance = Self::default();
+            transparent_proxy::Internal::_init_with_admin(&mut instance, Self::env().caller(), forward_to);
+
+            instance
+        }
+
+        #[ink(message, payable, selector = _)]
+        pub fn forward(&self) {
+            transparent_proxy::Internal::_fallback(self)
+        }
+    }
+}
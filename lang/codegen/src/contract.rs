@@ -53,6 +53,10 @@ pub fn generate(_attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     // After, we can consume all other stuff.
     items = consume_traits(items);
 
+    if let Some(env) = internal::extract_env(attrs.clone()) {
+        items = propagate_env(items, &env);
+    }
+
     let generated_items = generate_impls(items);
 
     module.content = Some((braces, generated_items));
@@ -91,6 +95,22 @@ fn consume_traits(items: Vec<syn::Item>) -> Vec<syn::Item> {
     result
 }
 
+/// Wrappers defined inside of the contract use the environment of the contract,
+/// if the environment is not specified explicitly.
+fn propagate_env(mut items: Vec<syn::Item>, env: &syn::Path) -> Vec<syn::Item> {
+    items.iter_mut().for_each(|item| {
+        if let Item::Type(item_type) = item {
+            item_type.attrs.iter_mut().for_each(|attr| {
+                if is_attr(std::slice::from_ref(attr), "wrapper") && attr.tokens.is_empty() {
+                    attr.tokens = quote! { (env = #env) };
+                }
+            });
+        }
+    });
+
+    items
+}
+
 fn generate_impls(mut items: Vec<syn::Item>) -> Vec<syn::Item> {
     let mut generated_items: Vec<syn::Item> = vec![];
     items.iter_mut().for_each(|mut item| {
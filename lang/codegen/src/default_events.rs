@@ -0,0 +1,1183 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::implementations::{
+    ImplArgs,
+    OverridenFnMap,
+};
+use proc_macro2::TokenStream;
+use quote::{
+    format_ident,
+    quote,
+    ToTokens,
+};
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+use syn::Item;
+
+/// Canonical event of a default module, emitted from one of its `_emit_*` hooks.
+struct DefaultEvent {
+    /// Path of the `Internal` trait that declares the hook, as used in the generated implementation
+    internal_trait: &'static str,
+    /// Name of the hook that emits the event
+    hook: &'static str,
+    /// Definition of the `#[ink(event)]` struct
+    definition: syn::ItemStruct,
+    /// Expression building the event from the arguments of the hook
+    constructor: syn::Expr,
+}
+
+impl DefaultEvent {
+    fn new(
+        internal_trait: &'static str,
+        hook: &'static str,
+        definition: TokenStream,
+        constructor: TokenStream,
+    ) -> Self {
+        Self {
+            internal_trait,
+            hook,
+            definition: syn::parse2(quote!(#[ink(event)] #definition)).expect("Should parse"),
+            constructor: syn::parse2(constructor).expect("Should parse"),
+        }
+    }
+
+    fn name(&self) -> String {
+        self.definition.ident.to_string()
+    }
+}
+
+/// Adds the canonical events of the implemented modules to the contract and emits them from the hooks.
+///
+/// An event is skipped if the contract already defines an item with the same name, or if the hook
+/// emitting it is overridden with `#[overrider]`. If several modules define an event with the same name,
+/// a compile error is emitted, as the contract can't have two events with the same name.
+pub(crate) fn emit_default_events(impl_args: &mut ImplArgs, modules: &[String]) {
+    let storage_struct_name = format_ident!("{}", impl_args.storage_struct_name);
+    let defined = impl_args.items.iter().flat_map(item_names).collect::<HashSet<_>>();
+    let mut injected = HashMap::<String, &str>::new();
+    let mut events = vec![];
+
+    for module in modules {
+        for event in default_events(module) {
+            if defined.contains(&event.name()) || is_overridden(impl_args.map, &event) {
+                continue
+            }
+
+            let hook = impl_args
+                .items
+                .iter_mut()
+                .chain(impl_args.overriden_traits.values_mut())
+                .find_map(|item| find_hook(item, &event));
+
+            let hook = match hook {
+                Some(hook) => hook,
+                None => continue,
+            };
+
+            if let Some(other) = injected.get(&event.name()) {
+                let message = format!(
+                    "`{}` and `{}` both define the `{}` event. Override `{}::{}` with `#[overrider]`, \
+                     define your own `{}` event or disable the default events with `no_events`",
+                    other,
+                    module,
+                    event.name(),
+                    event.internal_trait,
+                    event.hook,
+                    event.name(),
+                );
+                events.push(syn::parse_quote!(::core::compile_error!(#message);));
+                continue
+            }
+
+            let constructor = &event.constructor;
+            hook.block = syn::parse_quote!({
+                ::ink::codegen::EmitEvent::<#storage_struct_name>::emit_event(
+                    ::ink::codegen::Env::env(&*self),
+                    #constructor,
+                )
+            });
+
+            injected.insert(event.name(), module);
+            events.push(Item::Struct(event.definition));
+        }
+    }
+
+    impl_args.items.append(&mut events);
+}
+
+fn is_overridden(map: &OverridenFnMap, event: &DefaultEvent) -> bool {
+    map.get(event.internal_trait).is_some_and(|overrides| {
+        overrides
+            .iter()
+            .any(|(fn_name, (_, _, is_default))| fn_name == event.hook && !is_default)
+    })
+}
+
+fn find_hook<'a>(item: &'a mut Item, event: &DefaultEvent) -> Option<&'a mut syn::ImplItemMethod> {
+    let implementation = match item {
+        Item::Impl(implementation) => implementation,
+        _ => return None,
+    };
+
+    match &implementation.trait_ {
+        Some((_, path, _)) if path.to_token_stream().to_string().replace(' ', "") == event.internal_trait => {}
+        _ => return None,
+    }
+
+    implementation.items.iter_mut().find_map(|impl_item| {
+        match impl_item {
+            syn::ImplItem::Method(method) if method.sig.ident == event.hook => Some(method),
+            _ => None,
+        }
+    })
+}
+
+/// Names introduced into the contract module by the item, which the injected events must not clash with.
+fn item_names(item: &Item) -> Vec<String> {
+    match item {
+        Item::Struct(item) => vec![item.ident.to_string()],
+        Item::Enum(item) => vec![item.ident.to_string()],
+        Item::Type(item) => vec![item.ident.to_string()],
+        Item::Const(item) => vec![item.ident.to_string()],
+        Item::Trait(item) => vec![item.ident.to_string()],
+        Item::Mod(item) => vec![item.ident.to_string()],
+        Item::Use(item) => {
+            let mut names = vec![];
+            use_tree_names(&item.tree, &mut names);
+            names
+        }
+        _ => vec![],
+    }
+}
+
+fn use_tree_names(tree: &syn::UseTree, names: &mut Vec<String>) {
+    match tree {
+        syn::UseTree::Path(path) => use_tree_names(&path.tree, names),
+        syn::UseTree::Name(name) => names.push(name.ident.to_string()),
+        syn::UseTree::Rename(rename) => names.push(rename.rename.to_string()),
+        syn::UseTree::Group(group) => group.items.iter().for_each(|tree| use_tree_names(tree, names)),
+        syn::UseTree::Glob(_) => {}
+    }
+}
+
+// Topics are limited to two fields per event, so the events fit environments with
+// `MAX_EVENT_TOPICS` of 3 (the event signature takes one topic).
+fn default_events(module: &str) -> Vec<DefaultEvent> {
+    match module {
+        "PSP22" => psp22_events("psp22::Internal"),
+        "PSP22Pallet" => psp22_events("psp22_pallet::Internal"),
+        "PSP22Snapshot" => {
+            vec![DefaultEvent::new(
+                "snapshot::Internal",
+                "_emit_snapshot_event",
+                quote! {
+                    /// Emitted when a new snapshot of balances is taken
+                    pub struct Snapshot {
+                        pub id: SnapshotId,
+                    }
+                },
+                quote!(Snapshot { id }),
+            )]
+        }
+        "PSP22Vault" => {
+            vec![
+                DefaultEvent::new(
+                    "vault::Internal",
+                    "_emit_deposit_event",
+                    quote! {
+                        /// Emitted when `sender` deposits `assets` into the vault and `owner` receives `shares`
+                        pub struct Deposit {
+                            #[ink(topic)]
+                            pub sender: AccountId,
+                            #[ink(topic)]
+                            pub owner: AccountId,
+                            pub assets: Balance,
+                            pub shares: Balance,
+                        }
+                    },
+                    quote!(Deposit {
+                        sender,
+                        owner,
+                        assets,
+                        shares
+                    }),
+                ),
+                DefaultEvent::new(
+                    "vault::Internal",
+                    "_emit_withdraw_event",
+                    quote! {
+                        /// Emitted when `shares` of `owner` are burned and `receiver` gets `assets` from the vault
+                        pub struct Withdraw {
+                            pub sender: AccountId,
+                            #[ink(topic)]
+                            pub receiver: AccountId,
+                            #[ink(topic)]
+                            pub owner: AccountId,
+                            pub assets: Balance,
+                            pub shares: Balance,
+                        }
+                    },
+                    quote!(Withdraw {
+                        sender,
+                        receiver,
+                        owner,
+                        assets,
+                        shares
+                    }),
+                ),
+            ]
+        }
+        "PSP34" => {
+            vec![
+                DefaultEvent::new(
+                    "psp34::Internal",
+                    "_emit_transfer_event",
+                    quote! {
+                        /// Emitted when token `id` is minted, burned or transferred
+                        pub struct Transfer {
+                            #[ink(topic)]
+                            pub from: Option<AccountId>,
+                            #[ink(topic)]
+                            pub to: Option<AccountId>,
+                            pub id: Id,
+                        }
+                    },
+                    quote!(Transfer { from, to, id }),
+                ),
+                DefaultEvent::new(
+                    "psp34::Internal",
+                    "_emit_approval_event",
+                    quote! {
+                        /// Emitted when `from` approves or disapproves `to` to operate on token `id`,
+                        /// or on all tokens if `id` is `None`
+                        pub struct Approval {
+                            #[ink(topic)]
+                            pub from: AccountId,
+                            #[ink(topic)]
+                            pub to: AccountId,
+                            pub id: Option<Id>,
+                            pub approved: bool,
+                        }
+                    },
+                    quote!(Approval { from, to, id, approved }),
+                ),
+            ]
+        }
+        "PSP34Metadata" => attribute_set_events(quote!(AttributeSet { id, key, data })),
+        "PSP37" => {
+            vec![
+                DefaultEvent::new(
+                    "psp37::Internal",
+                    "_emit_transfer_event",
+                    quote! {
+                        /// Emitted when `value` of token `id` is minted, burned or transferred
+                        pub struct Transfer {
+                            #[ink(topic)]
+                            pub from: Option<AccountId>,
+                            #[ink(topic)]
+                            pub to: Option<AccountId>,
+                            pub id: Id,
+                            pub value: Balance,
+                        }
+                    },
+                    quote!(Transfer {
+                        from,
+                        to,
+                        id,
+                        value: amount
+                    }),
+                ),
+                DefaultEvent::new(
+                    "psp37::Internal",
+                    "_emit_transfer_batch_event",
+                    quote! {
+                        /// Emitted when several tokens are minted, burned or transferred at once
+                        pub struct TransferBatch {
+                            #[ink(topic)]
+                            pub from: Option<AccountId>,
+                            #[ink(topic)]
+                            pub to: Option<AccountId>,
+                            pub ids_amounts: Vec<(Id, Balance)>,
+                        }
+                    },
+                    quote!(TransferBatch { from, to, ids_amounts }),
+                ),
+                DefaultEvent::new(
+                    "psp37::Internal",
+                    "_emit_approval_event",
+                    quote! {
+                        /// Emitted when `owner` allows `operator` to spend `value` of token `id`,
+                        /// or of all tokens if `id` is `None`
+                        pub struct Approval {
+                            #[ink(topic)]
+                            pub owner: AccountId,
+                            #[ink(topic)]
+                            pub operator: AccountId,
+                            pub id: Option<Id>,
+                            pub value: Balance,
+                        }
+                    },
+                    quote!(Approval {
+                        owner,
+                        operator,
+                        id,
+                        value
+                    }),
+                ),
+            ]
+        }
+        "PSP37Metadata" => {
+            attribute_set_events(quote!(AttributeSet {
+                id: id.clone(),
+                key: key.clone(),
+                data: data.clone()
+            }))
+        }
+        "Ownable" => {
+            vec![DefaultEvent::new(
+                "ownable::Internal",
+                "_emit_ownership_transferred_event",
+                quote! {
+                    /// Emitted when the ownership is transferred or renounced
+                    pub struct OwnershipTransferred {
+                        #[ink(topic)]
+                        pub previous: Option<AccountId>,
+                        #[ink(topic)]
+                        pub new: Option<AccountId>,
+                    }
+                },
+                quote!(OwnershipTransferred { previous, new }),
+            )]
+        }
+        "Ownable2Step" => {
+            vec![DefaultEvent::new(
+                "ownable_2_step::Internal",
+                "_emit_ownership_transfer_started_event",
+                quote! {
+                    /// Emitted when the owner starts a transfer which `new` has to accept
+                    pub struct OwnershipTransferStarted {
+                        #[ink(topic)]
+                        pub previous: Option<AccountId>,
+                        #[ink(topic)]
+                        pub new: AccountId,
+                    }
+                },
+                quote!(OwnershipTransferStarted { previous, new }),
+            )]
+        }
+        "PaymentSplitter" => {
+            vec![
+                DefaultEvent::new(
+                    "payment_splitter::Internal",
+                    "_emit_payee_added_event",
+                    quote! {
+                        /// Emitted when `account` is added as a payee with `shares`
+                        pub struct PayeeAdded {
+                            #[ink(topic)]
+                            pub account: AccountId,
+                            pub shares: Balance,
+                        }
+                    },
+                    quote!(PayeeAdded { account, shares }),
+                ),
+                DefaultEvent::new(
+                    "payment_splitter::Internal",
+                    "_emit_payment_received_event",
+                    quote! {
+                        /// Emitted when the contract receives `amount` from `from`
+                        pub struct PaymentReceived {
+                            #[ink(topic)]
+                            pub from: AccountId,
+                            pub amount: Balance,
+                        }
+                    },
+                    quote!(PaymentReceived { from, amount }),
+                ),
+                DefaultEvent::new(
+                    "payment_splitter::Internal",
+                    "_emit_payment_released_event",
+                    quote! {
+                        /// Emitted when `amount` is released to the payee `to`
+                        pub struct PaymentReleased {
+                            #[ink(topic)]
+                            pub to: AccountId,
+                            pub amount: Balance,
+                        }
+                    },
+                    quote!(PaymentReleased { to, amount }),
+                ),
+            ]
+        }
+        "AccessControl" => {
+            vec![
+                DefaultEvent::new(
+                    "access_control::Internal",
+                    "_emit_role_admin_changed",
+                    quote! {
+                        /// Emitted when the admin role of `role` is changed from `previous` to `new`
+                        pub struct RoleAdminChanged {
+                            #[ink(topic)]
+                            pub role: RoleType,
+                            pub previous: RoleType,
+                            pub new: RoleType,
+                        }
+                    },
+                    quote!(RoleAdminChanged { role, previous, new }),
+                ),
+                DefaultEvent::new(
+                    "access_control::Internal",
+                    "_emit_role_granted",
+                    quote! {
+                        /// Emitted when `grantee` is granted `role` by `grantor`
+                        pub struct RoleGranted {
+                            #[ink(topic)]
+                            pub role: RoleType,
+                            #[ink(topic)]
+                            pub grantee: Option<AccountId>,
+                            pub grantor: Option<AccountId>,
+                        }
+                    },
+                    quote!(RoleGranted { role, grantee, grantor }),
+                ),
+                DefaultEvent::new(
+                    "access_control::Internal",
+                    "_emit_role_revoked",
+                    quote! {
+                        /// Emitted when `role` is revoked from `account` by `sender`
+                        pub struct RoleRevoked {
+                            #[ink(topic)]
+                            pub role: RoleType,
+                            #[ink(topic)]
+                            pub account: Option<AccountId>,
+                            pub sender: AccountId,
+                        }
+                    },
+                    quote!(RoleRevoked { role, account, sender }),
+                ),
+            ]
+        }
+        "AccessControlDefaultAdminRules" => {
+            vec![
+                DefaultEvent::new(
+                    "default_admin_rules::Internal",
+                    "_emit_default_admin_transfer_scheduled",
+                    quote! {
+                        /// Emitted when a transfer of the default admin role to `new_admin` is scheduled
+                        pub struct DefaultAdminTransferScheduled {
+                            #[ink(topic)]
+                            pub new_admin: Option<AccountId>,
+                            pub accept_schedule: Timestamp,
+                        }
+                    },
+                    quote!(DefaultAdminTransferScheduled {
+                        new_admin,
+                        accept_schedule
+                    }),
+                ),
+                DefaultEvent::new(
+                    "default_admin_rules::Internal",
+                    "_emit_default_admin_transfer_canceled",
+                    quote! {
+                        /// Emitted when a pending transfer of the default admin role is canceled
+                        pub struct DefaultAdminTransferCanceled {}
+                    },
+                    quote!(DefaultAdminTransferCanceled {}),
+                ),
+                DefaultEvent::new(
+                    "default_admin_rules::Internal",
+                    "_emit_default_admin_delay_change_scheduled",
+                    quote! {
+                        /// Emitted when a change of the default admin delay to `new_delay` is scheduled
+                        pub struct DefaultAdminDelayChangeScheduled {
+                            pub new_delay: Timestamp,
+                            pub effect_schedule: Timestamp,
+                        }
+                    },
+                    quote!(DefaultAdminDelayChangeScheduled {
+                        new_delay,
+                        effect_schedule
+                    }),
+                ),
+                DefaultEvent::new(
+                    "default_admin_rules::Internal",
+                    "_emit_default_admin_delay_change_canceled",
+                    quote! {
+                        /// Emitted when a pending change of the default admin delay is canceled
+                        pub struct DefaultAdminDelayChangeCanceled {}
+                    },
+                    quote!(DefaultAdminDelayChangeCanceled {}),
+                ),
+            ]
+        }
+        "Pausable" => {
+            vec![
+                DefaultEvent::new(
+                    "pausable::Internal",
+                    "_emit_paused_event",
+                    quote! {
+                        /// Emitted when the contract is paused by `account`
+                        pub struct Paused {
+                            pub account: AccountId,
+                        }
+                    },
+                    quote!(Paused { account }),
+                ),
+                DefaultEvent::new(
+                    "pausable::Internal",
+                    "_emit_unpaused_event",
+                    quote! {
+                        /// Emitted when the contract is unpaused by `account`
+                        pub struct Unpaused {
+                            pub account: AccountId,
+                        }
+                    },
+                    quote!(Unpaused { account }),
+                ),
+            ]
+        }
+        "GranularPausable" => {
+            vec![
+                DefaultEvent::new(
+                    "granular_pausable::Internal",
+                    "_emit_feature_paused_event",
+                    quote! {
+                        /// Emitted when `feature` is paused by `account`
+                        pub struct FeaturePaused {
+                            #[ink(topic)]
+                            pub feature: Feature,
+                            pub account: AccountId,
+                        }
+                    },
+                    quote!(FeaturePaused { feature, account }),
+                ),
+                DefaultEvent::new(
+                    "granular_pausable::Internal",
+                    "_emit_feature_unpaused_event",
+                    quote! {
+                        /// Emitted when `feature` is unpaused by `account`
+                        pub struct FeatureUnpaused {
+                            #[ink(topic)]
+                            pub feature: Feature,
+                            pub account: AccountId,
+                        }
+                    },
+                    quote!(FeatureUnpaused { feature, account }),
+                ),
+            ]
+        }
+        "TimelockController" => {
+            vec![
+                DefaultEvent::new(
+                    "timelock_controller::Internal",
+                    "_emit_min_delay_change_event",
+                    quote! {
+                        /// Emitted when the minimum delay for future operations is changed
+                        pub struct MinDelayChange {
+                            pub old_delay: Timestamp,
+                            pub new_delay: Timestamp,
+                        }
+                    },
+                    quote!(MinDelayChange { old_delay, new_delay }),
+                ),
+                DefaultEvent::new(
+                    "timelock_controller::Internal",
+                    "_emit_call_scheduled_event",
+                    quote! {
+                        /// Emitted when a call is scheduled as part of operation `id`
+                        pub struct CallScheduled {
+                            #[ink(topic)]
+                            pub id: OperationId,
+                            #[ink(topic)]
+                            pub index: u8,
+                            pub transaction: Transaction,
+                            pub predecessor: Option<OperationId>,
+                            pub delay: Timestamp,
+                        }
+                    },
+                    quote!(CallScheduled {
+                        id,
+                        index,
+                        transaction,
+                        predecessor,
+                        delay
+                    }),
+                ),
+                DefaultEvent::new(
+                    "timelock_controller::Internal",
+                    "_emit_cancelled_event",
+                    quote! {
+                        /// Emitted when operation `id` is cancelled
+                        pub struct Cancelled {
+                            #[ink(topic)]
+                            pub id: OperationId,
+                        }
+                    },
+                    quote!(Cancelled { id }),
+                ),
+                DefaultEvent::new(
+                    "timelock_controller::Internal",
+                    "_emit_call_executed_event",
+                    quote! {
+                        /// Emitted when a call is performed as part of operation `id`
+                        pub struct CallExecuted {
+                            #[ink(topic)]
+                            pub id: OperationId,
+                            #[ink(topic)]
+                            pub index: u8,
+                            pub transaction: Transaction,
+                        }
+                    },
+                    quote!(CallExecuted { id, index, transaction }),
+                ),
+            ]
+        }
+        "Multisig" => {
+            vec![
+                DefaultEvent::new(
+                    "multisig::Internal",
+                    "_emit_owner_added_event",
+                    quote! {
+                        /// Emitted when `owner` is added to the signers
+                        pub struct OwnerAdded {
+                            #[ink(topic)]
+                            pub owner: AccountId,
+                        }
+                    },
+                    quote!(OwnerAdded { owner }),
+                ),
+                DefaultEvent::new(
+                    "multisig::Internal",
+                    "_emit_owner_removed_event",
+                    quote! {
+                        /// Emitted when `owner` is removed from the signers
+                        pub struct OwnerRemoved {
+                            #[ink(topic)]
+                            pub owner: AccountId,
+                        }
+                    },
+                    quote!(OwnerRemoved { owner }),
+                ),
+                DefaultEvent::new(
+                    "multisig::Internal",
+                    "_emit_threshold_changed_event",
+                    quote! {
+                        /// Emitted when the number of approvals required to execute a transaction is changed
+                        pub struct ThresholdChanged {
+                            pub old_threshold: u32,
+                            pub new_threshold: u32,
+                        }
+                    },
+                    quote!(ThresholdChanged {
+                        old_threshold,
+                        new_threshold
+                    }),
+                ),
+                DefaultEvent::new(
+                    "multisig::Internal",
+                    "_emit_transaction_proposed_event",
+                    quote! {
+                        /// Emitted when `proposer` proposes transaction `id`
+                        pub struct TransactionProposed {
+                            #[ink(topic)]
+                            pub id: TransactionId,
+                            pub transaction: Transaction,
+                            #[ink(topic)]
+                            pub proposer: AccountId,
+                        }
+                    },
+                    quote!(TransactionProposed {
+                        id,
+                        transaction,
+                        proposer
+                    }),
+                ),
+                DefaultEvent::new(
+                    "multisig::Internal",
+                    "_emit_transaction_approved_event",
+                    quote! {
+                        /// Emitted when `owner` approves transaction `id`
+                        pub struct TransactionApproved {
+                            #[ink(topic)]
+                            pub id: TransactionId,
+                            #[ink(topic)]
+                            pub owner: AccountId,
+                        }
+                    },
+                    quote!(TransactionApproved { id, owner }),
+                ),
+                DefaultEvent::new(
+                    "multisig::Internal",
+                    "_emit_approval_revoked_event",
+                    quote! {
+                        /// Emitted when `owner` revokes the approval of transaction `id`
+                        pub struct ApprovalRevoked {
+                            #[ink(topic)]
+                            pub id: TransactionId,
+                            #[ink(topic)]
+                            pub owner: AccountId,
+                        }
+                    },
+                    quote!(ApprovalRevoked { id, owner }),
+                ),
+                DefaultEvent::new(
+                    "multisig::Internal",
+                    "_emit_transaction_executed_event",
+                    quote! {
+                        /// Emitted when transaction `id` is executed
+                        pub struct TransactionExecuted {
+                            #[ink(topic)]
+                            pub id: TransactionId,
+                        }
+                    },
+                    quote!(TransactionExecuted { id }),
+                ),
+            ]
+        }
+        "Proxy" => vec![delegate_code_changed_event("proxy::Internal")],
+        "TransparentProxy" => {
+            vec![
+                DefaultEvent::new(
+                    "transparent_proxy::Internal",
+                    "_emit_admin_changed_event",
+                    quote! {
+                        /// Emitted when the admin of the proxy is changed
+                        pub struct AdminChanged {
+                            #[ink(topic)]
+                            pub previous: Option<AccountId>,
+                            #[ink(topic)]
+                            pub new: Option<AccountId>,
+                        }
+                    },
+                    quote!(AdminChanged { previous, new }),
+                ),
+                delegate_code_changed_event("transparent_proxy::Internal"),
+            ]
+        }
+        "Initializable" => {
+            vec![DefaultEvent::new(
+                "initializable::Internal",
+                "_emit_initialized_event",
+                quote! {
+                    /// Emitted when the contract is initialized or reinitialized to `version`
+                    pub struct Initialized {
+                        pub version: u64,
+                    }
+                },
+                quote!(Initialized { version }),
+            )]
+        }
+        "Diamond" => {
+            vec![DefaultEvent::new(
+                "diamond::Internal",
+                "_emit_diamond_cut_event",
+                quote! {
+                    /// Emitted when facets are added, replaced or removed from the diamond
+                    pub struct DiamondCut {
+                        pub cuts: Vec<FacetCut>,
+                        pub init: Option<InitCall>,
+                    }
+                },
+                quote!(DiamondCut {
+                    cuts: diamond_cut.to_vec(),
+                    init: init.clone()
+                }),
+            )]
+        }
+        "Governor" => {
+            vec![
+                DefaultEvent::new(
+                    "GovernorEvents",
+                    "emit_proposal_created",
+                    quote! {
+                        /// Emitted when `proposer` creates the proposal `proposal_id`
+                        pub struct ProposalCreated {
+                            #[ink(topic)]
+                            pub proposal_id: ProposalId,
+                            #[ink(topic)]
+                            pub proposer: AccountId,
+                            pub transactions: Vec<Transaction>,
+                            pub vote_start: Timestamp,
+                            pub vote_end: Timestamp,
+                            pub description: String,
+                        }
+                    },
+                    quote!(ProposalCreated {
+                        proposal_id: _proposal_id,
+                        proposer: _proposer,
+                        transactions: _transactions,
+                        vote_start: _vote_start,
+                        vote_end: _vote_end,
+                        description: _description,
+                    }),
+                ),
+                DefaultEvent::new(
+                    "GovernorEvents",
+                    "emit_proposal_canceled",
+                    quote! {
+                        /// Emitted when the proposal `proposal_id` is canceled
+                        pub struct ProposalCanceled {
+                            #[ink(topic)]
+                            pub proposal_id: ProposalId,
+                        }
+                    },
+                    quote!(ProposalCanceled {
+                        proposal_id: _proposal_id
+                    }),
+                ),
+                DefaultEvent::new(
+                    "GovernorEvents",
+                    "emit_proposal_executed",
+                    quote! {
+                        /// Emitted when the proposal `proposal_id` is executed
+                        pub struct ProposalExecuted {
+                            #[ink(topic)]
+                            pub proposal_id: ProposalId,
+                        }
+                    },
+                    quote!(ProposalExecuted {
+                        proposal_id: _proposal_id
+                    }),
+                ),
+                DefaultEvent::new(
+                    "GovernorEvents",
+                    "emit_vote_cast",
+                    quote! {
+                        /// Emitted when `voter` casts `weight` votes on the proposal `proposal_id`
+                        pub struct VoteCast {
+                            #[ink(topic)]
+                            pub proposal_id: ProposalId,
+                            #[ink(topic)]
+                            pub voter: AccountId,
+                            pub support: VoteType,
+                            pub weight: Balance,
+                            pub reason: String,
+                        }
+                    },
+                    quote!(VoteCast {
+                        proposal_id: _proposal_id,
+                        voter: _voter,
+                        support: _support,
+                        weight: _weight,
+                        reason: _reason,
+                    }),
+                ),
+                DefaultEvent::new(
+                    "GovernorEvents",
+                    "emit_vote_cast_with_params",
+                    quote! {
+                        /// Emitted when `voter` casts `weight` votes with `params` on the proposal `proposal_id`
+                        pub struct VoteCastWithParams {
+                            #[ink(topic)]
+                            pub proposal_id: ProposalId,
+                            #[ink(topic)]
+                            pub voter: AccountId,
+                            pub support: VoteType,
+                            pub weight: Balance,
+                            pub reason: String,
+                            pub params: Vec<u8>,
+                        }
+                    },
+                    quote!(VoteCastWithParams {
+                        proposal_id: _proposal_id,
+                        voter: _voter,
+                        support: _support,
+                        weight: _weight,
+                        reason: _reason,
+                        params: _params,
+                    }),
+                ),
+                DefaultEvent::new(
+                    "GovernorEvents",
+                    "emit_proposal_extended",
+                    quote! {
+                        /// Emitted when the deadline of the proposal `proposal_id` is extended
+                        pub struct ProposalExtended {
+                            #[ink(topic)]
+                            pub proposal_id: ProposalId,
+                            pub extended_deadline: Timestamp,
+                        }
+                    },
+                    quote!(ProposalExtended {
+                        proposal_id: _proposal_id,
+                        extended_deadline: _extended_deadline,
+                    }),
+                ),
+            ]
+        }
+        "GovernorSettings" => {
+            vec![
+                DefaultEvent::new(
+                    "GovernorSettingsEvents",
+                    "emit_voting_delay_set",
+                    quote! {
+                        /// Emitted when the voting delay is set
+                        pub struct VotingDelaySet {
+                            pub old_voting_delay: Option<u64>,
+                            pub new_voting_delay: u64,
+                        }
+                    },
+                    quote!(VotingDelaySet {
+                        old_voting_delay: _old_voting_delay,
+                        new_voting_delay: _new_voting_delay,
+                    }),
+                ),
+                DefaultEvent::new(
+                    "GovernorSettingsEvents",
+                    "emit_voting_period_set",
+                    quote! {
+                        /// Emitted when the voting period is set
+                        pub struct VotingPeriodSet {
+                            pub old_voting_period: Option<u64>,
+                            pub new_voting_period: u64,
+                        }
+                    },
+                    quote!(VotingPeriodSet {
+                        old_voting_period: _old_voting_period,
+                        new_voting_period: _new_voting_period,
+                    }),
+                ),
+                DefaultEvent::new(
+                    "GovernorSettingsEvents",
+                    "emit_proposal_threshold_set",
+                    quote! {
+                        /// Emitted when the proposal threshold is set
+                        pub struct ProposalThresholdSet {
+                            pub old_proposal_threshold: Option<u128>,
+                            pub new_proposal_threshold: u128,
+                        }
+                    },
+                    quote!(ProposalThresholdSet {
+                        old_proposal_threshold: _old_proposal_threshold,
+                        new_proposal_threshold: _new_proposal_threshold,
+                    }),
+                ),
+            ]
+        }
+        "GovernorQuorum" => {
+            vec![
+                DefaultEvent::new(
+                    "QuorumEvents",
+                    "emit_quorum_numerator_updated",
+                    quote! {
+                        /// Emitted when the quorum numerator is updated
+                        pub struct QuorumNumeratorUpdated {
+                            pub old_quorum_numerator: u128,
+                            pub new_quorum_numerator: u128,
+                        }
+                    },
+                    quote!(QuorumNumeratorUpdated {
+                        old_quorum_numerator: _old_quorum_numerator,
+                        new_quorum_numerator: _new_quorum_numerator,
+                    }),
+                ),
+                DefaultEvent::new(
+                    "QuorumEvents",
+                    "emit_governor_invalid_quorum_fraction",
+                    quote! {
+                        /// Emitted when the quorum fraction is invalid
+                        pub struct GovernorInvalidQuorumFraction {
+                            pub quorum_numerator: u128,
+                            pub quorum_denominator: u128,
+                        }
+                    },
+                    quote!(GovernorInvalidQuorumFraction {
+                        quorum_numerator: _quorum_numerator,
+                        quorum_denominator: _quorum_denominator,
+                    }),
+                ),
+            ]
+        }
+        "GovernorTimelock" => {
+            vec![
+                DefaultEvent::new(
+                    "GovernorTimelockEvents",
+                    "emit_proposal_queued",
+                    quote! {
+                        /// Emitted when the proposal `proposal_id` is queued to the timelock
+                        pub struct ProposalQueued {
+                            #[ink(topic)]
+                            pub proposal_id: ProposalId,
+                            pub eta: Timestamp,
+                        }
+                    },
+                    quote!(ProposalQueued {
+                        proposal_id: _proposal_id,
+                        eta: _eta,
+                    }),
+                ),
+                DefaultEvent::new(
+                    "GovernorTimelockEvents",
+                    "emit_timelock_change",
+                    quote! {
+                        /// Emitted when the timelock used by the governor is changed
+                        pub struct TimelockChange {
+                            pub old_timelock: Option<AccountId>,
+                            pub new_timelock: AccountId,
+                        }
+                    },
+                    quote!(TimelockChange {
+                        old_timelock: _old_timelock,
+                        new_timelock: _new_timelock,
+                    }),
+                ),
+            ]
+        }
+        "GovernorPreventLateQuorum" => {
+            vec![DefaultEvent::new(
+                "GovernorPreventLateQuorumEvents",
+                "emit_late_quorum_vote_extension_set",
+                quote! {
+                    /// Emitted when the late quorum vote extension is set
+                    pub struct LateQuorumVoteExtensionSet {
+                        pub old_vote_extension: Option<Timestamp>,
+                        pub new_vote_extension: Timestamp,
+                    }
+                },
+                quote!(LateQuorumVoteExtensionSet {
+                    old_vote_extension: _old_vote_extension,
+                    new_vote_extension: _new_vote_extension,
+                }),
+            )]
+        }
+        "PSP22Votes" => {
+            vec![
+                DefaultEvent::new(
+                    "VotesEvents",
+                    "emit_delegate_changed_event",
+                    quote! {
+                        /// Emitted when `delegator` changes its delegate from `from_delegate` to `to_delegate`
+                        pub struct DelegateChanged {
+                            #[ink(topic)]
+                            pub delegator: Option<AccountId>,
+                            pub from_delegate: Option<AccountId>,
+                            #[ink(topic)]
+                            pub to_delegate: Option<AccountId>,
+                        }
+                    },
+                    quote!(DelegateChanged {
+                        delegator: *_delegator,
+                        from_delegate: *_from_delegate,
+                        to_delegate: *_to_delegate,
+                    }),
+                ),
+                DefaultEvent::new(
+                    "VotesEvents",
+                    "emit_delegate_votes_changed_event",
+                    quote! {
+                        /// Emitted when the votes of `delegate` change from `previous_votes` to `new_votes`
+                        pub struct DelegateVotesChanged {
+                            #[ink(topic)]
+                            pub delegate: AccountId,
+                            pub previous_votes: Balance,
+                            pub new_votes: Balance,
+                        }
+                    },
+                    quote!(DelegateVotesChanged {
+                        delegate: *_delegate,
+                        previous_votes: _previous_votes,
+                        new_votes: _new_votes,
+                    }),
+                ),
+            ]
+        }
+        _ => vec![],
+    }
+}
+
+fn psp22_events(internal_trait: &'static str) -> Vec<DefaultEvent> {
+    vec![
+        DefaultEvent::new(
+            internal_trait,
+            "_emit_transfer_event",
+            quote! {
+                /// Emitted when `value` tokens are minted, burned or transferred
+                pub struct Transfer {
+                    #[ink(topic)]
+                    pub from: Option<AccountId>,
+                    #[ink(topic)]
+                    pub to: Option<AccountId>,
+                    pub value: Balance,
+                }
+            },
+            quote!(Transfer {
+                from,
+                to,
+                value: amount
+            }),
+        ),
+        DefaultEvent::new(
+            internal_trait,
+            "_emit_approval_event",
+            quote! {
+                /// Emitted when `owner` allows `spender` to spend `value` tokens
+                pub struct Approval {
+                    #[ink(topic)]
+                    pub owner: AccountId,
+                    #[ink(topic)]
+                    pub spender: AccountId,
+                    pub value: Balance,
+                }
+            },
+            quote!(Approval {
+                owner,
+                spender,
+                value: amount
+            }),
+        ),
+    ]
+}
+
+fn attribute_set_events(constructor: TokenStream) -> Vec<DefaultEvent> {
+    vec![DefaultEvent::new(
+        "metadata::Internal",
+        "_emit_attribute_set_event",
+        quote! {
+            /// Emitted when the attribute `key` of token `id` is set to `data`
+            pub struct AttributeSet {
+                pub id: Id,
+                pub key: String,
+                pub data: String,
+            }
+        },
+        constructor,
+    )]
+}
+
+fn delegate_code_changed_event(internal_trait: &'static str) -> DefaultEvent {
+    DefaultEvent::new(
+        internal_trait,
+        "_emit_delegate_code_changed_event",
+        quote! {
+            /// Emitted when the code hash the proxy delegates to is changed
+            pub struct DelegateCodeChanged {
+                #[ink(topic)]
+                pub previous: Option<Hash>,
+                #[ink(topic)]
+                pub new: Option<Hash>,
+            }
+        },
+        quote!(DelegateCodeChanged { previous, new }),
+    )
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use proc_macro2::TokenStream;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::{
+    parse2,
+    spanned::Spanned,
+    Data,
+    Fields,
+};
+
+pub fn error_from_derive(item: TokenStream) -> TokenStream {
+    let derive: syn::DeriveInput = parse2(item).expect("Expected DeriveInput");
+
+    let enum_ident = derive.ident;
+    let (impls, types, where_clause) = derive.generics.split_for_impl();
+
+    let variants = match &derive.data {
+        Data::Enum(en) => &en.variants,
+        _ => {
+            return quote_spanned!(enum_ident.span()=>
+                compile_error!("`ErrorFrom` can only be derived for enums");
+            )
+        }
+    };
+
+    let impls = variants
+        .iter()
+        .filter(|variant| variant.attrs.iter().any(|a| a.path.is_ident("from")))
+        .map(|variant| {
+            let variant_ident = &variant.ident;
+            let span = variant.span();
+
+            let ty = match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+                _ => {
+                    return quote_spanned!(span=>
+                        compile_error!("`#[from]` variant must have exactly one unnamed field with the absorbed error");
+                    )
+                }
+            };
+
+            quote_spanned!(span=>
+                impl #impls ::core::convert::From<#ty> for #enum_ident #types #where_clause {
+                    fn from(error: #ty) -> Self {
+                        #enum_ident::#variant_ident(error)
+                    }
+                }
+            )
+        });
+
+    quote! {
+        #(#impls)*
+    }
+}
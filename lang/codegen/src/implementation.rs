@@ -20,6 +20,7 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use crate::{
+    default_events::emit_default_events,
     implementations::*,
     internal,
     internal::*,
@@ -42,7 +43,7 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     let input: TokenStream = ink_module;
 
     // map attribute args to default contract names
-    let args = syn::parse2::<AttributeArgs>(attrs)
+    let mut args = syn::parse2::<AttributeArgs>(attrs)
         .expect("No default contracts to implement provided")
         .iter()
         .map(|arg| {
@@ -53,6 +54,19 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
         })
         .collect::<Vec<String>>();
 
+    // `no_events` disables the canonical events of the default implementations
+    let default_events = !args.iter().any(|arg| arg == "no_events");
+    args.retain(|arg| arg != "no_events");
+
+    // `guard_calls` adds the `non_reentrant` modifier to the default messages which make cross-contract calls
+    let guard_calls = args.iter().any(|arg| arg == "guard_calls");
+    args.retain(|arg| arg != "guard_calls");
+    if guard_calls && !args.iter().any(|arg| arg == "ReentrancyGuard") {
+        return quote! {
+            compile_error!("`guard_calls` requires the `ReentrancyGuard` implementation");
+        }
+    }
+
     let mut module = syn::parse2::<syn::ItemMod>(input).expect("Can't parse contract module");
     let (braces, items) = match module.clone().content {
         Some((brace, items)) => (brace, items),
@@ -74,6 +88,9 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     // if multiple contracts are using the same trait implemented differently we override it this way
     let mut overriden_traits = HashMap::<&str, syn::Item>::default();
 
+    // the items of the user, the generated ones are appended after them
+    let user_items = items.len();
+
     let mut impl_args = ImplArgs::new(&map, &mut items, &mut imports, &mut overriden_traits, ident);
 
     for to_implement in &args {
@@ -84,7 +101,9 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "PSP22Permit" => impl_psp22_permit(&mut impl_args),
             "PSP22Metadata" => impl_psp22_metadata(&mut impl_args),
             "PSP22Capped" => impl_psp22_capped(&mut impl_args),
+            "PSP22Snapshot" => impl_psp22_snapshot(&mut impl_args),
             "PSP22Wrapper" => impl_psp22_wrapper(&mut impl_args),
+            "PSP22Vault" => impl_psp22_vault(&mut impl_args),
             "PSP22Votes" => impl_psp22_votes(&mut impl_args),
             "Flashmint" => impl_flashmint(&mut impl_args),
             "PSP22TokenTimelock" => impl_token_timelock(&mut impl_args),
@@ -96,34 +115,63 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "PSP34Burnable" => impl_psp34_burnable(&mut impl_args),
             "PSP34Mintable" => impl_psp34_mintable(&mut impl_args),
             "PSP34Metadata" => impl_psp34_metadata(&mut impl_args),
+            "PSP34Royalty" => impl_psp34_royalty(&mut impl_args),
             "PSP34Enumerable" => impl_psp34_enumerable(&mut impl_args),
             "PSP37" => impl_psp37(&mut impl_args),
             "PSP37Batch" => impl_psp37_batch(&mut impl_args),
             "PSP37Burnable" => impl_psp37_burnable(&mut impl_args),
+            "PSP37Capped" => impl_psp37_capped(&mut impl_args),
             "PSP37Metadata" => impl_psp37_metadata(&mut impl_args),
             "PSP37Mintable" => impl_psp37_mintable(&mut impl_args),
             "PSP37Enumerable" => impl_psp37_enumerable(&mut impl_args),
             "Ownable" => impl_ownable(&mut impl_args),
+            "Ownable2Step" => impl_ownable_2_step(&mut impl_args),
             "PaymentSplitter" => impl_payment_splitter(&mut impl_args),
             "AccessControl" => impl_access_control(&mut impl_args),
             "AccessControlEnumerable" => impl_access_control_enumerable(&mut impl_args),
+            "AccessControlDefaultAdminRules" => impl_access_control_default_admin_rules(&mut impl_args),
             "Pausable" => impl_pausable(&mut impl_args),
+            "GranularPausable" => impl_granular_pausable(&mut impl_args),
+            "ReentrancyGuard" => impl_reentrancy_guard(&mut impl_args),
             "TimelockController" => impl_timelock_controller(&mut impl_args),
+            "Multisig" => impl_multisig(&mut impl_args),
             "Proxy" => impl_proxy(&mut impl_args),
+            "TransparentProxy" => impl_transparent_proxy(&mut impl_args),
+            "Initializable" => impl_initializable(&mut impl_args),
             "Diamond" => impl_diamond(&mut impl_args),
             "DiamondLoupe" => impl_diamond_loupe(&mut impl_args),
+            "DiamondFacet" => impl_diamond_facet(&mut impl_args),
             "Upgradeable" => impl_upgradeable(&mut impl_args),
             "Governor" => impl_governor(&mut impl_args),
             "GovernorSettings" => impl_governor_settings(&mut impl_args),
             "GovernorVotes" => impl_governor_votes(&mut impl_args),
             "GovernorQuorum" => impl_governor_quorum(&mut impl_args),
+            "GovernorPreventLateQuorum" => impl_governor_prevent_late_quorum(&mut impl_args),
             "GovernorCounting" => impl_governor_counting(&mut impl_args),
+            "GovernorCountingFractional" => impl_governor_counting_fractional(&mut impl_args),
+            "GovernorTimelock" => impl_governor_timelock(&mut impl_args),
             "Nonces" => impl_nonces(&mut impl_args),
             "PSP61" => impl_psp61(&mut impl_args, args.clone()),
-            _ => panic!("openbrush::implementation({to_implement}) not implemented!"),
+            _ => impl_registered(&mut impl_args, to_implement),
         }
     }
 
+    if guard_calls {
+        guard_external_calls(&mut impl_args, user_items);
+    }
+
+    if default_events {
+        emit_default_events(&mut impl_args, &args);
+    }
+
+    if args.iter().any(|arg| arg == "DiamondFacet") {
+        publish_facet_selectors(&mut impl_args);
+    }
+
+    if let Some(env) = extract_contract_env(&module) {
+        assert_compatible_env(&mut impl_args, &env);
+    }
+
     cleanup_imports(impl_args.imports);
 
     // add the imports
@@ -143,14 +191,46 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     }
 }
 
+/// Returns the environment specified by `#[openbrush::contract(env = MyEnv)]`, if any.
+fn extract_contract_env(module: &syn::ItemMod) -> Option<Path> {
+    module
+        .attrs
+        .iter()
+        .filter(|attr| {
+            attr.path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "contract")
+        })
+        .find_map(|attr| {
+            match attr.tokens.clone().into_iter().next() {
+                Some(proc_macro2::TokenTree::Group(group)) => internal::extract_env(group.stream()),
+                _ => None,
+            }
+        })
+}
+
+/// The implementations use the types of `DefaultEnvironment`, so the environment
+/// of the contract must be compatible with it.
+fn assert_compatible_env(impl_args: &mut ImplArgs, env: &Path) {
+    impl_args.items.push(syn::parse_quote! {
+        const _: fn() = || {
+            fn assert_compatible_environment<E: ::openbrush::traits::CompatibleEnvironment>() {}
+            assert_compatible_environment::<#env>();
+        };
+    });
+}
+
 fn cleanup_imports(imports: &mut HashMap<&str, syn::ItemUse>) {
     // we will remove unnecessary imports
     let psp22_impls = vec![
         "PSP22Mintable",
         "PSP22Burnable",
         "PSP22Capped",
+        "PSP22Snapshot",
         "PSP22Metadata",
         "PSP22Wrapper",
+        "PSP22Vault",
         "PSP22Permit",
         "Flashmint",
     ];
@@ -159,21 +239,34 @@ fn cleanup_imports(imports: &mut HashMap<&str, syn::ItemUse>) {
     let psp22_pallet_impls = vec!["PSP22PalletMintable", "PSP22PalletBurnable", "PSP22PalletMetadata"];
     check_and_remove_import("PSP22Pallet", psp22_pallet_impls, imports);
 
-    let psp34_impls = vec!["PSP34Mintable", "PSP34Burnable", "PSP34Metadata", "PSP34Enumerable"];
+    let psp34_impls = vec![
+        "PSP34Mintable",
+        "PSP34Burnable",
+        "PSP34Metadata",
+        "PSP34Enumerable",
+        "PSP34Royalty",
+    ];
     check_and_remove_import("PSP34", psp34_impls, imports);
 
     let psp37_impls = vec![
         "PSP37Batch",
         "PSP37Burnable",
+        "PSP37Capped",
         "PSP37Metadata",
         "PSP37Mintable",
         "PSP37Enumerable",
     ];
     check_and_remove_import("PSP37", psp37_impls, imports);
 
-    let access_impls = vec!["AccessControlEnumerable", "TimelockController"];
+    let access_impls = vec![
+        "AccessControlEnumerable",
+        "AccessControlDefaultAdminRules",
+        "TimelockController",
+    ];
     check_and_remove_import("AccessControl", access_impls, imports);
 
+    check_and_remove_import("Ownable", vec!["Ownable2Step"], imports);
+
     check_and_remove_import("Diamond", vec!["DiamondLoupe"], imports);
 }
 
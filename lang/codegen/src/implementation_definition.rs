@@ -0,0 +1,80 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::{
+    internal,
+    metadata::{
+        ImplementationDefinition,
+        LockedImplementation,
+    },
+};
+use proc_macro2::TokenStream;
+use quote::{
+    quote,
+    quote_spanned,
+};
+use syn::{
+    spanned::Spanned,
+    Item,
+};
+
+pub fn generate(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    if internal::skip() {
+        return quote! {}
+    }
+    let name = syn::parse2::<syn::Ident>(attrs).expect("Expected the name of the implementation");
+    let module = syn::parse2::<syn::ItemMod>(input).expect("Can't parse implementation definition module");
+
+    let items = match &module.content {
+        Some((_, items)) => items,
+        None => {
+            return quote_spanned! {
+                module.span() =>
+                    compile_error!("Implementation definition must be an inline module `mod name { ... }`");
+            }
+        }
+    };
+
+    for item in items.iter() {
+        match item {
+            Item::Use(_) | Item::Type(_) => {}
+            Item::Impl(item_impl) if item_impl.trait_.is_some() => {}
+            _ => {
+                return quote_spanned! {
+                    item.span() =>
+                        compile_error!(
+                            "Implementation definition can contain only imports, \
+                            `type Name = Data;` items for the required storage data \
+                            and implementations of traits");
+                }
+            }
+        }
+    }
+
+    // Brackets to force the unlock of the file after the update of the implementation definition
+    {
+        let mut implementation_lock = LockedImplementation::new(name.to_string());
+        implementation_lock.implementation_definition = Some(ImplementationDefinition::new(module));
+    }
+
+    quote! {}
+}
@@ -1,6 +1,8 @@
+use crate::metadata::LockedImplementation;
 use quote::{
     format_ident,
     quote,
+    ToTokens,
 };
 use std::collections::HashMap;
 use syn::Block;
@@ -197,8 +199,13 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
     override_functions("psp22::Internal", &mut internal, impl_args.map);
     override_functions("PSP22", &mut psp22, impl_args.map);
 
+    // only insert this if it is not present
+    impl_args
+        .overriden_traits
+        .entry("psp22::Internal")
+        .or_insert(syn::Item::Impl(internal));
+
     impl_args.items.push(syn::Item::Impl(internal_impl));
-    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(psp22_impl));
     impl_args.items.push(syn::Item::Impl(psp22));
 }
@@ -430,6 +437,160 @@ pub(crate) fn impl_psp22_capped(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(capped));
 }
 
+pub(crate) fn impl_psp22_snapshot(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl snapshot::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl snapshot::Internal for #storage_struct_name {
+            fn _emit_snapshot_event(&self, id: SnapshotId) {
+                snapshot::InternalImpl::_emit_snapshot_event(self, id)
+            }
+
+            fn _snapshot(&mut self) -> SnapshotId {
+                snapshot::InternalImpl::_snapshot(self)
+            }
+
+            fn _current_snapshot_id(&self) -> SnapshotId {
+                snapshot::InternalImpl::_current_snapshot_id(self)
+            }
+
+            fn _balance_of_at(&self, account: &AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+                snapshot::InternalImpl::_balance_of_at(self, account, snapshot_id)
+            }
+
+            fn _total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+                snapshot::InternalImpl::_total_supply_at(self, snapshot_id)
+            }
+
+            fn _update_snapshots(&mut self, from: Option<&AccountId>, to: Option<&AccountId>) -> Result<(), PSP22Error> {
+                snapshot::InternalImpl::_update_snapshots(self, from, to)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    // records the snapshots before the balances change
+    let mut psp22_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp22::Internal for #storage_struct_name {
+            fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, amount: Balance) {
+                psp22::InternalImpl::_emit_transfer_event(self, from, to, amount)
+            }
+
+            fn _emit_approval_event(&self, owner: AccountId, spender: AccountId, amount: Balance) {
+                psp22::InternalImpl::_emit_approval_event(self, owner, spender, amount)
+            }
+
+            fn _total_supply(&self) -> Balance {
+                psp22::InternalImpl::_total_supply(self)
+            }
+
+            fn _balance_of(&self, owner: &AccountId) -> Balance {
+                psp22::InternalImpl::_balance_of(self, owner)
+            }
+
+            fn _allowance(&self, owner: &AccountId, spender: &AccountId) -> Balance {
+                psp22::InternalImpl::_allowance(self, owner, spender)
+            }
+
+            fn _transfer_from_to(
+                &mut self,
+                from: AccountId,
+                to: AccountId,
+                amount: Balance,
+                data: Vec<u8>,
+            ) -> Result<(), PSP22Error> {
+                psp22::InternalImpl::_transfer_from_to(self, from, to, amount, data)
+            }
+
+            fn _approve_from_to(
+                &mut self,
+                owner: AccountId,
+                spender: AccountId,
+                amount: Balance,
+            ) -> Result<(), PSP22Error> {
+                psp22::InternalImpl::_approve_from_to(self, owner, spender, amount)
+            }
+
+            fn _mint_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                psp22::InternalImpl::_mint_to(self, account, amount)
+            }
+
+            fn _burn_from(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                psp22::InternalImpl::_burn_from(self, account, amount)
+            }
+
+            fn _before_token_transfer(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                snapshot::Internal::_update_snapshots(self, from, to)?;
+                psp22::InternalImpl::_before_token_transfer(self, from, to, amount)
+            }
+
+            fn _after_token_transfer(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                psp22::InternalImpl::_after_token_transfer(self, from, to, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let snapshot_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22SnapshotImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut snapshot = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Snapshot for #storage_struct_name {
+            #[ink(message)]
+            fn balance_of_at(&self, account: AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+                PSP22SnapshotImpl::balance_of_at(self, account, snapshot_id)
+            }
+
+            #[ink(message)]
+            fn total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+                PSP22SnapshotImpl::total_supply_at(self, snapshot_id)
+            }
+
+            #[ink(message)]
+            fn current_snapshot_id(&self) -> SnapshotId {
+                PSP22SnapshotImpl::current_snapshot_id(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::extensions::snapshot::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22Snapshot", import);
+    impl_args.vec_import();
+
+    override_functions("psp22::Internal", &mut psp22_internal, impl_args.map);
+    override_functions("snapshot::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Snapshot", &mut snapshot, impl_args.map);
+
+    impl_args
+        .overriden_traits
+        .insert("psp22::Internal", syn::Item::Impl(psp22_internal));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(snapshot_impl));
+    impl_args.items.push(syn::Item::Impl(snapshot));
+}
+
 pub(crate) fn impl_psp22_wrapper(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -502,6 +663,192 @@ pub(crate) fn impl_psp22_wrapper(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(wrapper));
 }
 
+pub(crate) fn impl_psp22_vault(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl vault::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl vault::Internal for #storage_struct_name {
+            fn _emit_deposit_event(&self, sender: AccountId, owner: AccountId, assets: Balance, shares: Balance) {
+                vault::InternalImpl::_emit_deposit_event(self, sender, owner, assets, shares)
+            }
+
+            fn _emit_withdraw_event(
+                &self,
+                sender: AccountId,
+                receiver: AccountId,
+                owner: AccountId,
+                assets: Balance,
+                shares: Balance,
+            ) {
+                vault::InternalImpl::_emit_withdraw_event(self, sender, receiver, owner, assets, shares)
+            }
+
+            fn _init(&mut self, asset: AccountId) {
+                vault::InternalImpl::_init(self, asset)
+            }
+
+            fn _asset(&self) -> Option<AccountId> {
+                vault::InternalImpl::_asset(self)
+            }
+
+            fn _total_assets(&self) -> Balance {
+                vault::InternalImpl::_total_assets(self)
+            }
+
+            fn _decimals_offset(&self) -> u8 {
+                vault::InternalImpl::_decimals_offset(self)
+            }
+
+            fn _convert_to_shares(&self, assets: Balance, rounding: Rounding) -> Result<Balance, PSP22Error> {
+                vault::InternalImpl::_convert_to_shares(self, assets, rounding)
+            }
+
+            fn _convert_to_assets(&self, shares: Balance, rounding: Rounding) -> Result<Balance, PSP22Error> {
+                vault::InternalImpl::_convert_to_assets(self, shares, rounding)
+            }
+
+            fn _deposit(
+                &mut self,
+                caller: AccountId,
+                receiver: AccountId,
+                assets: Balance,
+                shares: Balance,
+            ) -> Result<(), PSP22Error> {
+                vault::InternalImpl::_deposit(self, caller, receiver, assets, shares)
+            }
+
+            fn _withdraw(
+                &mut self,
+                caller: AccountId,
+                receiver: AccountId,
+                owner: AccountId,
+                assets: Balance,
+                shares: Balance,
+            ) -> Result<(), PSP22Error> {
+                vault::InternalImpl::_withdraw(self, caller, receiver, owner, assets, shares)
+            }
+
+            fn _transfer_asset_in(&mut self, from: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                vault::InternalImpl::_transfer_asset_in(self, from, amount)
+            }
+
+            fn _transfer_asset_out(&mut self, to: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+                vault::InternalImpl::_transfer_asset_out(self, to, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let vault_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22VaultImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut vault = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Vault for #storage_struct_name {
+            #[ink(message)]
+            fn asset(&self) -> Option<AccountId> {
+                PSP22VaultImpl::asset(self)
+            }
+
+            #[ink(message)]
+            fn total_assets(&self) -> Balance {
+                PSP22VaultImpl::total_assets(self)
+            }
+
+            #[ink(message)]
+            fn convert_to_shares(&self, assets: Balance) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::convert_to_shares(self, assets)
+            }
+
+            #[ink(message)]
+            fn convert_to_assets(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::convert_to_assets(self, shares)
+            }
+
+            #[ink(message)]
+            fn max_deposit(&self, receiver: AccountId) -> Balance {
+                PSP22VaultImpl::max_deposit(self, receiver)
+            }
+
+            #[ink(message)]
+            fn max_mint(&self, receiver: AccountId) -> Balance {
+                PSP22VaultImpl::max_mint(self, receiver)
+            }
+
+            #[ink(message)]
+            fn max_withdraw(&self, owner: AccountId) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::max_withdraw(self, owner)
+            }
+
+            #[ink(message)]
+            fn max_redeem(&self, owner: AccountId) -> Balance {
+                PSP22VaultImpl::max_redeem(self, owner)
+            }
+
+            #[ink(message)]
+            fn preview_deposit(&self, assets: Balance) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::preview_deposit(self, assets)
+            }
+
+            #[ink(message)]
+            fn preview_mint(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::preview_mint(self, shares)
+            }
+
+            #[ink(message)]
+            fn preview_withdraw(&self, assets: Balance) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::preview_withdraw(self, assets)
+            }
+
+            #[ink(message)]
+            fn preview_redeem(&self, shares: Balance) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::preview_redeem(self, shares)
+            }
+
+            #[ink(message)]
+            fn deposit(&mut self, assets: Balance, receiver: AccountId) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::deposit(self, assets, receiver)
+            }
+
+            #[ink(message)]
+            fn mint(&mut self, shares: Balance, receiver: AccountId) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::mint(self, shares, receiver)
+            }
+
+            #[ink(message)]
+            fn withdraw(&mut self, assets: Balance, receiver: AccountId, owner: AccountId) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::withdraw(self, assets, receiver, owner)
+            }
+
+            #[ink(message)]
+            fn redeem(&mut self, shares: Balance, receiver: AccountId, owner: AccountId) -> Result<Balance, PSP22Error> {
+                PSP22VaultImpl::redeem(self, shares, receiver, owner)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::extensions::vault::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22Vault", import);
+    impl_args.vec_import();
+
+    override_functions("vault::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Vault", &mut vault, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(vault_impl));
+    impl_args.items.push(syn::Item::Impl(vault));
+}
+
 pub(crate) fn impl_flashmint(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -660,8 +1007,18 @@ pub(crate) fn impl_token_timelock(impl_args: &mut ImplArgs) {
 
 pub(crate) fn impl_psp22_votes(impl_args: &mut ImplArgs) {
     let _storage_struct_name = impl_args.contract_name();
-    let votes_events = syn::parse2::<syn::ItemImpl>(quote!(
-        impl VotesEvents for Contract {}
+    let mut votes_events = syn::parse2::<syn::ItemImpl>(quote!(
+        impl VotesEvents for Contract {
+            fn emit_delegate_changed_event(
+                &self,
+                _delegator: &Option<AccountId>,
+                _from_delegate: &Option<AccountId>,
+                _to_delegate: &Option<AccountId>,
+            ) {
+            }
+
+            fn emit_delegate_votes_changed_event(&self, _delegate: &AccountId, _previous_votes: Balance, _new_votes: Balance) {}
+        }
     ))
     .expect("Should parse");
 
@@ -755,6 +1112,8 @@ pub(crate) fn impl_psp22_votes(impl_args: &mut ImplArgs) {
     .expect("Should parse");
     impl_args.imports.insert("PSP22Votes", import);
 
+    override_functions("VotesEvents", &mut votes_events, impl_args.map);
+
     impl_args.items.push(syn::Item::Impl(votes_events));
     impl_args.items.push(syn::Item::Impl(votes_internal));
     impl_args.items.push(syn::Item::Impl(votes_impl));
@@ -772,12 +1131,12 @@ pub(crate) fn impl_psp22_pallet(impl_args: &mut ImplArgs) {
 
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
         impl psp22_pallet::Internal for #storage_struct_name {
-            fn _emit_transfer_event(&self, _from: Option<AccountId>, _to: Option<AccountId>, _amount: Balance) {
-                psp22_pallet::InternalImpl::_emit_transfer_event(self, _from, _to, _amount)
+            fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, amount: Balance) {
+                psp22_pallet::InternalImpl::_emit_transfer_event(self, from, to, amount)
             }
 
-            fn _emit_approval_event(&self, _owner: AccountId, _spender: AccountId, _amount: Balance) {
-                psp22_pallet::InternalImpl::_emit_approval_event(self, _owner, _spender, _amount)
+            fn _emit_approval_event(&self, owner: AccountId, spender: AccountId, amount: Balance) {
+                psp22_pallet::InternalImpl::_emit_approval_event(self, owner, spender, amount)
             }
 
             fn _mint_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
@@ -1276,32 +1635,99 @@ pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(metadata));
 }
 
-pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp34_royalty(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34EnumerableImpl for #storage_struct_name {}
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl royalty::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut psp34_enumerable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34Enumerable for #storage_struct_name {
-            #[ink(message)]
-            fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Result<Id, PSP34Error> {
-                PSP34EnumerableImpl::owners_token_by_index(self, owner, index)
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl royalty::Internal for #storage_struct_name {
+            fn _fee_denominator(&self) -> u16 {
+                royalty::InternalImpl::_fee_denominator(self)
             }
 
-            #[ink(message)]
-            fn token_by_index(&self, index: u128) -> Result<Id, PSP34Error> {
-                PSP34EnumerableImpl::token_by_index(self, index)
+            fn _royalty_info(&self, id: &Id, sale_price: Balance) -> Option<(AccountId, Balance)> {
+                royalty::InternalImpl::_royalty_info(self, id, sale_price)
             }
-        }
 
-    ))
-    .expect("Should parse");
+            fn _set_default_royalty(&mut self, receiver: AccountId, fee_numerator: u16) -> Result<(), PSP34Error> {
+                royalty::InternalImpl::_set_default_royalty(self, receiver, fee_numerator)
+            }
 
-    let psp34_balances_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl enumerable::BalancesManagerImpl for #storage_struct_name {}
-    ))
+            fn _delete_default_royalty(&mut self) {
+                royalty::InternalImpl::_delete_default_royalty(self)
+            }
+
+            fn _set_token_royalty(&mut self, id: Id, receiver: AccountId, fee_numerator: u16) -> Result<(), PSP34Error> {
+                royalty::InternalImpl::_set_token_royalty(self, id, receiver, fee_numerator)
+            }
+
+            fn _delete_token_royalty(&mut self, id: Id) {
+                royalty::InternalImpl::_delete_token_royalty(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let royalty_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34RoyaltyImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut royalty = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Royalty for #storage_struct_name {
+            #[ink(message)]
+            fn royalty_info(&self, id: Id, sale_price: Balance) -> Option<(AccountId, Balance)> {
+                PSP34RoyaltyImpl::royalty_info(self, id, sale_price)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::royalty::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34Royalty", import);
+    impl_args.vec_import();
+
+    override_functions("royalty::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34Royalty", &mut royalty, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(royalty_impl));
+    impl_args.items.push(syn::Item::Impl(royalty));
+}
+
+pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34EnumerableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut psp34_enumerable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Enumerable for #storage_struct_name {
+            #[ink(message)]
+            fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Result<Id, PSP34Error> {
+                PSP34EnumerableImpl::owners_token_by_index(self, owner, index)
+            }
+
+            #[ink(message)]
+            fn token_by_index(&self, index: u128) -> Result<Id, PSP34Error> {
+                PSP34EnumerableImpl::token_by_index(self, index)
+            }
+        }
+
+    ))
+    .expect("Should parse");
+
+    let psp34_balances_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::BalancesManagerImpl for #storage_struct_name {}
+    ))
     .expect("Should parse");
 
     let mut psp34_balances = syn::parse2::<syn::ItemImpl>(quote!(
@@ -1590,8 +2016,12 @@ pub(crate) fn impl_psp37(impl_args: &mut ImplArgs) {
         .entry("psp37::BalancesManagerImpl")
         .or_insert(syn::Item::Impl(psp37_balances_impl));
 
+    impl_args
+        .overriden_traits
+        .entry("psp37::Internal")
+        .or_insert(syn::Item::Impl(internal));
+
     impl_args.items.push(syn::Item::Impl(internal_impl));
-    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(psp37_impl));
     impl_args.items.push(syn::Item::Impl(psp37));
 }
@@ -1695,6 +2125,169 @@ pub(crate) fn impl_psp37_burnable(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(burnable));
 }
 
+pub(crate) fn impl_psp37_capped(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl capped::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl capped::Internal for #storage_struct_name {
+            fn _exists(&self, id: &Id) -> bool {
+                capped::InternalImpl::_exists(self, id)
+            }
+
+            fn _max_supply(&self, id: &Id) -> Option<Balance> {
+                capped::InternalImpl::_max_supply(self, id)
+            }
+
+            fn _set_max_supply(&mut self, id: &Id, max_supply: Balance) -> Result<(), PSP37Error> {
+                capped::InternalImpl::_set_max_supply(self, id, max_supply)
+            }
+
+            fn _check_max_supply(&self, ids_amounts: &[(Id, Balance)]) -> Result<(), PSP37Error> {
+                capped::InternalImpl::_check_max_supply(self, ids_amounts)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    // rejects the mints above the max supply
+    let mut psp37_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp37::Internal for #storage_struct_name {
+            fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id, amount: Balance) {
+                psp37::InternalImpl::_emit_transfer_event(self, from, to, id, amount)
+            }
+
+            fn _emit_transfer_batch_event(
+                &self,
+                from: Option<AccountId>,
+                to: Option<AccountId>,
+                ids_amounts: Vec<(Id, Balance)>,
+            ) {
+                psp37::InternalImpl::_emit_transfer_batch_event(self, from, to, ids_amounts)
+            }
+
+            fn _emit_approval_event(&self, owner: AccountId, operator: AccountId, id: Option<Id>, value: Balance) {
+                psp37::InternalImpl::_emit_approval_event(self, owner, operator, id, value)
+            }
+
+            fn _mint_to(&mut self, to: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+                psp37::InternalImpl::_mint_to(self, to, ids_amounts)
+            }
+
+            fn _burn_from(&mut self, from: AccountId, ids_amounts: Vec<(Id, Balance)>) -> Result<(), PSP37Error> {
+                psp37::InternalImpl::_burn_from(self, from, ids_amounts)
+            }
+
+            fn _transfer_from(
+                &mut self,
+                from: AccountId,
+                to: AccountId,
+                id: Id,
+                amount: Balance,
+                data: Vec<u8>,
+            ) -> Result<(), PSP37Error> {
+                psp37::InternalImpl::_transfer_from(self, from, to, id, amount, data)
+            }
+
+            fn _get_allowance(&self, account: &AccountId, operator: &AccountId, id: &Option<&Id>) -> Balance {
+                psp37::InternalImpl::_get_allowance(self, account, operator, id)
+            }
+
+            fn _approve_for(&mut self, operator: AccountId, id: Option<Id>, value: Balance) -> Result<(), PSP37Error> {
+                psp37::InternalImpl::_approve_for(self, operator, id, value)
+            }
+
+            fn _decrease_allowance(
+                &mut self,
+                owner: &AccountId,
+                operator: &AccountId,
+                id: &Id,
+                value: Balance,
+            ) -> Result<(), PSP37Error> {
+                psp37::InternalImpl::_decrease_allowance(self, owner, operator, id, value)
+            }
+
+            fn _transfer_token(
+                &mut self,
+                from: &AccountId,
+                to: &AccountId,
+                id: Id,
+                amount: Balance,
+                data: &[u8],
+            ) -> Result<(), PSP37Error> {
+                psp37::InternalImpl::_transfer_token(self, from, to, id, amount, data)
+            }
+
+            fn _before_token_transfer(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                ids: &[(Id, Balance)],
+            ) -> Result<(), PSP37Error> {
+                // `is_none` means that it is minting
+                if from.is_none() {
+                    capped::Internal::_check_max_supply(self, ids)?;
+                }
+                psp37::InternalImpl::_before_token_transfer(self, from, to, ids)
+            }
+
+            fn _after_token_transfer(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                ids: &[(Id, Balance)],
+            ) -> Result<(), PSP37Error> {
+                psp37::InternalImpl::_after_token_transfer(self, from, to, ids)
+            }
+        }
+
+    ))
+    .expect("Should parse");
+
+    let capped_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP37CappedImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut capped = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP37Capped for #storage_struct_name {
+            #[ink(message)]
+            fn exists(&self, id: Id) -> bool {
+                PSP37CappedImpl::exists(self, id)
+            }
+
+            #[ink(message)]
+            fn max_supply(&self, id: Id) -> Option<Balance> {
+                PSP37CappedImpl::max_supply(self, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp37::extensions::capped::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP37Capped", import);
+    impl_args.vec_import();
+
+    override_functions("psp37::Internal", &mut psp37_internal, impl_args.map);
+    override_functions("capped::Internal", &mut internal, impl_args.map);
+    override_functions("PSP37Capped", &mut capped, impl_args.map);
+
+    impl_args
+        .overriden_traits
+        .insert("psp37::Internal", syn::Item::Impl(psp37_internal));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(capped_impl));
+    impl_args.items.push(syn::Item::Impl(capped));
+}
+
 pub(crate) fn impl_psp37_metadata(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -1933,10 +2526,94 @@ pub(crate) fn impl_ownable(impl_args: &mut ImplArgs) {
     override_functions("ownable::Internal", &mut internal, impl_args.map);
     override_functions("Ownable", &mut ownable, impl_args.map);
 
+    // only insert it if it is not present
+    impl_args
+        .overriden_traits
+        .entry("Ownable")
+        .or_insert(syn::Item::Impl(ownable));
+
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(ownable_impl));
-    impl_args.items.push(syn::Item::Impl(ownable));
+}
+
+pub(crate) fn impl_ownable_2_step(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl ownable_2_step::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl ownable_2_step::Internal for #storage_struct_name {
+            fn _emit_ownership_transfer_started_event(&self, previous: Option<AccountId>, new: AccountId) {
+                ownable_2_step::InternalImpl::_emit_ownership_transfer_started_event(self, previous, new)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let ownable_2_step_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Ownable2StepImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut ownable_2_step = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Ownable2Step for #storage_struct_name {
+            #[ink(message)]
+            fn pending_owner(&self) -> Option<AccountId> {
+                Ownable2StepImpl::pending_owner(self)
+            }
+
+            #[ink(message)]
+            fn accept_ownership(&mut self) -> Result<(), OwnableError> {
+                Ownable2StepImpl::accept_ownership(self)
+            }
+
+            #[ink(message)]
+            fn cancel_ownership_transfer(&mut self) -> Result<(), OwnableError> {
+                Ownable2StepImpl::cancel_ownership_transfer(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mut ownable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Ownable for #storage_struct_name {
+            #[ink(message)]
+            fn owner(&self) -> Option<AccountId> {
+                OwnableImpl::owner(self)
+            }
+
+            #[ink(message)]
+            fn renounce_ownership(&mut self) -> Result<(), OwnableError> {
+                Ownable2StepImpl::renounce_ownership(self)
+            }
+
+            #[ink(message)]
+            fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
+                Ownable2StepImpl::transfer_ownership(self, new_owner)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::ownable::ownable_2_step::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("Ownable2Step", import);
+
+    override_functions("ownable_2_step::Internal", &mut internal, impl_args.map);
+    override_functions("Ownable2Step", &mut ownable_2_step, impl_args.map);
+    override_functions("Ownable", &mut ownable, impl_args.map);
+
+    impl_args.overriden_traits.insert("Ownable", syn::Item::Impl(ownable));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(ownable_2_step_impl));
+    impl_args.items.push(syn::Item::Impl(ownable_2_step));
 }
 
 pub(crate) fn impl_payment_splitter(impl_args: &mut ImplArgs) {
@@ -2119,8 +2796,18 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
             }
 
             #[ink(message)]
-            fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-                AccessControlImpl::revoke_role(self, role, account)
+            fn grant_role_until(
+                &mut self,
+                role: RoleType,
+                account: Option<AccountId>,
+                expires_at: Timestamp,
+            ) -> Result<(), AccessControlError> {
+                AccessControlImpl::grant_role_until(self, role, account, expires_at)
+            }
+
+            #[ink(message)]
+            fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlImpl::revoke_role(self, role, account)
             }
 
             #[ink(message)]
@@ -2157,6 +2844,14 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
             fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
                 access_control::MembersManagerImpl::_set_role_admin(self, role, new_admin)
             }
+
+            fn _get_expiration(&self, role: RoleType, member: &Option<AccountId>) -> Option<Timestamp> {
+                access_control::MembersManagerImpl::_get_expiration(self, role, member)
+            }
+
+            fn _set_expiration(&mut self, role: RoleType, member: &Option<AccountId>, expires_at: Option<Timestamp>) {
+                access_control::MembersManagerImpl::_set_expiration(self, role, member, expires_at)
+            }
         }
     ))
     .expect("Should parse");
@@ -2182,10 +2877,244 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
         .entry("access_control::MembersManager")
         .or_insert(syn::Item::Impl(members));
 
+    impl_args
+        .overriden_traits
+        .entry("AccessControl")
+        .or_insert(syn::Item::Impl(access_control));
+
+    impl_args
+        .overriden_traits
+        .entry("access_control::Internal")
+        .or_insert(syn::Item::Impl(internal));
+
     impl_args.items.push(syn::Item::Impl(internal_impl));
-    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(access_control_impl));
-    impl_args.items.push(syn::Item::Impl(access_control));
+}
+
+pub(crate) fn impl_access_control_default_admin_rules(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl default_admin_rules::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl default_admin_rules::Internal for #storage_struct_name {
+            fn _emit_default_admin_transfer_scheduled(&self, new_admin: Option<AccountId>, accept_schedule: Timestamp) {
+                default_admin_rules::InternalImpl::_emit_default_admin_transfer_scheduled(self, new_admin, accept_schedule)
+            }
+
+            fn _emit_default_admin_transfer_canceled(&self) {
+                default_admin_rules::InternalImpl::_emit_default_admin_transfer_canceled(self)
+            }
+
+            fn _emit_default_admin_delay_change_scheduled(&self, new_delay: Timestamp, effect_schedule: Timestamp) {
+                default_admin_rules::InternalImpl::_emit_default_admin_delay_change_scheduled(self, new_delay, effect_schedule)
+            }
+
+            fn _emit_default_admin_delay_change_canceled(&self) {
+                default_admin_rules::InternalImpl::_emit_default_admin_delay_change_canceled(self)
+            }
+
+            fn _init_with_default_admin(
+                &mut self,
+                admin: Option<AccountId>,
+                initial_delay: Timestamp,
+            ) -> Result<(), AccessControlError> {
+                default_admin_rules::InternalImpl::_init_with_default_admin(self, admin, initial_delay)
+            }
+
+            fn _setup_role_with_rules(&mut self, role: RoleType, member: Option<AccountId>) -> Result<(), AccessControlError> {
+                default_admin_rules::InternalImpl::_setup_role_with_rules(self, role, member)
+            }
+
+            fn _default_admin_delay_increase_wait() -> Timestamp {
+                <Self as default_admin_rules::InternalImpl>::_default_admin_delay_increase_wait()
+            }
+
+            fn _delay_change_wait(&self, current_delay: Timestamp, new_delay: Timestamp) -> Timestamp {
+                default_admin_rules::InternalImpl::_delay_change_wait(self, current_delay, new_delay)
+            }
+
+            fn _has_schedule_passed(&self, schedule: Timestamp) -> bool {
+                default_admin_rules::InternalImpl::_has_schedule_passed(self, schedule)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let default_admin_rules_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlDefaultAdminRulesImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut default_admin_rules = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlDefaultAdminRules for #storage_struct_name {
+            #[ink(message)]
+            fn default_admin(&self) -> Option<AccountId> {
+                AccessControlDefaultAdminRulesImpl::default_admin(self)
+            }
+
+            #[ink(message)]
+            fn pending_default_admin(&self) -> (Option<AccountId>, Timestamp) {
+                AccessControlDefaultAdminRulesImpl::pending_default_admin(self)
+            }
+
+            #[ink(message)]
+            fn default_admin_delay(&self) -> Timestamp {
+                AccessControlDefaultAdminRulesImpl::default_admin_delay(self)
+            }
+
+            #[ink(message)]
+            fn pending_default_admin_delay(&self) -> (Timestamp, Timestamp) {
+                AccessControlDefaultAdminRulesImpl::pending_default_admin_delay(self)
+            }
+
+            #[ink(message)]
+            fn begin_default_admin_transfer(&mut self, new_admin: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::begin_default_admin_transfer(self, new_admin)
+            }
+
+            #[ink(message)]
+            fn cancel_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::cancel_default_admin_transfer(self)
+            }
+
+            #[ink(message)]
+            fn accept_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::accept_default_admin_transfer(self)
+            }
+
+            #[ink(message)]
+            fn change_default_admin_delay(&mut self, new_delay: Timestamp) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::change_default_admin_delay(self, new_delay)
+            }
+
+            #[ink(message)]
+            fn rollback_default_admin_delay(&mut self) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::rollback_default_admin_delay(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    // the internal role management of `AccessControl` has to keep track of the default admin
+    let mut access_control_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl access_control::Internal for #storage_struct_name {
+            fn _emit_role_admin_changed(&mut self, role: RoleType, previous: RoleType, new: RoleType) {
+                access_control::InternalImpl::_emit_role_admin_changed(self, role, previous, new);
+            }
+
+            fn _emit_role_granted(&mut self, role: RoleType, grantee: Option<AccountId>, grantor: Option<AccountId>) {
+                access_control::InternalImpl::_emit_role_granted(self, role, grantee, grantor);
+            }
+
+            fn _emit_role_revoked(&mut self, role: RoleType, account: Option<AccountId>, sender: AccountId) {
+                access_control::InternalImpl::_emit_role_revoked(self, role, account, sender);
+            }
+
+            fn _default_admin() -> RoleType {
+                <Self as access_control::InternalImpl>::_default_admin()
+            }
+
+            fn _init_with_caller(&mut self) {
+                access_control::InternalImpl::_init_with_caller(self);
+            }
+
+            fn _init_with_admin(&mut self, admin: Option<AccountId>) {
+                access_control::InternalImpl::_init_with_admin(self, admin);
+            }
+
+            fn _setup_role(&mut self, role: RoleType, member: Option<AccountId>) {
+                let _ = default_admin_rules::Internal::_setup_role_with_rules(self, role, member);
+            }
+
+            fn _do_revoke_role(&mut self, role: RoleType, account: Option<AccountId>) {
+                default_admin_rules::InternalImpl::_do_revoke_role_with_rules(self, role, account);
+            }
+
+            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
+                access_control::InternalImpl::_set_role_admin(self, role, new_admin);
+            }
+
+            fn _check_role(&self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                access_control::InternalImpl::_check_role(self, role, account)
+            }
+
+            fn _get_role_admin(&self, role: RoleType) -> RoleType {
+                access_control::InternalImpl::_get_role_admin(self, role)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mut access_control = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControl for #storage_struct_name {
+            #[ink(message)]
+            fn has_role(&self, role: RoleType, address: Option<AccountId>) -> bool {
+                AccessControlImpl::has_role(self, role, address)
+            }
+
+            #[ink(message)]
+            fn get_role_admin(&self, role: RoleType) -> RoleType {
+                AccessControlImpl::get_role_admin(self, role)
+            }
+
+            #[ink(message)]
+            fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::grant_role(self, role, account)
+            }
+
+            #[ink(message)]
+            fn grant_role_until(
+                &mut self,
+                role: RoleType,
+                account: Option<AccountId>,
+                expires_at: Timestamp,
+            ) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::grant_role_until(self, role, account, expires_at)
+            }
+
+            #[ink(message)]
+            fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::revoke_role(self, role, account)
+            }
+
+            #[ink(message)]
+            fn renounce_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::renounce_role(self, role, account)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::access_control::extensions::default_admin_rules::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AccessControlDefaultAdminRules", import);
+
+    override_functions("default_admin_rules::Internal", &mut internal, impl_args.map);
+    override_functions(
+        "AccessControlDefaultAdminRules",
+        &mut default_admin_rules,
+        impl_args.map,
+    );
+    override_functions("access_control::Internal", &mut access_control_internal, impl_args.map);
+    override_functions("AccessControl", &mut access_control, impl_args.map);
+
+    impl_args
+        .overriden_traits
+        .insert("AccessControl", syn::Item::Impl(access_control));
+
+    impl_args
+        .overriden_traits
+        .insert("access_control::Internal", syn::Item::Impl(access_control_internal));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(default_admin_rules_impl));
+    impl_args.items.push(syn::Item::Impl(default_admin_rules));
 }
 
 pub(crate) fn impl_access_control_enumerable(impl_args: &mut ImplArgs) {
@@ -2206,6 +3135,11 @@ pub(crate) fn impl_access_control_enumerable(impl_args: &mut ImplArgs) {
             fn get_role_member_count(&self, role: RoleType) -> u32 {
                 AccessControlEnumerableImpl::get_role_member_count(self, role)
             }
+
+            #[ink(message)]
+            fn prune_expired_role_members(&mut self, role: RoleType) -> u32 {
+                AccessControlEnumerableImpl::prune_expired_role_members(self, role)
+            }
         }
     ))
     .expect("Should parse");
@@ -2236,6 +3170,14 @@ pub(crate) fn impl_access_control_enumerable(impl_args: &mut ImplArgs) {
             fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
                 enumerable::MembersManagerImpl::_set_role_admin(self, role, new_admin)
             }
+
+            fn _get_expiration(&self, role: RoleType, member: &Option<AccountId>) -> Option<Timestamp> {
+                enumerable::MembersManagerImpl::_get_expiration(self, role, member)
+            }
+
+            fn _set_expiration(&mut self, role: RoleType, member: &Option<AccountId>, expires_at: Option<Timestamp>) {
+                enumerable::MembersManagerImpl::_set_expiration(self, role, member, expires_at)
+            }
         }
     ))
     .expect("Should parse");
@@ -2326,6 +3268,171 @@ pub(crate) fn impl_pausable(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(pausable));
 }
 
+pub(crate) fn impl_granular_pausable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl granular_pausable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl granular_pausable::Internal for #storage_struct_name {
+            fn _emit_feature_paused_event(&self, feature: Feature, account: AccountId) {
+                granular_pausable::InternalImpl::_emit_feature_paused_event(self, feature, account)
+            }
+
+            fn _emit_feature_unpaused_event(&self, feature: Feature, account: AccountId) {
+                granular_pausable::InternalImpl::_emit_feature_unpaused_event(self, feature, account)
+            }
+
+            fn _feature_paused(&self, feature: Feature) -> bool {
+                granular_pausable::InternalImpl::_feature_paused(self, feature)
+            }
+
+            fn _pause_feature(&mut self, feature: Feature) -> Result<(), PausableError> {
+                granular_pausable::InternalImpl::_pause_feature(self, feature)
+            }
+
+            fn _unpause_feature(&mut self, feature: Feature) -> Result<(), PausableError> {
+                granular_pausable::InternalImpl::_unpause_feature(self, feature)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let granular_pausable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GranularPausableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut granular_pausable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GranularPausable for #storage_struct_name {
+            #[ink(message)]
+            fn feature_paused(&self, feature: Feature) -> bool {
+                GranularPausableImpl::feature_paused(self, feature)
+            }
+
+            #[ink(message)]
+            fn pause_feature(&mut self, feature: Feature) -> Result<(), GranularPausableError> {
+                GranularPausableImpl::pause_feature(self, feature)
+            }
+
+            #[ink(message)]
+            fn unpause_feature(&mut self, feature: Feature) -> Result<(), GranularPausableError> {
+                GranularPausableImpl::unpause_feature(self, feature)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::pausable::granular_pausable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("GranularPausable", import);
+
+    override_functions("granular_pausable::Internal", &mut internal, impl_args.map);
+    override_functions("GranularPausable", &mut granular_pausable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(granular_pausable_impl));
+    impl_args.items.push(syn::Item::Impl(granular_pausable));
+}
+
+pub(crate) fn impl_reentrancy_guard(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let reentrancy_guard_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl ReentrancyGuardImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut reentrancy_guard = syn::parse2::<syn::ItemImpl>(quote!(
+        impl ReentrancyGuard for #storage_struct_name {
+            #[ink(message)]
+            fn reentrancy_guard_entered(&self) -> bool {
+                ReentrancyGuardImpl::reentrancy_guard_entered(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::reentrancy_guard::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("ReentrancyGuard", import);
+
+    override_functions("ReentrancyGuard", &mut reentrancy_guard, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(reentrancy_guard_impl));
+    impl_args.items.push(syn::Item::Impl(reentrancy_guard));
+}
+
+/// Adds the `non_reentrant` modifier to the default messages which make cross-contract calls.
+/// It is applied only to the generated implementations (the first `user_items` items belong to the user)
+/// and skips the messages which already carry the `non_reentrant` modifier.
+pub(crate) fn guard_external_calls(impl_args: &mut ImplArgs, user_items: usize) {
+    let guarded_messages = [
+        ("FlashLender", vec!["flashloan"]),
+        ("TimelockController", vec!["execute", "execute_batch"]),
+    ];
+
+    let generated = impl_args
+        .items
+        .iter_mut()
+        .skip(user_items)
+        .chain(impl_args.overriden_traits.values_mut());
+
+    for item in generated {
+        if let syn::Item::Impl(implementation) = item {
+            let trait_name = match &implementation.trait_ {
+                Some((_, path, _)) => path.segments.last().expect("Trait path is not empty").ident.to_string(),
+                None => continue,
+            };
+
+            let methods = match guarded_messages.iter().find(|(name, _)| *name == trait_name) {
+                Some((_, methods)) => methods,
+                None => continue,
+            };
+
+            for impl_item in implementation.items.iter_mut() {
+                if let syn::ImplItem::Method(method) = impl_item {
+                    if methods.contains(&method.sig.ident.to_string().as_str()) && !is_non_reentrant(&method.attrs) {
+                        method
+                            .attrs
+                            .push(syn::parse_quote!(#[openbrush::modifiers(non_reentrant)]));
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if one of the `modifiers` attributes already contains the `non_reentrant` modifier.
+fn is_non_reentrant(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| {
+            attr.path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "modifiers")
+        })
+        .any(|attr| {
+            attr.tokens.clone().into_iter().any(|token| {
+                match token {
+                    proc_macro2::TokenTree::Group(group) => {
+                        group.stream().into_iter().any(
+                            |token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "non_reentrant"),
+                        )
+                    }
+                    _ => false,
+                }
+            })
+        })
+}
+
 pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -2566,6 +3673,169 @@ pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(timelock_controller));
 }
 
+pub(crate) fn impl_multisig(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl multisig::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl multisig::Internal for #storage_struct_name {
+            fn _emit_owner_added_event(&self, owner: AccountId) {
+                multisig::InternalImpl::_emit_owner_added_event(self, owner)
+            }
+
+            fn _emit_owner_removed_event(&self, owner: AccountId) {
+                multisig::InternalImpl::_emit_owner_removed_event(self, owner)
+            }
+
+            fn _emit_threshold_changed_event(&self, old_threshold: u32, new_threshold: u32) {
+                multisig::InternalImpl::_emit_threshold_changed_event(self, old_threshold, new_threshold)
+            }
+
+            fn _emit_transaction_proposed_event(&self, id: TransactionId, transaction: Transaction, proposer: AccountId) {
+                multisig::InternalImpl::_emit_transaction_proposed_event(self, id, transaction, proposer)
+            }
+
+            fn _emit_transaction_approved_event(&self, id: TransactionId, owner: AccountId) {
+                multisig::InternalImpl::_emit_transaction_approved_event(self, id, owner)
+            }
+
+            fn _emit_approval_revoked_event(&self, id: TransactionId, owner: AccountId) {
+                multisig::InternalImpl::_emit_approval_revoked_event(self, id, owner)
+            }
+
+            fn _emit_transaction_executed_event(&self, id: TransactionId) {
+                multisig::InternalImpl::_emit_transaction_executed_event(self, id)
+            }
+
+            fn _init(&mut self, owners: Vec<AccountId>, threshold: u32) -> Result<(), MultisigError> {
+                multisig::InternalImpl::_init(self, owners, threshold)
+            }
+
+            fn _is_owner(&self, account: &AccountId) -> bool {
+                multisig::InternalImpl::_is_owner(self, account)
+            }
+
+            fn _approval_count(&self, id: TransactionId) -> u32 {
+                multisig::InternalImpl::_approval_count(self, id)
+            }
+
+            fn _approve(&mut self, id: TransactionId, owner: AccountId) -> Result<(), MultisigError> {
+                multisig::InternalImpl::_approve(self, id, owner)
+            }
+
+            fn _add_owner(&mut self, owner: AccountId) -> Result<(), MultisigError> {
+                multisig::InternalImpl::_add_owner(self, owner)
+            }
+
+            fn _remove_owner(&mut self, owner: AccountId) -> Result<(), MultisigError> {
+                multisig::InternalImpl::_remove_owner(self, owner)
+            }
+
+            fn _change_threshold(&mut self, threshold: u32) -> Result<(), MultisigError> {
+                multisig::InternalImpl::_change_threshold(self, threshold)
+            }
+
+            fn _call(&mut self, id: TransactionId, transaction: Transaction) -> Result<(), MultisigError> {
+                multisig::InternalImpl::_call(self, id, transaction)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let multisig_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl MultisigImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut multisig = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Multisig for #storage_struct_name {
+            #[ink(message)]
+            fn owners(&self) -> Vec<AccountId> {
+                MultisigImpl::owners(self)
+            }
+
+            #[ink(message)]
+            fn is_owner(&self, account: AccountId) -> bool {
+                MultisigImpl::is_owner(self, account)
+            }
+
+            #[ink(message)]
+            fn threshold(&self) -> u32 {
+                MultisigImpl::threshold(self)
+            }
+
+            #[ink(message)]
+            fn transaction(&self, id: TransactionId) -> Option<Transaction> {
+                MultisigImpl::transaction(self, id)
+            }
+
+            #[ink(message)]
+            fn has_approved(&self, id: TransactionId, owner: AccountId) -> bool {
+                MultisigImpl::has_approved(self, id, owner)
+            }
+
+            #[ink(message)]
+            fn approval_count(&self, id: TransactionId) -> u32 {
+                MultisigImpl::approval_count(self, id)
+            }
+
+            #[ink(message)]
+            fn propose(&mut self, transaction: Transaction) -> Result<TransactionId, MultisigError> {
+                MultisigImpl::propose(self, transaction)
+            }
+
+            #[ink(message)]
+            fn approve(&mut self, id: TransactionId) -> Result<(), MultisigError> {
+                MultisigImpl::approve(self, id)
+            }
+
+            #[ink(message)]
+            fn revoke(&mut self, id: TransactionId) -> Result<(), MultisigError> {
+                MultisigImpl::revoke(self, id)
+            }
+
+            #[ink(message, payable)]
+            fn execute(&mut self, id: TransactionId) -> Result<(), MultisigError> {
+                MultisigImpl::execute(self, id)
+            }
+
+            #[ink(message)]
+            fn add_owner(&mut self, owner: AccountId) -> Result<(), MultisigError> {
+                MultisigImpl::add_owner(self, owner)
+            }
+
+            #[ink(message)]
+            fn remove_owner(&mut self, owner: AccountId) -> Result<(), MultisigError> {
+                MultisigImpl::remove_owner(self, owner)
+            }
+
+            #[ink(message)]
+            fn change_threshold(&mut self, threshold: u32) -> Result<(), MultisigError> {
+                MultisigImpl::change_threshold(self, threshold)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::governance::extensions::multisig::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("Multisig", import);
+    impl_args.vec_import();
+
+    override_functions("multisig::Internal", &mut internal, impl_args.map);
+    override_functions("Multisig", &mut multisig, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(multisig_impl));
+    impl_args.items.push(syn::Item::Impl(multisig));
+}
+
 pub(crate) fn impl_proxy(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -2625,6 +3895,154 @@ pub(crate) fn impl_proxy(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(proxy));
 }
 
+pub(crate) fn impl_transparent_proxy(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl transparent_proxy::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl transparent_proxy::Internal for #storage_struct_name {
+            fn _emit_admin_changed_event(&self, previous: Option<AccountId>, new: Option<AccountId>) {
+                transparent_proxy::InternalImpl::_emit_admin_changed_event(self, previous, new)
+            }
+
+            fn _emit_delegate_code_changed_event(&self, previous: Option<Hash>, new: Option<Hash>) {
+                transparent_proxy::InternalImpl::_emit_delegate_code_changed_event(self, previous, new)
+            }
+
+            fn _init_with_admin(&mut self, admin: AccountId, forward_to: Hash) {
+                transparent_proxy::InternalImpl::_init_with_admin(self, admin, forward_to)
+            }
+
+            fn _upgrade_to(&mut self, code_hash: Hash) {
+                transparent_proxy::InternalImpl::_upgrade_to(self, code_hash)
+            }
+
+            fn _fallback_if_not_admin(&self) {
+                transparent_proxy::InternalImpl::_fallback_if_not_admin(self)
+            }
+
+            fn _fallback(&self) -> ! {
+                transparent_proxy::InternalImpl::_fallback(self)
+            }
+
+            fn _init_call(&self, call: InitCall) -> ! {
+                transparent_proxy::InternalImpl::_init_call(self, call)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let transparent_proxy_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl TransparentProxyImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut transparent_proxy = syn::parse2::<syn::ItemImpl>(quote!(
+        impl TransparentProxy for #storage_struct_name {
+            #[ink(message)]
+            fn proxy_admin(&self) -> Option<AccountId> {
+                TransparentProxyImpl::proxy_admin(self)
+            }
+
+            #[ink(message)]
+            fn proxy_implementation(&self) -> Hash {
+                TransparentProxyImpl::proxy_implementation(self)
+            }
+
+            #[ink(message)]
+            fn change_proxy_admin(&mut self, new_admin: AccountId) -> Result<(), ProxyError> {
+                TransparentProxyImpl::change_proxy_admin(self, new_admin)
+            }
+
+            #[ink(message)]
+            fn upgrade_to(&mut self, code_hash: Hash) -> Result<(), ProxyError> {
+                TransparentProxyImpl::upgrade_to(self, code_hash)
+            }
+
+            #[ink(message)]
+            fn upgrade_to_and_call(&mut self, code_hash: Hash, init: InitCall) -> Result<(), ProxyError> {
+                TransparentProxyImpl::upgrade_to_and_call(self, code_hash, init)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::proxy::transparent_proxy::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("TransparentProxy", import);
+
+    override_functions("transparent_proxy::Internal", &mut internal, impl_args.map);
+    override_functions("TransparentProxy", &mut transparent_proxy, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(transparent_proxy_impl));
+    impl_args.items.push(syn::Item::Impl(transparent_proxy));
+}
+
+pub(crate) fn impl_initializable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl initializable::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl initializable::Internal for #storage_struct_name {
+            fn _emit_initialized_event(&self, version: u64) {
+                initializable::InternalImpl::_emit_initialized_event(self, version)
+            }
+
+            fn _get_initialized_version(&self) -> u64 {
+                initializable::InternalImpl::_get_initialized_version(self)
+            }
+
+            fn _is_initializing(&self) -> bool {
+                initializable::InternalImpl::_is_initializing(self)
+            }
+
+            fn _disable_initializers(&mut self) -> Result<(), InitializableError> {
+                initializable::InternalImpl::_disable_initializers(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let initializable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl InitializableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut initializable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Initializable for #storage_struct_name {
+            #[ink(message)]
+            fn initialized_version(&self) -> u64 {
+                InitializableImpl::initialized_version(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::initializable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("Initializable", import);
+
+    override_functions("initializable::Internal", &mut internal, impl_args.map);
+    override_functions("Initializable", &mut initializable, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(initializable_impl));
+    impl_args.items.push(syn::Item::Impl(initializable));
+}
+
 pub(crate) fn impl_diamond(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -2646,6 +4064,30 @@ pub(crate) fn impl_diamond(impl_args: &mut ImplArgs) {
                 diamond::InternalImpl::_diamond_cut_facet(self, facet_cut)
             }
 
+            fn _facet_info(&self, code_hash: Hash) -> Result<FacetInfo, DiamondError> {
+                diamond::InternalImpl::_facet_info(self, code_hash)
+            }
+
+            fn _require_facet_info(&self) -> bool {
+                diamond::InternalImpl::_require_facet_info(self)
+            }
+
+            fn _claim_storage_keys(&mut self, code_hash: Hash, storage_keys: &[u32]) {
+                diamond::InternalImpl::_claim_storage_keys(self, code_hash, storage_keys)
+            }
+
+            fn _make_selectors_immutable(&mut self, selectors: &[Selector]) -> Result<(), DiamondError> {
+                diamond::InternalImpl::_make_selectors_immutable(self, selectors)
+            }
+
+            fn _register_interfaces(&mut self) {
+                diamond::InternalImpl::_register_interfaces(self)
+            }
+
+            fn _supported_interfaces(&self) -> Vec<u32> {
+                diamond::InternalImpl::_supported_interfaces(self)
+            }
+
             fn _fallback(&self) -> ! {
                 diamond::InternalImpl::_fallback(self)
             }
@@ -2680,36 +4122,177 @@ pub(crate) fn impl_diamond(impl_args: &mut ImplArgs) {
     ))
     .expect("Should parse");
 
-    let mut cut = syn::parse2::<syn::ItemImpl>(quote!(
-        impl diamond::DiamondCut for #storage_struct_name {
-            fn _on_add_facet(&mut self, _code_hash: Hash) {}
+    let mut cut = syn::parse2::<syn::ItemImpl>(quote!(
+        impl diamond::DiamondCut for #storage_struct_name {
+            fn _on_add_facet(&mut self, _code_hash: Hash) {}
+
+            fn _on_remove_facet(&mut self, _code_hash: Hash) {}
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::diamond::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("Diamond", import);
+    impl_args.vec_import();
+
+    override_functions("DiamondCut", &mut cut, impl_args.map);
+    override_functions("diamond::Internal", &mut internal, impl_args.map);
+    override_functions("Diamond", &mut diamond, impl_args.map);
+
+    // only insert this if it is not present
+    impl_args
+        .overriden_traits
+        .entry("diamond::DiamondCut")
+        .or_insert(syn::Item::Impl(cut));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(diamond_impl));
+    impl_args.items.push(syn::Item::Impl(diamond));
+}
+
+pub(crate) fn impl_diamond_facet(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl diamond_facet::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let facet_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl DiamondFacetImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut facet = syn::parse2::<syn::ItemImpl>(quote!(
+        impl DiamondFacet for #storage_struct_name {
+            #[ink(message)]
+            fn facet_info(&self) -> FacetInfo {
+                DiamondFacetImpl::facet_info(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::diamond::extensions::diamond_facet::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("DiamondFacet", import);
+    impl_args.vec_import();
+
+    override_functions("DiamondFacet", &mut facet, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(facet_impl));
+    impl_args.items.push(syn::Item::Impl(facet));
+}
+
+/// Generates `diamond_facet::Internal` with the selectors of all messages of the contract
+/// and the storage keys of all its `#[storage_field]`s, so it must be called after all other
+/// implementations are generated.
+pub(crate) fn publish_facet_selectors(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let mut selectors = Vec::new();
+
+    for item in impl_args.items.iter().chain(impl_args.overriden_traits.values()) {
+        if let syn::Item::Impl(implementation) = item {
+            let trait_name = implementation
+                .trait_
+                .as_ref()
+                .map(|(_, path, _)| path.segments.last().expect("Trait path is not empty").ident.to_string());
+
+            for impl_item in implementation.items.iter() {
+                if let syn::ImplItem::Method(method) = impl_item {
+                    if let Some(selector) = message_selector(trait_name.as_deref(), method) {
+                        selectors.push(quote!([ #( #selector ),* ]));
+                    }
+                }
+            }
+        }
+    }
+
+    let storage_field_types = impl_args
+        .items
+        .iter()
+        .find_map(|item| {
+            match item {
+                syn::Item::Struct(item_struct) if item_struct.ident == storage_struct_name => Some(item_struct),
+                _ => None,
+            }
+        })
+        .map(|item_struct| {
+            item_struct
+                .fields
+                .iter()
+                .filter(|field| field.attrs.iter().any(|attr| attr.path.is_ident("storage_field")))
+                .map(|field| field.ty.clone())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl diamond_facet::Internal for #storage_struct_name {
+            fn _facet_selectors(&self) -> Vec<Selector> {
+                ::ink::prelude::vec![ #( #selectors ),* ]
+            }
 
-            fn _on_remove_facet(&mut self, _code_hash: Hash) {}
+            fn _facet_storage_keys(&self) -> Vec<u32> {
+                let mut storage_keys = Vec::new();
+                #( storage_keys.extend_from_slice(<#storage_field_types as ::openbrush::traits::StorageKeys>::STORAGE_KEYS); )*
+                storage_keys
+            }
         }
     ))
     .expect("Should parse");
 
-    let import = syn::parse2::<syn::ItemUse>(quote!(
-        use openbrush::contracts::diamond::*;
-    ))
-    .expect("Should parse");
-    impl_args.imports.insert("Diamond", import);
-    impl_args.vec_import();
+    override_functions("diamond_facet::Internal", &mut internal, impl_args.map);
 
-    override_functions("DiamondCut", &mut cut, impl_args.map);
-    override_functions("diamond::Internal", &mut internal, impl_args.map);
-    override_functions("Diamond", &mut diamond, impl_args.map);
+    impl_args.items.push(syn::Item::Impl(internal));
+}
 
-    // only insert this if it is not present
-    impl_args
-        .overriden_traits
-        .entry("diamond::DiamondCut")
-        .or_insert(syn::Item::Impl(cut));
+/// Returns the selector bytes of the ink! message, or `None` if the method is not a message
+/// or has a wildcard selector.
+///
+/// The selector is computed the same way as ink! does: from the explicit `selector` argument,
+/// otherwise from `Trait::message` for trait implementations and from `message` for inherent ones.
+fn message_selector(trait_name: Option<&str>, method: &syn::ImplItemMethod) -> Option<[u8; 4]> {
+    let mut is_message = false;
+    let mut explicit_selector = None;
+
+    for attr in method.attrs.iter().filter(|attr| attr.path.is_ident("ink")) {
+        let nested = attr
+            .parse_args_with(syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated)
+            .ok()?;
+
+        for meta in nested.iter() {
+            match meta {
+                syn::Meta::Path(path) if path.is_ident("message") => is_message = true,
+                syn::Meta::NameValue(name_value) if name_value.path.is_ident("selector") => {
+                    match &name_value.lit {
+                        syn::Lit::Int(int) => explicit_selector = Some(int.base10_parse::<u32>().ok()?.to_be_bytes()),
+                        _ => return None,
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
 
-    impl_args.items.push(syn::Item::Impl(internal_impl));
-    impl_args.items.push(syn::Item::Impl(internal));
-    impl_args.items.push(syn::Item::Impl(diamond_impl));
-    impl_args.items.push(syn::Item::Impl(diamond));
+    if !is_message {
+        return None
+    }
+
+    explicit_selector.or_else(|| {
+        let ident = method.sig.ident.to_string();
+        let selector_string = match trait_name {
+            Some(trait_name) => format!("{trait_name}::{ident}"),
+            None => ident,
+        };
+        Some(::ink_ir::Selector::compute(&selector_string.into_bytes()).to_bytes())
+    })
 }
 
 pub(crate) fn impl_diamond_loupe(impl_args: &mut ImplArgs) {
@@ -2832,6 +4415,19 @@ pub(crate) fn impl_psp61(impl_args: &mut ImplArgs, impls: Vec<String>) {
     ))
     .expect("Should parse");
 
+    // interfaces registered by the diamond are stored, so they are added at runtime
+    let diamond_interfaces = if impls.iter().any(|args| args == "Diamond") {
+        quote!(
+            for interface_id in diamond::Internal::_supported_interfaces(self) {
+                if !interfaces.contains(&interface_id) {
+                    interfaces.push(interface_id);
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
+
     let traits_implemented: Vec<_> = impls
         .into_iter()
         .map(|args| {
@@ -2855,9 +4451,12 @@ pub(crate) fn impl_psp61(impl_args: &mut ImplArgs, impls: Vec<String>) {
     let psp61_internal_ob = syn::parse2::<syn::ItemImpl>(quote!(
         impl PSP61InternalOB for #storage_struct_name {
             fn _interfaces_ob(&self) -> ::ink::prelude::vec::Vec<u32> {
-                ::ink::prelude::vec![
+                #[allow(unused_mut)]
+                let mut interfaces = ::ink::prelude::vec![
                     #(#traits_implemented),*
-                ]
+                ];
+                #diamond_interfaces
+                interfaces
             }
         }
     ))
@@ -2877,8 +4476,14 @@ pub(crate) fn impl_psp61(impl_args: &mut ImplArgs, impls: Vec<String>) {
 
 pub(crate) fn impl_governor_settings(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let governor_settings_events = syn::parse2::<syn::ItemImpl>(quote!(
-        impl GovernorSettingsEvents for #storage_struct_name {}
+    let mut governor_settings_events = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorSettingsEvents for #storage_struct_name {
+            fn emit_voting_delay_set(&self, _old_voting_delay: Option<u64>, _new_voting_delay: u64) {}
+
+            fn emit_voting_period_set(&self, _old_voting_period: Option<u64>, _new_voting_period: u64) {}
+
+            fn emit_proposal_threshold_set(&self, _old_proposal_threshold: Option<u128>, _new_proposal_threshold: u128) {}
+        }
     ))
     .expect("Should parse");
     let governor_settings_internal = syn::parse2::<syn::ItemImpl>(quote!(
@@ -2930,6 +4535,8 @@ pub(crate) fn impl_governor_settings(impl_args: &mut ImplArgs) {
     .expect("Should parse");
     impl_args.imports.insert("GovernorSettings", import);
 
+    override_functions("GovernorSettingsEvents", &mut governor_settings_events, impl_args.map);
+
     impl_args.items.push(syn::Item::Impl(governor_settings_events));
     impl_args.items.push(syn::Item::Impl(governor_settings_internal));
     impl_args.items.push(syn::Item::Impl(governor_settings_impl));
@@ -2954,8 +4561,12 @@ pub(crate) fn impl_governor_votes(impl_args: &mut ImplArgs) {
 
 pub(crate) fn impl_governor_quorum(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let quorum_events = syn::parse2::<syn::ItemImpl>(quote!(
-        impl QuorumEvents for #storage_struct_name {}
+    let mut quorum_events = syn::parse2::<syn::ItemImpl>(quote!(
+        impl QuorumEvents for #storage_struct_name {
+            fn emit_quorum_numerator_updated(&self, _old_quorum_numerator: u128, _new_quorum_numerator: u128) {}
+
+            fn emit_governor_invalid_quorum_fraction(&self, _quorum_numerator: u128, _quorum_denominator: u128) {}
+        }
     ))
     .expect("Should parse");
 
@@ -3000,6 +4611,7 @@ pub(crate) fn impl_governor_quorum(impl_args: &mut ImplArgs) {
     .expect("Should parse");
     impl_args.imports.insert("GovernorQuorum", import);
 
+    override_functions("QuorumEvents", &mut quorum_events, impl_args.map);
     override_functions("Quorum", &mut quorum, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(quorum_events));
@@ -3045,6 +4657,295 @@ pub(crate) fn impl_governor_counting(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(counting_internal));
     impl_args.items.push(syn::Item::Impl(governor_counting));
 }
+
+pub(crate) fn impl_governor_counting_fractional(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+
+    let governor_counting_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorCountingImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let counting_fractional_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl CountingFractionalInternal for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let counting_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl CountingInternal for #storage_struct_name {
+            fn _count_vote(
+                &mut self,
+                proposal_id: ProposalId,
+                account: AccountId,
+                support: VoteType,
+                weight: Balance,
+                params: Vec<u8>,
+            ) -> Result<(), GovernanceError> {
+                CountingFractionalInternal::_count_vote_fractional(self, proposal_id, account, support, weight, params)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let governor_counting_fractional_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorCountingFractionalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let governor_counting = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorCounting for #storage_struct_name {
+            #[ink(message)]
+            fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+                GovernorCountingImpl::has_voted(self, proposal_id, account)
+            }
+
+            #[ink(message)]
+            fn proposal_votes(&self, proposal_id: ProposalId) -> Result<ProposalVote, GovernanceError> {
+                GovernorCountingImpl::proposal_votes(self, proposal_id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let governor_counting_fractional = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorCountingFractional for #storage_struct_name {
+            #[ink(message)]
+            fn used_votes(&self, proposal_id: ProposalId, account: AccountId) -> Balance {
+                GovernorCountingFractionalImpl::used_votes(self, proposal_id, account)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::governance::extensions::governor_counting::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("GovernorCounting", import);
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::governance::extensions::governor_counting_fractional::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("GovernorCountingFractional", import);
+    impl_args.vec_import();
+
+    impl_args.items.push(syn::Item::Impl(governor_counting_impl));
+    impl_args.items.push(syn::Item::Impl(counting_fractional_internal));
+    impl_args.items.push(syn::Item::Impl(counting_internal));
+    impl_args.items.push(syn::Item::Impl(governor_counting_fractional_impl));
+    impl_args.items.push(syn::Item::Impl(governor_counting));
+    impl_args.items.push(syn::Item::Impl(governor_counting_fractional));
+}
+
+pub(crate) fn impl_governor_timelock(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+
+    let mut governor_timelock_events = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorTimelockEvents for #storage_struct_name {
+            fn emit_proposal_queued(&self, _proposal_id: ProposalId, _eta: Timestamp) {}
+
+            fn emit_timelock_change(&self, _old_timelock: Option<AccountId>, _new_timelock: AccountId) {}
+        }
+    ))
+    .expect("Should parse");
+
+    let governor_timelock_internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorTimelockInternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut governor_timelock_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorTimelockInternal for #storage_struct_name {
+            fn _init_governor_timelock(&mut self, timelock: AccountId) -> Result<(), GovernanceError> {
+                GovernorTimelockInternalImpl::_init_governor_timelock(self, timelock)
+            }
+
+            fn _update_timelock(&mut self, new_timelock: AccountId) -> Result<(), GovernanceError> {
+                GovernorTimelockInternalImpl::_update_timelock(self, new_timelock)
+            }
+
+            fn _timelock(&self) -> Option<AccountId> {
+                GovernorTimelockInternalImpl::_timelock(self)
+            }
+
+            fn _timelock_id(&self, proposal_id: ProposalId) -> Option<OperationId> {
+                GovernorTimelockInternalImpl::_timelock_id(self, proposal_id)
+            }
+
+            fn _timelock_salt(&self, description_hash: HashType) -> [u8; 32] {
+                GovernorTimelockInternalImpl::_timelock_salt(self, description_hash)
+            }
+
+            fn _timelock_eta(&self, proposal_id: ProposalId) -> Timestamp {
+                GovernorTimelockInternalImpl::_timelock_eta(self, proposal_id)
+            }
+
+            fn _queue_operations(
+                &mut self,
+                proposal_id: ProposalId,
+                transactions: Vec<Transaction>,
+                description_hash: HashType,
+            ) -> Result<Timestamp, GovernanceError> {
+                GovernorTimelockInternalImpl::_queue_operations(self, proposal_id, transactions, description_hash)
+            }
+
+            fn _execute_operations(
+                &mut self,
+                transactions: Vec<Transaction>,
+                description_hash: HashType,
+            ) -> Result<(), GovernanceError> {
+                GovernorTimelockInternalImpl::_execute_operations(self, transactions, description_hash)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let governor_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorInternal for #storage_struct_name {
+            fn _execute(&mut self, transactions: Vec<Transaction>, description_hash: HashType) -> Result<(), GovernanceError> {
+                GovernorTimelockInternal::_execute_operations(self, transactions, description_hash)
+            }
+
+            fn _proposal_eta(&self, proposal_id: ProposalId) -> Timestamp {
+                GovernorTimelockInternal::_timelock_eta(self, proposal_id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let governor_timelock_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorTimelockImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut governor_timelock = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorTimelock for #storage_struct_name {
+            #[ink(message)]
+            fn timelock(&self) -> Option<AccountId> {
+                GovernorTimelockImpl::timelock(self)
+            }
+
+            #[ink(message)]
+            fn proposal_eta(&self, proposal_id: ProposalId) -> Timestamp {
+                GovernorTimelockImpl::proposal_eta(self, proposal_id)
+            }
+
+            #[ink(message)]
+            fn queue(
+                &mut self,
+                transactions: Vec<Transaction>,
+                description_hash: HashType,
+            ) -> Result<ProposalId, GovernanceError> {
+                GovernorTimelockImpl::queue(self, transactions, description_hash)
+            }
+
+            #[ink(message)]
+            fn update_timelock(&mut self, new_timelock: AccountId) -> Result<(), GovernanceError> {
+                GovernorTimelockImpl::update_timelock(self, new_timelock)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::governance::extensions::governor_timelock::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("GovernorTimelock", import);
+    impl_args.vec_import();
+
+    override_functions("GovernorTimelockEvents", &mut governor_timelock_events, impl_args.map);
+    override_functions(
+        "GovernorTimelockInternal",
+        &mut governor_timelock_internal,
+        impl_args.map,
+    );
+    override_functions("GovernorTimelock", &mut governor_timelock, impl_args.map);
+
+    extend_overriden_trait(impl_args, "GovernorInternal", governor_internal);
+
+    impl_args.items.push(syn::Item::Impl(governor_timelock_events));
+    impl_args.items.push(syn::Item::Impl(governor_timelock_internal_impl));
+    impl_args.items.push(syn::Item::Impl(governor_timelock_internal));
+    impl_args.items.push(syn::Item::Impl(governor_timelock_impl));
+    impl_args.items.push(syn::Item::Impl(governor_timelock));
+}
+
+pub(crate) fn impl_governor_prevent_late_quorum(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+
+    let mut prevent_late_quorum_events = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorPreventLateQuorumEvents for #storage_struct_name {
+            fn emit_late_quorum_vote_extension_set(
+                &self,
+                _old_vote_extension: Option<Timestamp>,
+                _new_vote_extension: Timestamp,
+            ) {
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let prevent_late_quorum_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorPreventLateQuorumInternal for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let governor_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorInternal for #storage_struct_name {
+            fn _proposal_deadline(&self, proposal_id: ProposalId) -> Result<Timestamp, GovernanceError> {
+                GovernorPreventLateQuorumInternal::_extended_proposal_deadline(self, proposal_id)
+            }
+
+            fn _after_cast_vote(&mut self, proposal_id: ProposalId) -> Result<(), GovernanceError> {
+                GovernorPreventLateQuorumInternal::_extend_deadline_on_quorum(self, proposal_id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let prevent_late_quorum_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorPreventLateQuorumImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut prevent_late_quorum = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorPreventLateQuorum for #storage_struct_name {
+            #[ink(message)]
+            fn late_quorum_vote_extension(&self) -> Timestamp {
+                GovernorPreventLateQuorumImpl::late_quorum_vote_extension(self)
+            }
+
+            #[ink(message)]
+            fn set_late_quorum_vote_extension(&mut self, new_vote_extension: Timestamp) -> Result<(), GovernanceError> {
+                GovernorPreventLateQuorumImpl::set_late_quorum_vote_extension(self, new_vote_extension)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::governance::extensions::governor_prevent_late_quorum::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("GovernorPreventLateQuorum", import);
+
+    override_functions(
+        "GovernorPreventLateQuorumEvents",
+        &mut prevent_late_quorum_events,
+        impl_args.map,
+    );
+    override_functions("GovernorPreventLateQuorum", &mut prevent_late_quorum, impl_args.map);
+
+    extend_overriden_trait(impl_args, "GovernorInternal", governor_internal);
+
+    impl_args.items.push(syn::Item::Impl(prevent_late_quorum_events));
+    impl_args.items.push(syn::Item::Impl(prevent_late_quorum_internal));
+    impl_args.items.push(syn::Item::Impl(prevent_late_quorum_impl));
+    impl_args.items.push(syn::Item::Impl(prevent_late_quorum));
+}
+
 pub(crate) fn impl_governor(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
 
@@ -3058,8 +4959,46 @@ pub(crate) fn impl_governor(impl_args: &mut ImplArgs) {
     ))
     .expect("Should parse");
 
-    let governor_events = syn::parse2::<syn::ItemImpl>(quote!(
-        impl GovernorEvents for #storage_struct_name {}
+    let mut governor_events = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorEvents for #storage_struct_name {
+            fn emit_proposal_created(
+                &self,
+                _proposal_id: ProposalId,
+                _proposer: AccountId,
+                _transactions: Vec<Transaction>,
+                _vote_start: Timestamp,
+                _vote_end: Timestamp,
+                _description: String,
+            ) {
+            }
+
+            fn emit_proposal_canceled(&self, _proposal_id: ProposalId) {}
+
+            fn emit_proposal_executed(&self, _proposal_id: ProposalId) {}
+
+            fn emit_vote_cast(
+                &self,
+                _proposal_id: ProposalId,
+                _voter: AccountId,
+                _support: VoteType,
+                _weight: Balance,
+                _reason: String,
+            ) {
+            }
+
+            fn emit_vote_cast_with_params(
+                &self,
+                _proposal_id: ProposalId,
+                _voter: AccountId,
+                _support: VoteType,
+                _weight: Balance,
+                _reason: String,
+                _params: Vec<u8>,
+            ) {
+            }
+
+            fn emit_proposal_extended(&self, _proposal_id: ProposalId, _extended_deadline: Timestamp) {}
+        }
     ))
     .expect("Should parse");
 
@@ -3178,8 +5117,15 @@ pub(crate) fn impl_governor(impl_args: &mut ImplArgs) {
     .expect("Should parse");
     impl_args.imports.insert("Governor", import);
 
+    override_functions("GovernorEvents", &mut governor_events, impl_args.map);
+
+    // only insert this if it is not present
+    impl_args
+        .overriden_traits
+        .entry("GovernorInternal")
+        .or_insert(syn::Item::Impl(governor_internal));
+
     impl_args.items.push(syn::Item::Impl(governor_storage_getters));
-    impl_args.items.push(syn::Item::Impl(governor_internal));
     impl_args.items.push(syn::Item::Impl(governor_events));
     impl_args.items.push(syn::Item::Impl(governor_impl));
     impl_args.items.push(syn::Item::Impl(governor));
@@ -3212,6 +5158,77 @@ pub(crate) fn impl_nonces(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(nonces_impl));
     impl_args.items.push(syn::Item::Impl(nonces));
 }
+// several extensions may override different functions of the same trait, so we merge them into one implementation
+fn extend_overriden_trait(impl_args: &mut ImplArgs, trait_name: &'static str, mut implementation: syn::ItemImpl) {
+    match impl_args.overriden_traits.get_mut(trait_name) {
+        Some(syn::Item::Impl(overriden)) => overriden.items.append(&mut implementation.items),
+        _ => {
+            impl_args
+                .overriden_traits
+                .insert(trait_name, syn::Item::Impl(implementation));
+        }
+    }
+}
+
+/// Implements the traits registered by an external crate via `#[openbrush::implementation_definition]`.
+/// Registered impl blocks are composed the same way as the default ones: the functions can be
+/// overridden with `#[overrider]` and `#[default_impl]`, and implementations of the same trait with
+/// ink! messages are generated only once.
+pub(crate) fn impl_registered(impl_args: &mut ImplArgs, name: &str) {
+    let implementation_lock = LockedImplementation::new(name.to_string());
+    let definition = implementation_lock
+        .implementation_definition
+        .as_ref()
+        .unwrap_or_else(|| panic!("openbrush::implementation({name}) not implemented!"));
+    let storage_struct_name = impl_args.contract_name();
+
+    for item in definition.items() {
+        match item {
+            syn::Item::Use(import) => {
+                // keys of the registered items are not known at compile time of the macro,
+                // so we leak them to use together with the keys of the default implementations
+                let key: &'static str = Box::leak(import.to_token_stream().to_string().into_boxed_str());
+                impl_args.imports.insert(key, import);
+            }
+            syn::Item::Type(data) => {
+                let data_ty = data.ty;
+                let assertion = syn::parse2::<syn::Item>(quote!(
+                    const _: fn() = || {
+                        fn assert_storage<T: ::openbrush::traits::Storage<#data_ty>>() {}
+                        assert_storage::<#storage_struct_name>();
+                    };
+                ))
+                .expect("Should parse");
+                impl_args.items.push(assertion);
+            }
+            syn::Item::Impl(mut implementation) => {
+                implementation.self_ty = Box::new(syn::parse_quote!(#storage_struct_name));
+                let trait_name = implementation
+                    .trait_
+                    .as_ref()
+                    .map(|(_, path, _)| path.to_token_stream().to_string().replace(' ', ""))
+                    .expect("Implementation definition contains only implementations of traits");
+
+                override_functions(&trait_name, &mut implementation, impl_args.map);
+
+                let has_messages = implementation.items.iter().any(|impl_item| {
+                    matches!(impl_item, syn::ImplItem::Method(method) if method.attrs.iter().any(|attr| attr.path.is_ident("ink")))
+                });
+                if has_messages {
+                    let key: &'static str = Box::leak(trait_name.into_boxed_str());
+                    impl_args
+                        .overriden_traits
+                        .entry(key)
+                        .or_insert(syn::Item::Impl(implementation));
+                } else {
+                    impl_args.items.push(syn::Item::Impl(implementation));
+                }
+            }
+            _ => unreachable!("Implementation definition is validated during registration"),
+        }
+    }
+}
+
 fn override_functions(trait_name: &str, implementation: &mut syn::ItemImpl, map: &OverridenFnMap) {
     if let Some(overrides) = map.get(trait_name) {
         // we will find which fns we wanna override
@@ -306,6 +306,26 @@ pub(crate) fn new_attribute(attr_stream: TokenStream) -> syn::Attribute {
     syn::parse2::<Attributes>(attr_stream).unwrap().attr()[0].clone()
 }
 
+/// Returns the environment specified by the `env = MyEnv` argument of the macro, if any.
+pub(crate) fn extract_env(attrs: TokenStream) -> Option<syn::Path> {
+    let args = syn::parse::Parser::parse2(
+        syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated,
+        attrs,
+    )
+    .expect("Can't parse arguments of the macro");
+
+    args.into_iter().find_map(|arg| {
+        if let syn::Expr::Assign(assign) = arg {
+            match (*assign.left, *assign.right) {
+                (syn::Expr::Path(left), syn::Expr::Path(right)) if left.path.is_ident("env") => Some(right.path),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    })
+}
+
 pub(crate) const INK_PREFIX: &str = "ink=";
 
 #[inline]
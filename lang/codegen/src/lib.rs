@@ -21,7 +21,10 @@
 
 pub mod accessors;
 pub mod contract;
+mod default_events;
+pub mod error_derive;
 pub mod implementation;
+pub mod implementation_definition;
 mod implementations;
 pub mod internal;
 pub mod metadata;
@@ -39,11 +39,13 @@ use std::{
     str::FromStr,
 };
 use syn::{
+    ItemMod,
     ItemTrait,
     TraitItem,
 };
 
 const TEMP_FOLDER: &str = "__openbrush_metadata_folder";
+const IMPLEMENTATION_PREFIX: &str = "__openbrush_implementation_";
 
 pub(crate) struct TraitDefinition(ItemTrait);
 
@@ -105,6 +107,53 @@ impl Drop for LockedTrait {
     }
 }
 
+/// Module registered via `#[openbrush::implementation_definition]`. It contains imports,
+/// required `Storage` data and default impl blocks of the implementation.
+pub(crate) struct ImplementationDefinition(ItemMod);
+
+impl ImplementationDefinition {
+    pub(crate) fn new(item: ItemMod) -> Self {
+        Self(item)
+    }
+
+    pub(crate) fn items(&self) -> Vec<syn::Item> {
+        self.0.content.clone().map(|(_, items)| items).unwrap_or_default()
+    }
+}
+
+pub(crate) struct LockedImplementation {
+    file: File,
+    pub(crate) implementation_definition: Option<ImplementationDefinition>,
+}
+
+impl LockedImplementation {
+    pub(crate) fn new(implementation_name: String) -> Self {
+        let file = get_locked_file(format!("{}{}", IMPLEMENTATION_PREFIX, implementation_name));
+        let reader = BufReader::new(&file);
+        let token_string: String = serde_json::from_reader(reader).unwrap_or_default();
+
+        let stream = TokenStream::from_str(token_string.as_str()).unwrap_or_default();
+        let implementation_definition = syn::parse2::<ItemMod>(stream).ok().map(ImplementationDefinition::new);
+
+        Self {
+            file,
+            implementation_definition,
+        }
+    }
+}
+
+impl Drop for LockedImplementation {
+    fn drop(&mut self) {
+        self.file.set_len(0).expect("Can't truncate the file");
+        self.file.seek(SeekFrom::Start(0)).expect("Can't set cursor position");
+        if let Some(implementation_definition) = &self.implementation_definition {
+            serde_json::to_writer(&self.file, &implementation_definition.0.to_token_stream().to_string())
+                .expect("Can't dump definition metadata to file");
+        }
+        self.file.unlock().expect("Can't remove exclusive lock");
+    }
+}
+
 /// Function returns exclusively locked file for metadata.
 /// It stores file in the target folder where `ink` is stored.
 fn get_locked_file(name: String) -> File {
@@ -37,7 +37,22 @@ use syn::{
     Fields,
 };
 
-fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>, Vec<Option<TokenStream>>) {
+/// Returns the position of the generic argument after which the storage key of the mapping is placed,
+/// or `None` if `ident` is not a mapping.
+fn mapping_key_position(ident: &syn::Ident) -> Option<usize> {
+    if ident == "Mapping" || ident == "MultiMapping" || ident == "IterableMapping" {
+        Some(1)
+    } else if ident == "CheckpointsMapping" {
+        Some(0)
+    } else {
+        None
+    }
+}
+
+fn wrap_upgradeable_fields(
+    structure_name: &str,
+    fields: Fields,
+) -> (Vec<Field>, Vec<Option<(syn::Ident, TokenStream)>>) {
     fields
         .iter()
         .map(|field| {
@@ -67,7 +82,7 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
                     pub const #key_name: u32 = ::openbrush::storage_unique_key!(#structure_name, #field_name);
                 };
 
-                (new_field, Some(storage_key))
+                (new_field, Some((key_name, storage_key)))
             } else {
                 let mut new_field = field.clone();
                 let span = field.ty.span();
@@ -81,7 +96,7 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
 
                 let is_mapping = if let syn::Type::Path(path) = &field.ty {
                     if let Some(segment) = path.path.segments.last() {
-                        segment.ident == "Mapping" || segment.ident == "MultiMapping"
+                        mapping_key_position(&segment.ident).is_some()
                     } else {
                         false
                     }
@@ -91,10 +106,10 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
 
                 if let syn::Type::Path(path) = &mut new_field.ty {
                     if let Some(segment) = path.path.segments.last_mut() {
-                        if segment.ident == "Mapping" || segment.ident == "MultiMapping" {
+                        if let Some(position) = mapping_key_position(&segment.ident) {
                             let mut args = segment.arguments.clone();
                             if let syn::PathArguments::AngleBracketed(args) = &mut args {
-                                if let Some(syn::GenericArgument::Type(ty)) = args.args.iter_mut().nth(1) {
+                                if let Some(syn::GenericArgument::Type(ty)) = args.args.iter_mut().nth(position) {
                                     *ty = syn::Type::Verbatim(quote_spanned!(span =>
                                         #ty, ::ink::storage::traits::ManualKey<#key_name>
                                     ));
@@ -106,9 +121,10 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
                 }
 
                 let storage_key = if is_mapping {
-                    Some(quote! {
+                    let storage_key = quote! {
                         pub const #key_name: u32 = ::openbrush::storage_unique_key!(#structure_name, #field_name);
-                    })
+                    };
+                    Some((key_name, storage_key))
                 } else {
                     None
                 };
@@ -119,6 +135,17 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
         .unzip()
 }
 
+fn impl_storage_keys(s: &synstructure::Structure, key_names: &[syn::Ident]) -> TokenStream {
+    let ident = &s.ast().ident;
+    let (impl_generics, ty_generics, where_clause) = s.ast().generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::openbrush::traits::StorageKeys for #ident #ty_generics #where_clause {
+            const STORAGE_KEYS: &'static [u32] = &[ #( #key_names ),* ];
+        }
+    }
+}
+
 fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> TokenStream {
     let struct_ident = s.ast().ident.clone();
     let vis = s.ast().vis.clone();
@@ -127,6 +154,8 @@ fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> Toke
     let (_, _, where_closure) = s.ast().generics.split_for_impl();
 
     let (fields, storage_keys) = wrap_upgradeable_fields(struct_ident.to_string().as_str(), struct_item.fields.clone());
+    let (key_names, storage_keys): (Vec<_>, Vec<_>) = storage_keys.into_iter().flatten().unzip();
+    let storage_keys_impl = impl_storage_keys(s, &key_names);
 
     match struct_item.fields {
         Fields::Unnamed(_) => {
@@ -137,6 +166,8 @@ fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> Toke
                 );
 
                 #(#storage_keys)*
+
+                #storage_keys_impl
             }
         }
         _ => {
@@ -147,6 +178,8 @@ fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> Toke
                 }
 
                 #(#storage_keys)*
+
+                #storage_keys_impl
             }
         }
     }
@@ -158,7 +191,7 @@ fn generate_enum(s: &synstructure::Structure, enum_item: DataEnum) -> TokenStrea
     let attrs = s.ast().attrs.clone();
     let types = s.ast().generics.clone();
     let (_, _, where_closure) = s.ast().generics.split_for_impl();
-    let mut all_storage_keys: Vec<Option<TokenStream>> = vec![];
+    let mut all_storage_keys: Vec<(syn::Ident, TokenStream)> = vec![];
 
     let variants = enum_item.variants.into_iter().map(|variant| {
         let attrs = variant.attrs;
@@ -180,13 +213,18 @@ fn generate_enum(s: &synstructure::Structure, enum_item: DataEnum) -> TokenStrea
             Fields::Unit => quote! {},
         };
 
-        all_storage_keys.extend(storage_keys);
+        all_storage_keys.extend(storage_keys.into_iter().flatten());
 
         quote! {
             #(#attrs)*
             #variant_ident #fields #discriminant,
         }
     });
+    // the storage keys are collected while the variants are generated
+    let variants = variants.collect::<Vec<_>>();
+
+    let (key_names, all_storage_keys): (Vec<_>, Vec<_>) = all_storage_keys.into_iter().unzip();
+    let storage_keys_impl = impl_storage_keys(s, &key_names);
 
     quote! {
         #(#attrs)*
@@ -195,6 +233,8 @@ fn generate_enum(s: &synstructure::Structure, enum_item: DataEnum) -> TokenStrea
         }
 
         #(#all_storage_keys)*
+
+        #storage_keys_impl
     }
 }
 
@@ -186,6 +186,7 @@ fn generate_wrapper(ink_trait: ItemTrait) -> proc_macro2::TokenStream {
         .for_each(|method| {
             let message_ident = method.sig.ident.clone();
             let message_builder_ident = format_ident!("{}_builder", method.sig.ident);
+            let try_message_ident = format_ident!("try_{}", method.sig.ident);
             let output_ty = match method.sig.output.clone() {
                 syn::ReturnType::Default => quote! { () },
                 syn::ReturnType::Type(_, return_type) => quote! { #return_type },
@@ -239,12 +240,20 @@ fn generate_wrapper(ink_trait: ItemTrait) -> proc_macro2::TokenStream {
                     #( , #input_bindings : #input_types )*
                 ) -> #output_ty;
 
+                fn #try_message_ident(
+                    & self
+                    #( , #input_bindings : #input_types )*
+                ) -> ::core::result::Result<
+                    ::core::result::Result<#output_ty, ::ink::LangError>,
+                    ::ink::env::Error,
+                >;
+
                 fn #message_builder_ident(
                     & self
                     #( , #input_bindings : #input_types )*
                 ) -> ::ink::env::call::CallBuilder<
-                    ::ink::env::DefaultEnvironment,
-                    ::ink::env::call::utils::Set< ::ink::env::call::Call< ::ink::env::DefaultEnvironment > >,
+                    E,
+                    ::ink::env::call::utils::Set< ::ink::env::call::Call< E > >,
                     ::ink::env::call::utils::Set< ::ink::env::call::ExecutionInput<#arg_list> >,
                     ::ink::env::call::utils::Set<::ink::env::call::utils::ReturnType<#output_ty>>,
                 >;
@@ -256,23 +265,34 @@ fn generate_wrapper(ink_trait: ItemTrait) -> proc_macro2::TokenStream {
                     & self
                     #( , #input_bindings : #input_types )*
                 ) -> #output_ty {
-                    Self::#message_builder_ident(self #( , #input_bindings)*)
+                    <Self as #trait_wrapper_ident<E>>::#message_builder_ident(self #( , #input_bindings)*)
                         .try_invoke()
                         .unwrap_or_else(|err| ::core::panic!("{}: {:?}", #panic_str, err))
                         .unwrap_or_else(|err| ::core::panic!("Can't decode ::ink::LangErr: {:?}", err))
                 }
 
+                #[inline]
+                fn #try_message_ident(
+                    & self
+                    #( , #input_bindings : #input_types )*
+                ) -> ::core::result::Result<
+                    ::core::result::Result<#output_ty, ::ink::LangError>,
+                    ::ink::env::Error,
+                > {
+                    <Self as #trait_wrapper_ident<E>>::#message_builder_ident(self #( , #input_bindings)*).try_invoke()
+                }
+
                 #[inline]
                 fn #message_builder_ident(
                     & self
                     #( , #input_bindings : #input_types )*
                 ) -> ::ink::env::call::CallBuilder<
-                    ::ink::env::DefaultEnvironment,
-                    ::ink::env::call::utils::Set< ::ink::env::call::Call< ::ink::env::DefaultEnvironment > >,
+                    E,
+                    ::ink::env::call::utils::Set< ::ink::env::call::Call< E > >,
                     ::ink::env::call::utils::Set< ::ink::env::call::ExecutionInput<#arg_list> >,
                     ::ink::env::call::utils::Set<::ink::env::call::utils::ReturnType<#output_ty>>,
                 > {
-                    ::ink::env::call::build_call::<::ink::env::DefaultEnvironment>()
+                    ::ink::env::call::build_call::<E>()
                         .call_type(
                             ::ink::env::call::Call::new(self.clone()))
                         .exec_input(
@@ -298,11 +318,14 @@ fn generate_wrapper(ink_trait: ItemTrait) -> proc_macro2::TokenStream {
     let trait_id = ::ink_ir::Selector::compute(&message_selectors.join("").into_bytes()).into_be_u32();
 
     quote! {
-        pub trait #trait_wrapper_ident {
+        pub trait #trait_wrapper_ident<E: ::ink::env::Environment = ::ink::env::DefaultEnvironment> {
             #( #def_messages )*
         }
 
-        impl #trait_wrapper_ident for ::openbrush::traits::AccountId {
+        impl<E> #trait_wrapper_ident<E> for ::openbrush::traits::AccountId
+        where
+            E: ::ink::env::Environment<AccountId = ::openbrush::traits::AccountId>,
+        {
             #( #impl_messages )*
         }
 
@@ -26,10 +26,11 @@ use quote::{
 };
 use syn::parse2;
 
-pub fn generate(_: TokenStream, input: TokenStream) -> TokenStream {
+pub fn generate(attrs: TokenStream, input: TokenStream) -> TokenStream {
     if crate::internal::skip() {
         return quote! {}
     }
+    let env = crate::internal::extract_env(attrs);
     let mut type_item: syn::ItemType = parse2(input).unwrap();
     if let syn::Type::TraitObject(traits) = &mut *type_item.ty {
         traits.bounds.iter_mut().for_each(|ty| {
@@ -48,21 +49,20 @@ pub fn generate(_: TokenStream, input: TokenStream) -> TokenStream {
 
         let mut union_trait = quote! {};
         if traits.bounds.len() > 1 {
-            let bounds = traits.bounds.clone();
-            let union_ident = traits
+            let bounds = traits
                 .bounds
-                .clone()
                 .iter()
                 .filter_map(|bound| {
-                    if let syn::TypeParamBound::Trait(t) = bound.clone() {
-                        Some(t)
+                    if let syn::TypeParamBound::Trait(t) = bound {
+                        Some(t.path.clone())
                     } else {
                         None
                     }
                 })
-                .fold(format_ident!("Union"), |acc, t| {
-                    format_ident!("{}{}", acc, t.path.segments.last().unwrap().ident)
-                });
+                .collect::<Vec<_>>();
+            let union_ident = bounds.iter().fold(format_ident!("Union"), |acc, path| {
+                format_ident!("{}{}", acc, path.segments.last().unwrap().ident)
+            });
             traits.bounds.clear();
             let union_bound = syn::TraitBound {
                 paren_token: None,
@@ -72,12 +72,24 @@ pub fn generate(_: TokenStream, input: TokenStream) -> TokenStream {
             };
             traits.bounds.push(syn::TypeParamBound::Trait(union_bound));
             union_trait = quote! {
-                pub trait #union_ident : #bounds {}
+                pub trait #union_ident<E: ::ink::env::Environment = ::ink::env::DefaultEnvironment> : #( #bounds<E> )+* {}
 
-                impl #union_ident for ::openbrush::traits::AccountId {}
+                impl<E> #union_ident<E> for ::openbrush::traits::AccountId
+                where
+                    E: ::ink::env::Environment<AccountId = ::openbrush::traits::AccountId>,
+                {}
             }
         }
 
+        if let Some(env) = env {
+            traits.bounds.iter_mut().for_each(|ty| {
+                if let syn::TypeParamBound::Trait(t) = ty {
+                    t.path.segments.last_mut().expect("Trait path is empty").arguments =
+                        syn::PathArguments::AngleBracketed(syn::parse_quote! { <#env> });
+                }
+            });
+        }
+
         quote! {
             #union_trait
 
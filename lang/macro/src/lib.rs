@@ -26,7 +26,9 @@ use proc_macro::TokenStream;
 use openbrush_lang_codegen::{
     accessors,
     contract,
+    error_derive,
     implementation,
+    implementation_definition,
     modifier_definition,
     modifiers,
     storage_derive,
@@ -43,6 +45,14 @@ use openbrush_lang_codegen::{
 /// After consumption, it pastes ink! code and then ink!'s macros will be processed.
 ///
 /// This macro consumes impl section for traits defined with [`#[openbrush::trait_definition]`](`macro@crate::trait_definition`).
+///
+/// The arguments of the macro are passed to `#[ink::contract]`. If the custom environment is specified
+/// via `#[openbrush::contract(env = MyEnvironment)]`, the wrappers defined inside of the contract with
+/// [`#[openbrush::wrapper]`](`macro@crate::wrapper`) use it by default. OpenBrush's implementations
+/// work with `DefaultEnvironment`, so [`#[openbrush::implementation]`](`macro@crate::implementation`)
+/// requires the environment to use the same `AccountId`, `Balance`, `Hash`, `Timestamp` and `BlockNumber`
+/// types (see `openbrush::traits::CompatibleEnvironment`), but the chain extension and other parameters
+/// can be customized.
 #[proc_macro_attribute]
 pub fn contract(_attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     contract::generate(_attrs.into(), ink_module.into()).into()
@@ -359,6 +369,10 @@ pub fn modifiers(_attrs: TokenStream, method: TokenStream) -> TokenStream {
 ///  ** Note ** Crated wrapper is only a type, so you can't create an instance of this object.
 ///  ** Note ** The wrapper contains only ink's methods of the trait, it doesn't include a method of super traits.
 /// If you want to wrap them too, you need to explicitly specify them.
+///  ** Note ** Each message `foo` panics if the call fails. Use `try_foo` to get the `Result` of the call
+/// or `foo_builder` to configure the call before invoking it.
+///  ** Note ** Calls are built for `DefaultEnvironment`. Use `#[openbrush::wrapper(env = MyEnvironment)]`
+/// to build them for a custom environment.
 ///
 /// # Example: Definition
 ///
@@ -399,6 +413,16 @@ pub fn modifiers(_attrs: TokenStream, method: TokenStream) -> TokenStream {
 /// let to: AccountId = [0; 32].into();
 /// let builder_for_foo: ::ink::env::call::CallBuilder<_, _, _, _> = Trait1and2Ref::foo_builder(&to);
 /// let ink_result: Result<bool, ink::LangError> = builder_for_foo.try_invoke().unwrap();
+///
+/// // Example of call which returns an error instead of panic if the callee fails
+/// let result: Result<Result<bool, ink::LangError>, ink::env::Error> = Trait1and2Ref::try_foo(&to);
+///
+/// // Example how to set gas limit, transferred value and call flags of the call
+/// let options = openbrush::traits::CallOptions::new()
+///     .gas_limit(1_000_000)
+///     .transferred_value(10)
+///     .call_flags(ink::env::CallFlags::default().set_allow_reentry(true));
+/// let result = options.apply(Trait1and2Ref::foo_builder(&to)).try_invoke();
 /// }
 /// ```
 #[proc_macro_attribute]
@@ -425,6 +449,27 @@ pub fn storage_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream
     storage_derive::storage_derive(item.into()).into()
 }
 
+/// The macro implements `From` for each variant of the error enum marked by `#[from]` attribute,
+/// so errors of OpenBrush modules (or any other errors) can be absorbed by the user's error type
+/// and propagated with `?`. The variant must have exactly one unnamed field with the absorbed error.
+///
+/// # Example
+/// ```skip
+///     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
+///     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+///     pub enum MyError {
+///         #[from]
+///         Ownable(OwnableError),
+///         #[from]
+///         PSP22(PSP22Error),
+///         Custom(String),
+///     }
+/// ```
+#[proc_macro_derive(ErrorFrom, attributes(from))]
+pub fn error_from_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    error_derive::error_from_derive(item.into()).into()
+}
+
 synstructure::decl_attribute!(
     [accessors] =>
     /// Macro that automatically implements accessors like get/set for struct fields, that implements `scale::Encode`
@@ -480,6 +525,22 @@ synstructure::decl_attribute!(
 /// you want to keep the default implementation from OpenBrush, but you want to attach some modifiers to
 /// that function.
 ///
+/// Implementations registered by external crates via
+/// [`#[openbrush::implementation_definition]`](`macro@crate::implementation_definition`) are supported too.
+///
+/// The macro also adds the canonical `#[ink(event)]` structs of the implemented modules
+/// (`Transfer`, `Approval`, `OwnershipTransferred`, `RoleGranted`, `Paused`, etc.) to the contract
+/// and emits them from the `_emit_*` hooks of the `Internal` traits. An event is not added if the contract
+/// already defines an item with the same name, or if its hook is overridden with `#[overrider]`.
+/// If two implemented modules define events with the same name, the macro emits a compile error.
+/// Pass `no_events` to the macro to disable the canonical events completely:
+/// `#[openbrush::implementation(PSP22, Ownable, no_events)]`.
+///
+/// Pass `guard_calls` together with `ReentrancyGuard` to add the `non_reentrant` modifier to the default
+/// messages which make cross-contract calls (`FlashLender::flashloan`, `TimelockController::execute` and
+/// `TimelockController::execute_batch`): `#[openbrush::implementation(PSP22, Flashmint, ReentrancyGuard, guard_calls)]`.
+/// Messages which already have the `non_reentrant` modifier are not guarded twice.
+///
 /// # Example
 ///
 /// ```skip
@@ -530,6 +591,63 @@ pub fn implementation(attrs: TokenStream, ink_module: TokenStream) -> TokenStrea
     implementation::generate(attrs.into(), ink_module.into()).into()
 }
 
+/// This macro registers the implementation of traits, so it can be used by
+/// [`#[openbrush::implementation]`](`macro@crate::implementation`) like the implementations
+/// defined in OpenBrush. It allows external crates to provide their own modules.
+///
+/// The macro consumes the module and saves its content to the metadata. The module can contain:
+/// - Imports, that are added to the contract.
+/// - `type Name = Data;` items, that describe the storage data required by the implementation.
+/// The contract must implement `Storage<Data>` for each of them.
+/// - Impl blocks of the traits. The type of the impl block is replaced with the contract's storage,
+/// so any type can be used there. Functions of these blocks can be overridden with `#[overrider]`
+/// or `#[default_impl]` attributes.
+///
+/// The crate which registers the implementation must be compiled before the contract which uses it.
+///
+/// # Example
+///
+/// ```skip
+/// #[openbrush::implementation_definition(Counter)]
+/// mod counter_implementation {
+///     use my_crate::counter::*;
+///
+///     type Data = counter::Data;
+///
+///     impl counter::InternalImpl for Contract {}
+///
+///     impl counter::Internal for Contract {
+///         fn _emit_incremented_event(&self, value: u32) {
+///             counter::InternalImpl::_emit_incremented_event(self, value)
+///         }
+///     }
+///
+///     impl CounterImpl for Contract {}
+///
+///     impl Counter for Contract {
+///         #[ink(message)]
+///         fn get(&self) -> u32 {
+///             CounterImpl::get(self)
+///         }
+///     }
+/// }
+///
+/// #[openbrush::implementation(Counter)]
+/// #[openbrush::contract]
+/// pub mod my_contract {
+///     ...
+///
+///     #[overrider(counter::Internal)]
+///     fn _emit_incremented_event(&self, value: u32) {
+///         // here we can change the behavior of the registered implementation
+///     }
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn implementation_definition(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    implementation_definition::generate(attrs.into(), input.into()).into()
+}
+
 synstructure::decl_attribute!(
     [storage_item] =>
     /// The macro implements `ink::storage_item` macro for the struct, which means that it prepares your struct
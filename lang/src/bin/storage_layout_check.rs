@@ -0,0 +1,64 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Checks that the storage layout of the new version of the contract is compatible
+//! with the old one before upgrading the code hash.
+//!
+//! Usage: `storage_layout_check <old metadata.json> <new metadata.json>`
+//!
+//! Exits with `1` if the layouts are incompatible and with `2` if the metadata can't be read.
+
+use openbrush_lang::utils::storage_layout::check_compatibility;
+use std::{
+    env,
+    fs,
+    process,
+};
+
+fn read(path: &str) -> String {
+    fs::read_to_string(path).unwrap_or_else(|err| {
+        eprintln!("can't read {path}: {err}");
+        process::exit(2)
+    })
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 3 {
+        eprintln!("usage: {} <old metadata.json> <new metadata.json>", args[0]);
+        process::exit(2)
+    }
+
+    match check_compatibility(&read(&args[1]), &read(&args[2])) {
+        Ok(incompatibilities) if incompatibilities.is_empty() => println!("storage layouts are compatible"),
+        Ok(incompatibilities) => {
+            for incompatibility in incompatibilities.iter() {
+                println!("{incompatibility}");
+            }
+            process::exit(1)
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            process::exit(2)
+        }
+    }
+}
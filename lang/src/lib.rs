@@ -31,6 +31,7 @@ pub use openbrush_lang_macro::{
     accessors,
     contract,
     implementation,
+    implementation_definition,
     modifier_definition,
     modifiers,
     storage_item,
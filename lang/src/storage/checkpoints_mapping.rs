@@ -0,0 +1,402 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::{
+    storage::RawMapping,
+    utils::checkpoints::{
+        Checkpoint,
+        CheckpointsError,
+    },
+};
+use core::marker::PhantomData;
+use ink::{
+    primitives::Key,
+    storage::traits::{
+        AutoKey,
+        Storable,
+        StorableHint,
+        StorageKey,
+    },
+};
+use scale::{
+    Error,
+    Input,
+    Output,
+};
+
+/// A mapping of the owner to the history of its checkpoints. See `Votes` as an example.
+///
+/// Unlike `Checkpoints`, every checkpoint is stored in its own storage cell under (`owner`, `index`)
+/// and the count of checkpoints is stored separately, so pushing a new checkpoint doesn't require
+/// loading and rewriting the whole history.
+///
+/// If the mapping tracks only one history, use `()` as the owner.
+pub struct CheckpointsMapping<K, KeyType: StorageKey = AutoKey> {
+    #[allow(clippy::type_complexity)]
+    _marker: PhantomData<fn() -> (K, KeyType)>,
+}
+
+impl<K, KeyType> CheckpointsMapping<K, KeyType>
+where
+    KeyType: StorageKey,
+{
+    #[allow(dead_code)]
+    fn new() -> Self {
+        Self {
+            _marker: Default::default(),
+        }
+    }
+
+    /// Contains count of checkpoints by owner.
+    /// length: Mapping<K, u32>,
+    fn length<'a>(&self) -> RawMapping<&'a K, u32, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &0))
+    }
+
+    /// Mapping from owner's index to checkpoint.
+    /// checkpoints: Mapping<(K, u32), Checkpoint>,
+    fn checkpoints<'a>(&self) -> RawMapping<(&'a K, &'a u32), Checkpoint, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &1))
+    }
+}
+
+impl<K, KeyType> Default for CheckpointsMapping<K, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn default() -> Self {
+        Self {
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<K, KeyType> core::fmt::Debug for CheckpointsMapping<K, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("CheckpointsMapping")
+            .field("key", &KeyType::KEY)
+            .finish()
+    }
+}
+
+/// Counting sqrt using Newton's method.
+fn sqrt(x: u32) -> u32 {
+    let mut z = (x + 1) / 2;
+    let mut y = x;
+    while z < y {
+        y = z;
+        z = (x / z + z) / 2;
+    }
+    y
+}
+
+impl<K, KeyType> CheckpointsMapping<K, KeyType>
+where
+    K: scale::Encode,
+    KeyType: StorageKey,
+{
+    /// Pushes a (`key`, `value`) pair into the history of `owner` so that it is stored as the checkpoint.
+    /// Returns previous value and new value.
+    pub fn push(&mut self, owner: &K, key: u64, value: u128) -> Result<(u128, u128), CheckpointsError> {
+        let len = self.len(owner);
+
+        match self.last(owner, len) {
+            Some(last) => {
+                if last.key > key {
+                    return Err(CheckpointsError::UnorderedInsertion)
+                }
+
+                if last.key == key {
+                    self.checkpoints()
+                        .insert((owner, &(len - 1)), &Checkpoint { key, value });
+                } else {
+                    self.checkpoints().insert((owner, &len), &Checkpoint { key, value });
+                    self.length().insert(owner, &(len + 1));
+                }
+                Ok((last.value, value))
+            }
+            None => {
+                self.checkpoints().insert((owner, &0), &Checkpoint { key, value });
+                self.length().insert(owner, &1);
+                Ok((0, value))
+            }
+        }
+    }
+
+    /// Returns the value in the first (oldest) checkpoint of `owner` with key greater or equal than the search key,
+    /// or `None` if there is none.
+    pub fn lower_lookup(&self, owner: &K, key: u64) -> Option<u128> {
+        let len = self.len(owner);
+        let pos = self._lower_binary_lookup(owner, key, 0, len);
+        match pos == len {
+            true => None,
+            false => Some(self._unsafe_at(owner, pos).value),
+        }
+    }
+
+    /// Returns the value in the last (most recent) checkpoint of `owner` with key lower or equal than the search key,
+    /// or `None` if there is none.
+    pub fn upper_lookup(&self, owner: &K, key: u64) -> Option<u128> {
+        let len = self.len(owner);
+        let pos = self._upper_binary_lookup(owner, key, 0, len);
+        match pos == 0 {
+            true => None,
+            false => Some(self._unsafe_at(owner, pos - 1).value),
+        }
+    }
+
+    /// Returns the value in the last (most recent) checkpoint of `owner` with key lower or equal than the search key,
+    /// or `None` if there is none.
+    ///
+    /// NOTE: This is a variant of `upper_lookup` that is optimised to find "recent" checkpoint (checkpoints with high keys).
+    pub fn upper_lookup_recent(&self, owner: &K, key: u64) -> Option<u128> {
+        let len = self.len(owner);
+
+        let mut low = 0;
+        let mut high = len;
+
+        if len > 5 {
+            let mid = len - sqrt(len);
+            if key < self._unsafe_at(owner, mid).key {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        let pos = self._upper_binary_lookup(owner, key, low, high);
+
+        match pos == 0 {
+            true => None,
+            false => Some(self._unsafe_at(owner, pos - 1).value),
+        }
+    }
+
+    /// Returns the value in the most recent checkpoint of `owner`, or 0 if there are no checkpoints.
+    pub fn latest(&self, owner: &K) -> u128 {
+        self.last(owner, self.len(owner))
+            .map(|checkpoint| checkpoint.value)
+            .unwrap_or_default()
+    }
+
+    /// Returns whether there is a checkpoint of `owner` (i.e. the history is not empty), and if so the key and value
+    /// in the most recent checkpoint.
+    pub fn latest_checkpoint(&self, owner: &K) -> (bool, u64, u128) {
+        match self.last(owner, self.len(owner)) {
+            Some(checkpoint) => (true, checkpoint.key, checkpoint.value),
+            None => (false, 0, 0),
+        }
+    }
+
+    /// Returns the number of checkpoints of `owner`.
+    pub fn len(&self, owner: &K) -> u32 {
+        self.length().get(owner).unwrap_or_default()
+    }
+
+    /// Returns `true` if `owner` has no checkpoints.
+    pub fn is_empty(&self, owner: &K) -> bool {
+        self.len(owner) == 0
+    }
+
+    /// Returns the checkpoint of `owner` at the given index, or None if there is none.
+    pub fn at(&self, owner: &K, index: u32) -> Option<Checkpoint> {
+        self.checkpoints().get((owner, &index))
+    }
+
+    /// Returns the last checkpoint of `owner`, where `len` is the count of its checkpoints.
+    fn last(&self, owner: &K, len: u32) -> Option<Checkpoint> {
+        match len == 0 {
+            true => None,
+            false => Some(self._unsafe_at(owner, len - 1)),
+        }
+    }
+
+    /// Returns the checkpoint of `owner` at the given index, the index must be lower than the length.
+    fn _unsafe_at(&self, owner: &K, index: u32) -> Checkpoint {
+        self.at(owner, index)
+            .expect("The checkpoint under the index lower than length should exist")
+    }
+
+    /// Return the index of the last (most recent) checkpoint with key lower or equal than the search key, or `high` if there is none.
+    /// `low` and `high` define a section where to do the search, with inclusive `low` and exclusive `high`.
+    fn _upper_binary_lookup(&self, owner: &K, key: u64, mut low: u32, mut high: u32) -> u32 {
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if key < self._unsafe_at(owner, mid).key {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        high
+    }
+
+    /// Return the index of the first (oldest) checkpoint with key is greater or equal than the search key, or `high` if there is none.
+    /// `low` and `high` define a section where to do the search, with inclusive `low` and exclusive `high`.
+    fn _lower_binary_lookup(&self, owner: &K, key: u64, mut low: u32, mut high: u32) -> u32 {
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if key > self._unsafe_at(owner, mid).key {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        high
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use ink::{
+        metadata::layout::{
+            Layout,
+            LayoutKey,
+            RootLayout,
+        },
+        storage::traits::StorageLayout,
+    };
+    use scale_info::{
+        build::Fields,
+        type_params,
+        Path,
+        Type,
+        TypeInfo,
+    };
+
+    impl<K, KeyType> TypeInfo for CheckpointsMapping<K, KeyType>
+    where
+        K: TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        type Identity = Self;
+
+        fn type_info() -> Type {
+            Type::builder()
+                .path(Path::new("CheckpointsMapping", module_path!()))
+                .type_params(type_params![K])
+                .composite(Fields::unnamed().field(|f| f.ty::<[(K, Checkpoint)]>()))
+        }
+    }
+
+    impl<K, KeyType> StorageLayout for CheckpointsMapping<K, KeyType>
+    where
+        K: scale_info::TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        fn layout(_: &Key) -> Layout {
+            Layout::Root(RootLayout::new(
+                LayoutKey::from(&KeyType::KEY),
+                <Checkpoint as StorageLayout>::layout(&KeyType::KEY),
+            ))
+        }
+    }
+};
+
+impl<K, KeyType> Storable for CheckpointsMapping<K, KeyType>
+where
+    KeyType: StorageKey,
+{
+    #[inline]
+    fn encode<T: Output + ?Sized>(&self, _dest: &mut T) {}
+
+    #[inline]
+    fn decode<I: Input>(_input: &mut I) -> Result<Self, Error> {
+        Ok(Default::default())
+    }
+}
+
+impl<K, Key, InnerKey> StorableHint<Key> for CheckpointsMapping<K, InnerKey>
+where
+    Key: StorageKey,
+    InnerKey: StorageKey,
+{
+    type Type = CheckpointsMapping<K, Key>;
+    type PreferredKey = InnerKey;
+}
+
+impl<K, KeyType> StorageKey for CheckpointsMapping<K, KeyType>
+where
+    KeyType: StorageKey,
+{
+    const KEY: Key = KeyType::KEY;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn push_works() {
+        let mut mapping: CheckpointsMapping<u128> = CheckpointsMapping::default();
+
+        assert_eq!(mapping.push(&1, 1, 1), Ok((0, 1)));
+        assert_eq!(mapping.push(&1, 1, 2), Ok((1, 2)));
+        assert_eq!(mapping.push(&1, 2, 3), Ok((2, 3)));
+        assert_eq!(mapping.push(&1, 1, 4), Err(CheckpointsError::UnorderedInsertion));
+
+        assert_eq!(mapping.len(&1), 2);
+        assert_eq!(mapping.len(&2), 0);
+        assert_eq!(mapping.at(&1, 0), Some(Checkpoint { key: 1, value: 2 }));
+        assert_eq!(mapping.at(&1, 2), None);
+    }
+
+    #[ink::test]
+    fn lookup_works() {
+        let mut mapping: CheckpointsMapping<u128> = CheckpointsMapping::default();
+        for key in [1, 2, 5, 7, 8, 10, 12] {
+            mapping.push(&1, key, key as u128).unwrap();
+        }
+
+        assert_eq!(mapping.lower_lookup(&1, 0), Some(1));
+        assert_eq!(mapping.lower_lookup(&1, 3), Some(5));
+        assert_eq!(mapping.lower_lookup(&1, 13), None);
+
+        assert_eq!(mapping.upper_lookup(&1, 0), None);
+        assert_eq!(mapping.upper_lookup(&1, 3), Some(2));
+        assert_eq!(mapping.upper_lookup(&1, 13), Some(12));
+
+        assert_eq!(mapping.upper_lookup_recent(&1, 0), None);
+        assert_eq!(mapping.upper_lookup_recent(&1, 3), Some(2));
+        assert_eq!(mapping.upper_lookup_recent(&1, 9), Some(8));
+        assert_eq!(mapping.upper_lookup_recent(&1, 13), Some(12));
+
+        assert_eq!(mapping.upper_lookup(&2, 13), None);
+    }
+
+    #[ink::test]
+    fn latest_works() {
+        let mut mapping: CheckpointsMapping<u128> = CheckpointsMapping::default();
+        assert_eq!(mapping.latest(&1), 0);
+        assert_eq!(mapping.latest_checkpoint(&1), (false, 0, 0));
+
+        mapping.push(&1, 1, 10).unwrap();
+        mapping.push(&1, 3, 30).unwrap();
+        mapping.push(&2, 2, 20).unwrap();
+
+        assert_eq!(mapping.latest(&1), 30);
+        assert_eq!(mapping.latest_checkpoint(&1), (true, 3, 30));
+        assert_eq!(mapping.latest_checkpoint(&2), (true, 2, 20));
+    }
+}
@@ -0,0 +1,336 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+
+use crate::storage::RawMapping;
+use core::marker::PhantomData;
+use ink::{
+    prelude::vec::Vec,
+    primitives::Key,
+    storage::traits::{
+        AutoKey,
+        Packed,
+        Storable,
+        StorableHint,
+        StorageKey,
+    },
+};
+use scale::{
+    Error,
+    Input,
+    Output,
+};
+
+/// A mapping that keeps track of its keys. The mapping provides iteration functionality over all
+/// keys and values with bounded pagination.
+///
+/// Insertion and removal are O(1): the removed key is replaced by the last key (swap-remove),
+/// so the order of keys is not preserved after removal.
+pub struct IterableMapping<K, V, KeyType: StorageKey = AutoKey> {
+    #[allow(clippy::type_complexity)]
+    _marker: PhantomData<fn() -> (K, V, KeyType)>,
+}
+
+impl<K, V, KeyType> IterableMapping<K, V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    #[allow(dead_code)]
+    fn new() -> Self {
+        Self {
+            _marker: Default::default(),
+        }
+    }
+
+    /// Mapping from key to value.
+    /// values: Mapping<K, V>,
+    fn values<'a>(&self) -> RawMapping<&'a K, V, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &0))
+    }
+
+    /// Mapping from key to its index.
+    /// key_to_index: Mapping<K, u128>,
+    fn key_to_index<'a>(&self) -> RawMapping<&'a K, u128, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &1))
+    }
+
+    /// Mapping from index to key.
+    /// index_to_key: Mapping<u128, K>,
+    fn index_to_key<'a>(&self) -> RawMapping<&'a u128, K, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &2))
+    }
+
+    /// Contains count of keys.
+    /// length: u128,
+    fn length(&self) -> RawMapping<(), u128, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &3))
+    }
+}
+
+impl<K, V, KeyType> Default for IterableMapping<K, V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn default() -> Self {
+        Self {
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<K, V, KeyType> core::fmt::Debug for IterableMapping<K, V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("IterableMapping").field("key", &KeyType::KEY).finish()
+    }
+}
+
+impl<K, V, KeyType> IterableMapping<K, V, KeyType>
+where
+    K: Packed,
+    V: Packed,
+    KeyType: StorageKey,
+{
+    /// Insert the given `value` to the contract storage at `key`.
+    pub fn insert(&mut self, key: &K, value: &V) {
+        if !self.key_to_index().contains(key) {
+            let index = self.len();
+            self.index_to_key().insert(&index, key);
+            self.key_to_index().insert(key, &index);
+            self.length().insert((), &(index + 1));
+        }
+        self.values().insert(key, value)
+    }
+
+    /// Get the `value` at `key` from the contract storage.
+    ///
+    /// Returns `None` if no `value` exists at the given `key`.
+    #[inline]
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.values().get(key)
+    }
+
+    /// Checks if a value is stored at the given `key` in the contract storage.
+    #[inline]
+    pub fn contains(&self, key: &K) -> bool {
+        self.key_to_index().contains(key)
+    }
+
+    /// Returns the count of keys stored in the mapping.
+    #[inline]
+    pub fn len(&self) -> u128 {
+        self.length().get(()).unwrap_or_default()
+    }
+
+    /// Returns `true` if there are no keys stored in the mapping.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the key stored at `index`.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn key_at(&self, index: u128) -> Option<K> {
+        self.index_to_key().get(&index)
+    }
+
+    /// Returns at most `limit` keys starting from the `start` index.
+    pub fn keys_paginated(&self, start: u128, limit: u128) -> Vec<K> {
+        let end = start.saturating_add(limit).min(self.len());
+
+        (start..end)
+            .map(|index| self.key_at(index).expect("The key under the index should exist"))
+            .collect()
+    }
+
+    /// Returns at most `limit` values starting from the `start` index.
+    pub fn values_paginated(&self, start: u128, limit: u128) -> Vec<V> {
+        self.keys_paginated(start, limit)
+            .iter()
+            .map(|key| self.get(key).expect("The value under the key should exist"))
+            .collect()
+    }
+
+    /// Clears the value at `key` from storage.
+    ///
+    /// The last key takes the index of the removed one.
+    pub fn remove(&mut self, key: &K) {
+        let index = match self.key_to_index().get(key) {
+            Some(index) => index,
+            None => return,
+        };
+        let last_index = self.len() - 1;
+
+        if last_index != index {
+            let last_key = self
+                .key_at(last_index)
+                .expect("The key under the last index should exist");
+            self.index_to_key().insert(&index, &last_key);
+            self.key_to_index().insert(&last_key, &index);
+        }
+
+        self.index_to_key().remove(&last_index);
+        self.key_to_index().remove(key);
+        self.values().remove(key);
+        self.length().insert((), &last_index);
+    }
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use ink::{
+        metadata::layout::{
+            Layout,
+            LayoutKey,
+            RootLayout,
+        },
+        storage::traits::StorageLayout,
+    };
+    use scale_info::{
+        build::Fields,
+        type_params,
+        Path,
+        Type,
+        TypeInfo,
+    };
+
+    impl<K, V, KeyType> TypeInfo for IterableMapping<K, V, KeyType>
+    where
+        K: TypeInfo + 'static,
+        V: TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        type Identity = Self;
+
+        fn type_info() -> Type {
+            Type::builder()
+                .path(Path::new("IterableMapping", module_path!()))
+                .type_params(type_params![K, V])
+                .composite(Fields::unnamed().field(|f| f.ty::<[(K, V)]>()))
+        }
+    }
+
+    impl<K, V, KeyType> StorageLayout for IterableMapping<K, V, KeyType>
+    where
+        K: scale_info::TypeInfo + 'static,
+        V: Packed + StorageLayout + scale_info::TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        fn layout(_: &Key) -> Layout {
+            Layout::Root(RootLayout::new(
+                LayoutKey::from(&KeyType::KEY),
+                <V as StorageLayout>::layout(&KeyType::KEY),
+            ))
+        }
+    }
+};
+
+impl<K, V, KeyType> Storable for IterableMapping<K, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    #[inline]
+    fn encode<T: Output + ?Sized>(&self, _dest: &mut T) {}
+
+    #[inline]
+    fn decode<I: Input>(_input: &mut I) -> Result<Self, Error> {
+        Ok(Default::default())
+    }
+}
+
+impl<K, V, Key, InnerKey> StorableHint<Key> for IterableMapping<K, V, InnerKey>
+where
+    V: Packed,
+    Key: StorageKey,
+    InnerKey: StorageKey,
+{
+    type Type = IterableMapping<K, V, Key>;
+    type PreferredKey = InnerKey;
+}
+
+impl<K, V, KeyType> StorageKey for IterableMapping<K, V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    const KEY: Key = KeyType::KEY;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn insert_and_get_works() {
+        let mut mapping: IterableMapping<u128, u128> = IterableMapping::default();
+        mapping.insert(&1, &10);
+        mapping.insert(&2, &20);
+        mapping.insert(&1, &11);
+
+        assert_eq!(mapping.len(), 2);
+        assert_eq!(mapping.get(&1), Some(11));
+        assert_eq!(mapping.get(&2), Some(20));
+        assert_eq!(mapping.get(&3), None);
+        assert!(mapping.contains(&2));
+        assert!(!mapping.contains(&3));
+    }
+
+    #[ink::test]
+    fn remove_works() {
+        let mut mapping: IterableMapping<u128, u128> = IterableMapping::default();
+        for key in 0..4 {
+            mapping.insert(&key, &(key * 10));
+        }
+
+        mapping.remove(&1);
+        mapping.remove(&5);
+
+        assert_eq!(mapping.len(), 3);
+        assert_eq!(mapping.get(&1), None);
+        assert_eq!(mapping.keys_paginated(0, 10), vec![0, 3, 2]);
+        assert_eq!(mapping.values_paginated(0, 10), vec![0, 30, 20]);
+
+        mapping.remove(&2);
+        mapping.remove(&0);
+        mapping.remove(&3);
+
+        assert!(mapping.is_empty());
+        assert_eq!(mapping.keys_paginated(0, 10), vec![]);
+    }
+
+    #[ink::test]
+    fn pagination_works() {
+        let mut mapping: IterableMapping<u128, u128> = IterableMapping::default();
+        for key in 0..5 {
+            mapping.insert(&key, &key);
+        }
+
+        assert_eq!(mapping.keys_paginated(1, 2), vec![1, 2]);
+        assert_eq!(mapping.keys_paginated(3, 10), vec![3, 4]);
+        assert_eq!(mapping.keys_paginated(5, 10), vec![]);
+        assert_eq!(mapping.values_paginated(u128::MAX, u128::MAX), vec![]);
+    }
+}
@@ -20,10 +20,16 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use core::marker::PhantomData;
+#[cfg(feature = "checkpoints")]
+mod checkpoints_mapping;
+mod iterable_mapping;
 mod mapping;
 mod multi_mapping;
 mod raw_mapping;
 
+#[cfg(feature = "checkpoints")]
+pub use checkpoints_mapping::CheckpointsMapping;
+pub use iterable_mapping::IterableMapping;
 pub use mapping::Mapping;
 pub use multi_mapping::MultiMapping;
 pub use raw_mapping::RawMapping;
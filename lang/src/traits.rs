@@ -20,6 +20,12 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use ::ink::env::{
+    call::{
+        utils::Set,
+        Call,
+        CallBuilder,
+    },
+    CallFlags,
     DefaultEnvironment,
     Environment,
 };
@@ -29,7 +35,10 @@ use ink::storage::traits::{
     Storable,
     StorageKey,
 };
-pub use openbrush_lang_macro::Storage;
+pub use openbrush_lang_macro::{
+    ErrorFrom,
+    Storage,
+};
 pub use xxhash_rust::const_xxh32::xxh32;
 
 /// Aliases for types of the default environment
@@ -42,6 +51,30 @@ pub type ChainExtension = <DefaultEnvironment as Environment>::ChainExtension;
 pub type EnvAccess = ::ink::EnvAccess<'static, DefaultEnvironment>;
 pub type String = ink::prelude::string::String;
 
+/// The environment which uses the same types as the default environment, so it can be used
+/// with OpenBrush's implementations. Other parameters of the environment, like `ChainExtension`
+/// or `MAX_EVENT_TOPICS`, can be customized.
+///
+/// The implementations access the blockchain and build cross-contract calls with `DefaultEnvironment`,
+/// and their storage, events and errors are defined with its `AccountId`, `Balance`, `Hash`, `Timestamp`
+/// and `BlockNumber`. `#[openbrush::implementation]` checks that the environment of the contract
+/// is compatible.
+pub trait CompatibleEnvironment:
+    Environment<AccountId = AccountId, Balance = Balance, Hash = Hash, Timestamp = Timestamp, BlockNumber = BlockNumber>
+{
+}
+
+impl<E> CompatibleEnvironment for E where
+    E: Environment<
+        AccountId = AccountId,
+        Balance = Balance,
+        Hash = Hash,
+        Timestamp = Timestamp,
+        BlockNumber = BlockNumber,
+    >
+{
+}
+
 /// Each object has access to default environment via `Self::env()`.
 /// It can be used for interaction with host functions of the blockchain.
 pub trait DefaultEnv {
@@ -126,6 +159,70 @@ pub trait Flush: Storable + Sized + StorageKey {
 
 impl<T: Storable + Sized + StorageKey> Flush for T {}
 
+/// Lists the storage keys of the lazy fields and mappings of the type, which are stored
+/// outside of the storage cell of the type itself.
+///
+/// This trait is automatically implemented by `#[openbrush::storage_item]`.
+pub trait StorageKeys {
+    const STORAGE_KEYS: &'static [u32];
+}
+
+/// Options of the cross-contract call, which can be applied to the builders generated by
+/// `#[openbrush::wrapper]`, like `PSP22Ref::transfer_builder`.
+///
+/// Options which are not set keep the default values of the builder.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct CallOptions {
+    gas_limit: Option<u64>,
+    transferred_value: Option<Balance>,
+    call_flags: Option<CallFlags>,
+}
+
+impl CallOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum amount of gas that can be used by the call.
+    pub fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Sets the value transferred to the callee.
+    pub fn transferred_value(mut self, transferred_value: Balance) -> Self {
+        self.transferred_value = Some(transferred_value);
+        self
+    }
+
+    /// Sets the flags of the call.
+    pub fn call_flags(mut self, call_flags: CallFlags) -> Self {
+        self.call_flags = Some(call_flags);
+        self
+    }
+
+    /// Applies the options to the call `builder`.
+    pub fn apply<E, Args, RetType>(
+        &self,
+        builder: CallBuilder<E, Set<Call<E>>, Args, RetType>,
+    ) -> CallBuilder<E, Set<Call<E>>, Args, RetType>
+    where
+        E: Environment<Balance = Balance>,
+    {
+        let mut builder = builder;
+        if let Some(gas_limit) = self.gas_limit {
+            builder = builder.gas_limit(gas_limit);
+        }
+        if let Some(transferred_value) = self.transferred_value {
+            builder = builder.transferred_value(transferred_value);
+        }
+        if let Some(call_flags) = self.call_flags {
+            builder = builder.call_flags(call_flags);
+        }
+        builder
+    }
+}
+
 /// The value 0 is a valid seed.
 const XXH32_SEED: u32 = 0;
 
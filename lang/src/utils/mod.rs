@@ -24,3 +24,5 @@
 pub mod checkpoints;
 #[cfg(feature = "crypto")]
 pub mod crypto;
+#[cfg(feature = "std")]
+pub mod storage_layout;
@@ -0,0 +1,507 @@
+// Copyright (c) 2023 Brushfam
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Compares storage layouts from the metadata of two versions of a contract.
+//!
+//! Upgrading a contract with `set_code_hash` or changing the code of a proxy keeps the storage
+//! of the old version. The new version can read it only if every field keeps its storage key
+//! and its type, and the fields packed under the same key keep their order.
+
+use serde_json::Value;
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    fmt,
+};
+
+/// A single cell of the storage layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageEntry {
+    /// Path of the field, e.g. `Contract::ownable::owner`.
+    pub path: String,
+    /// Storage key of the cell where the field is stored, e.g. `0x00000000`.
+    pub key: String,
+    /// Type of the field.
+    pub ty: String,
+}
+
+/// Change of the storage layout which breaks reading of the existing storage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Incompatibility {
+    /// The field is not present in the new layout.
+    Removed { path: String, key: String },
+    /// The field is stored with another type.
+    Retyped {
+        path: String,
+        old_ty: String,
+        new_ty: String,
+    },
+    /// The field is stored under another storage key.
+    Rekeyed {
+        path: String,
+        old_key: String,
+        new_key: String,
+    },
+    /// Fields packed under the same storage key were added or reordered.
+    Repacked { key: String },
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Incompatibility::Removed { path, key } => write!(f, "removed: `{path}` stored under {key}"),
+            Incompatibility::Retyped { path, old_ty, new_ty } => {
+                write!(f, "retyped: `{path}` changed type from `{old_ty}` to `{new_ty}`")
+            }
+            Incompatibility::Rekeyed { path, old_key, new_key } => {
+                write!(f, "rekeyed: `{path}` moved from {old_key} to {new_key}")
+            }
+            Incompatibility::Repacked { key } => write!(f, "repacked: fields under {key} were added or reordered"),
+        }
+    }
+}
+
+/// The error type of the metadata parsing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutError {
+    /// The metadata is not a valid JSON.
+    InvalidJson(String),
+    /// The metadata doesn't contain `storage` and `types` sections.
+    MissingStorage,
+    /// The storage layout has unexpected format.
+    InvalidLayout(String),
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LayoutError::InvalidJson(err) => write!(f, "invalid metadata JSON: {err}"),
+            LayoutError::MissingStorage => write!(f, "metadata doesn't contain the storage layout"),
+            LayoutError::InvalidLayout(err) => write!(f, "invalid storage layout: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
+
+/// Compares the storage layouts of the `old` and `new` contract metadata JSONs.
+/// Returns the list of incompatibilities, the layouts are compatible if it is empty.
+pub fn check_compatibility(old: &str, new: &str) -> Result<Vec<Incompatibility>, LayoutError> {
+    let old = storage_entries(old)?;
+    let new = storage_entries(new)?;
+
+    let new_by_path: HashMap<&str, &StorageEntry> = new.iter().map(|entry| (entry.path.as_str(), entry)).collect();
+    let mut incompatibilities = Vec::new();
+
+    for entry in old.iter() {
+        match new_by_path.get(entry.path.as_str()) {
+            None => {
+                incompatibilities.push(Incompatibility::Removed {
+                    path: entry.path.clone(),
+                    key: entry.key.clone(),
+                })
+            }
+            Some(new_entry) => {
+                if new_entry.key != entry.key {
+                    incompatibilities.push(Incompatibility::Rekeyed {
+                        path: entry.path.clone(),
+                        old_key: entry.key.clone(),
+                        new_key: new_entry.key.clone(),
+                    })
+                }
+                if new_entry.ty != entry.ty {
+                    incompatibilities.push(Incompatibility::Retyped {
+                        path: entry.path.clone(),
+                        old_ty: entry.ty.clone(),
+                        new_ty: new_entry.ty.clone(),
+                    })
+                }
+            }
+        }
+    }
+
+    // Fields under the same key are encoded together, so the new fields can't be added
+    // to the existing cells and the order of the fields must be the same.
+    let old_cells = cells(&old);
+    let new_cells = cells(&new);
+    for (key, old_paths) in old_cells.iter() {
+        if let Some(new_paths) = new_cells.get(key) {
+            let all_kept = old_paths.iter().all(|path| new_paths.contains(path));
+            if all_kept && old_paths != new_paths {
+                incompatibilities.push(Incompatibility::Repacked { key: key.to_string() })
+            }
+        }
+    }
+
+    Ok(incompatibilities)
+}
+
+/// Returns all cells of the storage layout from the contract metadata JSON.
+pub fn storage_entries(metadata: &str) -> Result<Vec<StorageEntry>, LayoutError> {
+    let metadata: Value = serde_json::from_str(metadata).map_err(|err| LayoutError::InvalidJson(err.to_string()))?;
+
+    // The metadata generated by `cargo contract` may be wrapped into the version object.
+    let project = if metadata.get("storage").is_some() {
+        &metadata
+    } else {
+        metadata
+            .as_object()
+            .and_then(|object| object.values().find(|value| value.get("storage").is_some()))
+            .ok_or(LayoutError::MissingStorage)?
+    };
+
+    let storage = project.get("storage").ok_or(LayoutError::MissingStorage)?;
+    let types = Types::new(project.get("types").ok_or(LayoutError::MissingStorage)?)?;
+
+    let mut entries = Vec::new();
+    walk(storage, "", &types, &mut entries)?;
+    Ok(entries)
+}
+
+fn cells(entries: &[StorageEntry]) -> BTreeMap<&str, Vec<&str>> {
+    let mut cells = BTreeMap::<&str, Vec<&str>>::new();
+    for entry in entries.iter() {
+        cells.entry(entry.key.as_str()).or_default().push(entry.path.as_str());
+    }
+    cells
+}
+
+fn field<'a>(value: &'a Value, name: &str) -> Result<&'a Value, LayoutError> {
+    value
+        .get(name)
+        .ok_or_else(|| LayoutError::InvalidLayout(format!("missing `{name}` in {value}")))
+}
+
+fn string<'a>(value: &'a Value, name: &str) -> Result<&'a str, LayoutError> {
+    field(value, name)?
+        .as_str()
+        .ok_or_else(|| LayoutError::InvalidLayout(format!("`{name}` is not a string in {value}")))
+}
+
+fn walk(layout: &Value, path: &str, types: &Types, entries: &mut Vec<StorageEntry>) -> Result<(), LayoutError> {
+    if let Some(leaf) = layout.get("leaf") {
+        entries.push(StorageEntry {
+            path: path.to_string(),
+            key: string(leaf, "key")?.to_string(),
+            ty: types.name(field(leaf, "ty")?)?,
+        });
+    } else if let Some(root) = layout.get("root") {
+        walk(field(root, "layout")?, path, types, entries)?;
+    } else if let Some(hash) = layout.get("hash") {
+        walk(field(hash, "layout")?, path, types, entries)?;
+    } else if let Some(array) = layout.get("array") {
+        let path = format!("{path}[{}]", field(array, "len")?);
+        walk(field(array, "layout")?, &path, types, entries)?;
+    } else if let Some(structure) = layout.get("struct") {
+        let path = if path.is_empty() {
+            string(structure, "name")?
+        } else {
+            path
+        };
+        walk_fields(structure, path, types, entries)?;
+    } else if let Some(enumeration) = layout.get("enum") {
+        let name = string(enumeration, "name")?;
+        let path = if path.is_empty() { name } else { path };
+        entries.push(StorageEntry {
+            path: path.to_string(),
+            key: string(enumeration, "dispatchKey")?.to_string(),
+            ty: name.to_string(),
+        });
+
+        let variants = field(enumeration, "variants")?
+            .as_object()
+            .ok_or_else(|| LayoutError::InvalidLayout(format!("`variants` is not an object in {enumeration}")))?;
+        for variant in variants.values() {
+            let path = format!("{path}::{}", string(variant, "name")?);
+            walk_fields(variant, &path, types, entries)?;
+        }
+    } else {
+        return Err(LayoutError::InvalidLayout(format!("unknown layout {layout}")))
+    }
+
+    Ok(())
+}
+
+fn walk_fields(
+    structure: &Value,
+    path: &str,
+    types: &Types,
+    entries: &mut Vec<StorageEntry>,
+) -> Result<(), LayoutError> {
+    let fields = field(structure, "fields")?
+        .as_array()
+        .ok_or_else(|| LayoutError::InvalidLayout(format!("`fields` is not an array in {structure}")))?;
+
+    for (index, item) in fields.iter().enumerate() {
+        // Fields of tuple structs don't have names.
+        let name = match item.get("name").and_then(Value::as_str) {
+            Some(name) => name.to_string(),
+            None => index.to_string(),
+        };
+        walk(field(item, "layout")?, &format!("{path}::{name}"), types, entries)?;
+    }
+
+    Ok(())
+}
+
+/// Resolves the type ids of the metadata into the type names, which can be compared
+/// between different metadata.
+struct Types<'a> {
+    types: HashMap<u64, &'a Value>,
+}
+
+impl<'a> Types<'a> {
+    fn new(types: &'a Value) -> Result<Self, LayoutError> {
+        // The registry is flattened into the contract metadata, but not if it is serialized alone.
+        let types = types.get("types").unwrap_or(types);
+        let types = types
+            .as_array()
+            .ok_or_else(|| LayoutError::InvalidLayout("`types` is not an array".to_string()))?
+            .iter()
+            .map(|ty| {
+                let id = field(ty, "id")?
+                    .as_u64()
+                    .ok_or_else(|| LayoutError::InvalidLayout(format!("invalid type id in {ty}")))?;
+                Ok((id, field(ty, "type")?))
+            })
+            .collect::<Result<_, LayoutError>>()?;
+
+        Ok(Self { types })
+    }
+
+    fn name(&self, id: &Value) -> Result<String, LayoutError> {
+        let id = id
+            .as_u64()
+            .ok_or_else(|| LayoutError::InvalidLayout(format!("invalid type id {id}")))?;
+        let ty = self
+            .types
+            .get(&id)
+            .ok_or_else(|| LayoutError::InvalidLayout(format!("unknown type id {id}")))?;
+
+        let path = ty
+            .get("path")
+            .and_then(Value::as_array)
+            .map(|path| path.iter().filter_map(Value::as_str).collect::<Vec<_>>().join("::"))
+            .unwrap_or_default();
+
+        if !path.is_empty() {
+            let params = ty
+                .get("params")
+                .and_then(Value::as_array)
+                .map(|params| {
+                    params
+                        .iter()
+                        .filter_map(|param| param.get("type"))
+                        .filter(|ty| !ty.is_null())
+                        .map(|ty| self.name(ty))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()?
+                .unwrap_or_default();
+
+            return if params.is_empty() {
+                Ok(path)
+            } else {
+                Ok(format!("{path}<{}>", params.join(", ")))
+            }
+        }
+
+        let def = field(ty, "def")?;
+        if let Some(primitive) = def.get("primitive").and_then(Value::as_str) {
+            Ok(primitive.to_string())
+        } else if let Some(sequence) = def.get("sequence") {
+            Ok(format!("Vec<{}>", self.name(field(sequence, "type")?)?))
+        } else if let Some(array) = def.get("array") {
+            Ok(format!(
+                "[{}; {}]",
+                self.name(field(array, "type")?)?,
+                field(array, "len")?
+            ))
+        } else if let Some(compact) = def.get("compact") {
+            Ok(format!("Compact<{}>", self.name(field(compact, "type")?)?))
+        } else if let Some(tuple) = def.get("tuple").and_then(Value::as_array) {
+            let items = tuple.iter().map(|ty| self.name(ty)).collect::<Result<Vec<_>, _>>()?;
+            Ok(format!("({})", items.join(", ")))
+        } else {
+            // Anonymous composites and bit sequences are compared by their definition.
+            Ok(def.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn types() -> Value {
+        json!([
+            { "id": 0, "type": { "def": { "primitive": "u8" } } },
+            { "id": 1, "type": { "def": { "primitive": "u128" } } },
+            { "id": 2, "type": { "def": { "array": { "len": 32, "type": 0 } } } },
+            { "id": 3, "type": { "def": { "composite": { "fields": [{ "type": 2 }] } }, "path": ["ink_primitives", "types", "AccountId"] } },
+            { "id": 4, "type": { "def": { "variant": {} }, "path": ["Option"], "params": [{ "name": "T", "type": 3 }] } },
+            { "id": 5, "type": { "def": { "primitive": "bool" } } }
+        ])
+    }
+
+    fn leaf(key: &str, ty: u32) -> Value {
+        json!({ "leaf": { "key": key, "ty": ty } })
+    }
+
+    fn root(key: &str, layout: Value) -> Value {
+        json!({ "root": { "root_key": key, "layout": layout } })
+    }
+
+    fn metadata(fields: Vec<(&str, Value)>) -> String {
+        let fields: Vec<Value> = fields
+            .into_iter()
+            .map(|(name, layout)| json!({ "name": name, "layout": layout }))
+            .collect();
+        json!({
+            "storage": root("0x00000000", json!({ "struct": { "name": "Contract", "fields": fields } })),
+            "types": types(),
+        })
+        .to_string()
+    }
+
+    fn ownable(key: &str) -> Value {
+        json!({ "struct": { "name": "Data", "fields": [{ "name": "owner", "layout": root(key, leaf(key, 4)) }] } })
+    }
+
+    #[test]
+    fn storage_entries_works() {
+        let entries = storage_entries(&metadata(vec![
+            ("ownable", ownable("0x0a0b0c0d")),
+            ("flag", leaf("0x00000000", 5)),
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            entries,
+            vec![
+                StorageEntry {
+                    path: "Contract::ownable::owner".to_string(),
+                    key: "0x0a0b0c0d".to_string(),
+                    ty: "Option<ink_primitives::types::AccountId>".to_string(),
+                },
+                StorageEntry {
+                    path: "Contract::flag".to_string(),
+                    key: "0x00000000".to_string(),
+                    ty: "bool".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn same_layout_is_compatible() {
+        let old = metadata(vec![
+            ("ownable", ownable("0x0a0b0c0d")),
+            ("flag", leaf("0x00000000", 5)),
+        ]);
+
+        assert_eq!(check_compatibility(&old, &old), Ok(vec![]));
+    }
+
+    #[test]
+    fn added_lazy_field_is_compatible() {
+        let old = metadata(vec![("flag", leaf("0x00000000", 5))]);
+        let new = metadata(vec![
+            ("flag", leaf("0x00000000", 5)),
+            ("ownable", ownable("0x0a0b0c0d")),
+        ]);
+
+        assert_eq!(check_compatibility(&old, &new), Ok(vec![]));
+    }
+
+    #[test]
+    fn removed_field_is_reported() {
+        let old = metadata(vec![
+            ("ownable", ownable("0x0a0b0c0d")),
+            ("flag", leaf("0x00000000", 5)),
+        ]);
+        let new = metadata(vec![("flag", leaf("0x00000000", 5))]);
+
+        assert_eq!(
+            check_compatibility(&old, &new),
+            Ok(vec![Incompatibility::Removed {
+                path: "Contract::ownable::owner".to_string(),
+                key: "0x0a0b0c0d".to_string(),
+            }])
+        );
+    }
+
+    #[test]
+    fn retyped_and_rekeyed_fields_are_reported() {
+        let old = metadata(vec![
+            ("ownable", ownable("0x0a0b0c0d")),
+            ("flag", leaf("0x00000000", 5)),
+        ]);
+        let new = metadata(vec![
+            ("ownable", ownable("0x01020304")),
+            ("flag", leaf("0x00000000", 1)),
+        ]);
+
+        assert_eq!(
+            check_compatibility(&old, &new),
+            Ok(vec![
+                Incompatibility::Rekeyed {
+                    path: "Contract::ownable::owner".to_string(),
+                    old_key: "0x0a0b0c0d".to_string(),
+                    new_key: "0x01020304".to_string(),
+                },
+                Incompatibility::Retyped {
+                    path: "Contract::flag".to_string(),
+                    old_ty: "bool".to_string(),
+                    new_ty: "u128".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn repacked_fields_are_reported() {
+        let old = metadata(vec![
+            ("flag", leaf("0x00000000", 5)),
+            ("counter", leaf("0x00000000", 1)),
+        ]);
+        let new = metadata(vec![
+            ("counter", leaf("0x00000000", 1)),
+            ("flag", leaf("0x00000000", 5)),
+        ]);
+
+        assert_eq!(
+            check_compatibility(&old, &new),
+            Ok(vec![Incompatibility::Repacked {
+                key: "0x00000000".to_string()
+            }])
+        );
+    }
+
+    #[test]
+    fn missing_storage_fails() {
+        assert_eq!(check_compatibility("{}", "{}"), Err(LayoutError::MissingStorage));
+    }
+}
@@ -381,4 +381,93 @@ mod access_control {
             Err(AccessControlError::MissingRole)
         );
     }
+
+    #[ink::test]
+    fn should_expire_role_granted_until() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(100);
+        assert!(AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 200).is_ok());
+        assert!(AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_role_granted_event(&emitted_events[1], PAUSER, Some(accounts.bob), Some(accounts.alice));
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(200);
+        assert!(!AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+        assert_eq!(
+            AccessControl::renounce_role(&mut access_control, PAUSER, Some(accounts.bob)),
+            Err(AccessControlError::InvalidCaller)
+        );
+    }
+
+    #[ink::test]
+    fn should_not_grant_role_until_past_timestamp() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(100);
+        assert_eq!(
+            AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 50),
+            Err(AccessControlError::InvalidExpiration)
+        );
+        assert_eq!(
+            AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 100),
+            Err(AccessControlError::InvalidExpiration)
+        );
+        assert!(!AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+    }
+
+    #[ink::test]
+    fn should_revoke_expired_role() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 200).is_ok());
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(200);
+
+        assert!(AccessControl::revoke_role(&mut access_control, PAUSER, Some(accounts.bob)).is_ok());
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_role_revoked_event(&emitted_events[2], PAUSER, Some(accounts.bob), accounts.alice);
+        assert_eq!(
+            access_control::MembersManager::_get_expiration(&access_control, PAUSER, &Some(accounts.bob)),
+            None
+        );
+        assert_eq!(
+            AccessControl::revoke_role(&mut access_control, PAUSER, Some(accounts.bob)),
+            Err(AccessControlError::MissingRole)
+        );
+    }
+
+    #[ink::test]
+    fn should_not_grant_role_until_twice() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 200).is_ok());
+        assert_eq!(
+            AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 300),
+            Err(AccessControlError::RoleRedundant)
+        );
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            AccessControl::grant_role_until(&mut access_control, MINTER, Some(accounts.bob), 200),
+            Err(AccessControlError::MissingRole)
+        );
+    }
+
+    #[ink::test]
+    fn should_clear_expiration_on_regrant() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 200).is_ok());
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(200);
+        assert!(AccessControl::grant_role(&mut access_control, PAUSER, Some(accounts.bob)).is_ok());
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(1000);
+        assert!(AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+    }
 }
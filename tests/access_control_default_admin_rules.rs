@@ -0,0 +1,375 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "access_control")]
+#[openbrush::implementation(AccessControl, AccessControlDefaultAdminRules)]
+#[openbrush::contract]
+mod access_control_default_admin_rules {
+    use ::ink::env::DefaultEnvironment;
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct DefaultAdminTransferScheduled {
+        #[ink(topic)]
+        new_admin: Option<AccountId>,
+        accept_schedule: Timestamp,
+    }
+
+    const MINTER: RoleType = ink::selector_id!("MINTER");
+    const DELAY: Timestamp = 10;
+    const START: Timestamp = 100;
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct AccessControlStruct {
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        default_admin_rules: default_admin_rules::Data,
+    }
+
+    type Event = <AccessControlStruct as ::ink::reflect::ContractEventBase>::Type;
+
+    #[overrider(default_admin_rules::Internal)]
+    fn _emit_default_admin_transfer_scheduled(&self, new_admin: Option<AccountId>, accept_schedule: Timestamp) {
+        self.env().emit_event(DefaultAdminTransferScheduled {
+            new_admin,
+            accept_schedule,
+        })
+    }
+
+    impl AccessControlStruct {
+        #[ink(constructor)]
+        pub fn new(admin: AccountId, initial_delay: Timestamp) -> Self {
+            let mut instance = Self::default();
+            assert!(
+                default_admin_rules::Internal::_init_with_default_admin(&mut instance, Some(admin), initial_delay)
+                    .is_ok()
+            );
+            instance
+        }
+    }
+
+    fn set_block_timestamp(timestamp: Timestamp) {
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+    }
+
+    fn assert_default_admin_transfer_scheduled_event(
+        event: &ink::env::test::EmittedEvent,
+        expected_new_admin: Option<AccountId>,
+        expected_accept_schedule: Timestamp,
+    ) {
+        if let Event::DefaultAdminTransferScheduled(DefaultAdminTransferScheduled {
+            new_admin,
+            accept_schedule,
+        }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(new_admin, expected_new_admin, "New admin was not equal to expected");
+            assert_eq!(
+                accept_schedule, expected_accept_schedule,
+                "Accept schedule was not equal to expected"
+            );
+        } else {
+            panic!("encountered unexpected event kind: expected a DefaultAdminTransferScheduled event")
+        }
+    }
+
+    #[ink::test]
+    fn should_init_with_default_admin() {
+        let accounts = accounts();
+        let instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        assert_eq!(
+            AccessControlDefaultAdminRules::default_admin(&instance),
+            Some(accounts.alice)
+        );
+        assert_eq!(AccessControlDefaultAdminRules::default_admin_delay(&instance), DELAY);
+        assert!(AccessControl::has_role(
+            &instance,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.alice)
+        ));
+    }
+
+    #[ink::test]
+    fn should_reject_default_admin_role_in_grant_and_revoke() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        assert_eq!(
+            AccessControl::grant_role(&mut instance, DEFAULT_ADMIN_ROLE, Some(accounts.bob)),
+            Err(AccessControlError::EnforcedDefaultAdminRules)
+        );
+        assert_eq!(
+            AccessControl::revoke_role(&mut instance, DEFAULT_ADMIN_ROLE, Some(accounts.alice)),
+            Err(AccessControlError::EnforcedDefaultAdminRules)
+        );
+
+        assert!(AccessControl::grant_role(&mut instance, MINTER, Some(accounts.bob)).is_ok());
+        assert!(AccessControl::revoke_role(&mut instance, MINTER, Some(accounts.bob)).is_ok());
+    }
+
+    #[ink::test]
+    fn should_transfer_default_admin_after_delay() {
+        let accounts = accounts();
+        set_block_timestamp(START);
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut instance, Some(accounts.bob)),
+            Err(AccessControlError::MissingRole)
+        );
+
+        change_caller(accounts.alice);
+        assert!(
+            AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut instance, Some(accounts.bob)).is_ok()
+        );
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin(&instance),
+            (Some(accounts.bob), START + DELAY)
+        );
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_default_admin_transfer_scheduled_event(
+            emitted_events.last().unwrap(),
+            Some(accounts.bob),
+            START + DELAY,
+        );
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            AccessControlDefaultAdminRules::accept_default_admin_transfer(&mut instance),
+            Err(AccessControlError::EnforcedDefaultAdminDelay)
+        );
+
+        set_block_timestamp(START + DELAY);
+        change_caller(accounts.charlie);
+        assert_eq!(
+            AccessControlDefaultAdminRules::accept_default_admin_transfer(&mut instance),
+            Err(AccessControlError::InvalidDefaultAdmin)
+        );
+
+        change_caller(accounts.bob);
+        assert!(AccessControlDefaultAdminRules::accept_default_admin_transfer(&mut instance).is_ok());
+        assert_eq!(
+            AccessControlDefaultAdminRules::default_admin(&instance),
+            Some(accounts.bob)
+        );
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin(&instance),
+            (None, 0)
+        );
+        assert!(AccessControl::has_role(
+            &instance,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.bob)
+        ));
+        assert!(!AccessControl::has_role(
+            &instance,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.alice)
+        ));
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let role_events = emitted_events[emitted_events.len() - 2..]
+            .iter()
+            .map(|event| {
+                <Event as scale::Decode>::decode(&mut &event.data[..])
+                    .expect("encountered invalid contract event data buffer")
+            })
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            &role_events[0],
+            Event::RoleRevoked(RoleRevoked { role: DEFAULT_ADMIN_ROLE, account, .. }) if *account == Some(accounts.alice)
+        ));
+        assert!(matches!(
+            &role_events[1],
+            Event::RoleGranted(RoleGranted { role: DEFAULT_ADMIN_ROLE, grantee, .. }) if *grantee == Some(accounts.bob)
+        ));
+    }
+
+    #[ink::test]
+    fn should_cancel_default_admin_transfer() {
+        let accounts = accounts();
+        set_block_timestamp(START);
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        assert!(
+            AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut instance, Some(accounts.bob)).is_ok()
+        );
+        assert!(AccessControlDefaultAdminRules::cancel_default_admin_transfer(&mut instance).is_ok());
+
+        set_block_timestamp(START + DELAY);
+        change_caller(accounts.bob);
+        assert_eq!(
+            AccessControlDefaultAdminRules::accept_default_admin_transfer(&mut instance),
+            Err(AccessControlError::InvalidDefaultAdmin)
+        );
+    }
+
+    #[ink::test]
+    fn should_change_default_admin_delay_with_delay() {
+        let accounts = accounts();
+        set_block_timestamp(START);
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        // Increasing the delay waits for the new delay.
+        assert!(AccessControlDefaultAdminRules::change_default_admin_delay(&mut instance, 100).is_ok());
+        assert_eq!(AccessControlDefaultAdminRules::default_admin_delay(&instance), DELAY);
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin_delay(&instance),
+            (100, START + 100)
+        );
+
+        set_block_timestamp(START + 100);
+        assert_eq!(AccessControlDefaultAdminRules::default_admin_delay(&instance), 100);
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin_delay(&instance),
+            (0, 0)
+        );
+
+        // Decreasing the delay waits for the difference.
+        assert!(AccessControlDefaultAdminRules::change_default_admin_delay(&mut instance, 40).is_ok());
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin_delay(&instance),
+            (40, START + 160)
+        );
+
+        assert!(AccessControlDefaultAdminRules::rollback_default_admin_delay(&mut instance).is_ok());
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin_delay(&instance),
+            (0, 0)
+        );
+
+        set_block_timestamp(START + 160);
+        assert_eq!(AccessControlDefaultAdminRules::default_admin_delay(&instance), 100);
+    }
+
+    #[ink::test]
+    fn should_renounce_default_admin_after_delay() {
+        let accounts = accounts();
+        set_block_timestamp(START);
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        assert_eq!(
+            AccessControl::renounce_role(&mut instance, DEFAULT_ADMIN_ROLE, Some(accounts.alice)),
+            Err(AccessControlError::EnforcedDefaultAdminDelay)
+        );
+
+        assert!(AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut instance, None).is_ok());
+        assert_eq!(
+            AccessControl::renounce_role(&mut instance, DEFAULT_ADMIN_ROLE, Some(accounts.alice)),
+            Err(AccessControlError::EnforcedDefaultAdminDelay)
+        );
+
+        set_block_timestamp(START + DELAY);
+        assert!(AccessControl::renounce_role(&mut instance, DEFAULT_ADMIN_ROLE, Some(accounts.alice)).is_ok());
+        assert_eq!(AccessControlDefaultAdminRules::default_admin(&instance), None);
+        assert!(!AccessControl::has_role(
+            &instance,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.alice)
+        ));
+    }
+
+    #[ink::test]
+    fn should_saturate_schedules() {
+        let accounts = accounts();
+        set_block_timestamp(START);
+        let mut instance = AccessControlStruct::new(accounts.alice, Timestamp::MAX);
+
+        assert!(
+            AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut instance, Some(accounts.bob)).is_ok()
+        );
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin(&instance),
+            (Some(accounts.bob), Timestamp::MAX)
+        );
+
+        assert!(AccessControlDefaultAdminRules::change_default_admin_delay(&mut instance, 0).is_ok());
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin_delay(&instance),
+            (0, Timestamp::MAX)
+        );
+    }
+
+    #[ink::test]
+    fn should_track_default_admin_granted_internally() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::default();
+
+        access_control::Internal::_init_with_admin(&mut instance, Some(accounts.alice));
+        assert_eq!(
+            AccessControlDefaultAdminRules::default_admin(&instance),
+            Some(accounts.alice)
+        );
+
+        access_control::Internal::_do_revoke_role(&mut instance, DEFAULT_ADMIN_ROLE, Some(accounts.alice));
+        assert_eq!(AccessControlDefaultAdminRules::default_admin(&instance), None);
+
+        access_control::Internal::_setup_role(&mut instance, DEFAULT_ADMIN_ROLE, Some(accounts.bob));
+        assert_eq!(
+            AccessControlDefaultAdminRules::default_admin(&instance),
+            Some(accounts.bob)
+        );
+    }
+
+    #[ink::test]
+    fn should_not_setup_second_default_admin() {
+        let accounts = accounts();
+        let mut instance = AccessControlStruct::new(accounts.alice, DELAY);
+
+        assert_eq!(
+            default_admin_rules::Internal::_setup_role_with_rules(
+                &mut instance,
+                DEFAULT_ADMIN_ROLE,
+                Some(accounts.bob)
+            ),
+            Err(AccessControlError::DefaultAdminAlreadyGranted)
+        );
+        assert_eq!(
+            default_admin_rules::Internal::_init_with_default_admin(&mut instance, Some(accounts.bob), DELAY),
+            Err(AccessControlError::DefaultAdminAlreadyGranted)
+        );
+
+        access_control::Internal::_setup_role(&mut instance, DEFAULT_ADMIN_ROLE, Some(accounts.bob));
+        assert!(!AccessControl::has_role(
+            &instance,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.bob)
+        ));
+        assert_eq!(
+            AccessControlDefaultAdminRules::default_admin(&instance),
+            Some(accounts.alice)
+        );
+    }
+}
@@ -133,4 +133,98 @@ mod access_control_enumerable {
             None
         )
     }
+
+    #[ink::test]
+    fn should_prune_expired_role_members() {
+        let accounts = setup();
+        let alice = accounts.alice;
+        let mut access_control = AccessControlStruct::new(alice);
+
+        assert!(AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 100).is_ok());
+        assert!(AccessControl::grant_role(&mut access_control, PAUSER, Some(alice)).is_ok());
+        assert!(AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.eve), 300).is_ok());
+
+        assert_eq!(
+            AccessControlEnumerable::prune_expired_role_members(&mut access_control, PAUSER),
+            0
+        );
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(100);
+        assert_eq!(
+            AccessControlEnumerable::prune_expired_role_members(&mut access_control, PAUSER),
+            1
+        );
+        assert_eq!(
+            AccessControlEnumerable::get_role_member_count(&mut access_control, PAUSER),
+            2
+        );
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(300);
+        assert_eq!(
+            AccessControlEnumerable::prune_expired_role_members(&mut access_control, PAUSER),
+            1
+        );
+        assert_eq!(
+            AccessControlEnumerable::get_role_member(&mut access_control, PAUSER, 0),
+            Some(alice)
+        );
+        assert_eq!(
+            AccessControlEnumerable::get_role_member_count(&mut access_control, PAUSER),
+            1
+        );
+    }
+
+    #[ink::test]
+    fn should_skip_expired_role_members() {
+        let accounts = setup();
+        let alice = accounts.alice;
+        let mut access_control = AccessControlStruct::new(alice);
+
+        assert!(AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 100).is_ok());
+        assert!(AccessControl::grant_role(&mut access_control, PAUSER, Some(alice)).is_ok());
+        assert_eq!(
+            AccessControlEnumerable::get_role_member_count(&mut access_control, PAUSER),
+            2
+        );
+        assert_eq!(
+            AccessControlEnumerable::get_role_member(&mut access_control, PAUSER, 0),
+            Some(accounts.bob)
+        );
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(100);
+        assert!(!AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+        assert_eq!(
+            AccessControlEnumerable::get_role_member_count(&mut access_control, PAUSER),
+            1
+        );
+        assert_eq!(
+            AccessControlEnumerable::get_role_member(&mut access_control, PAUSER, 0),
+            Some(alice)
+        );
+        assert_eq!(
+            AccessControlEnumerable::get_role_member(&mut access_control, PAUSER, 1),
+            None
+        );
+    }
+
+    #[ink::test]
+    fn should_remove_expired_role_member_on_revoke() {
+        let accounts = setup();
+        let alice = accounts.alice;
+        let mut access_control = AccessControlStruct::new(alice);
+
+        assert!(AccessControl::grant_role_until(&mut access_control, PAUSER, Some(accounts.bob), 100).is_ok());
+        assert!(AccessControl::grant_role(&mut access_control, PAUSER, Some(alice)).is_ok());
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(100);
+        assert!(AccessControl::revoke_role(&mut access_control, PAUSER, Some(accounts.bob)).is_ok());
+        assert_eq!(
+            AccessControlEnumerable::get_role_member_count(&mut access_control, PAUSER),
+            1
+        );
+        assert_eq!(
+            AccessControlEnumerable::get_role_member(&mut access_control, PAUSER, 0),
+            Some(alice)
+        );
+    }
 }
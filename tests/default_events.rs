@@ -0,0 +1,105 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::implementation(PSP22, Ownable)]
+#[openbrush::contract]
+mod default_events {
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MyToken {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    type Event = <MyToken as ::ink::reflect::ContractEventBase>::Type;
+
+    #[overrider(psp22::Internal)]
+    fn _emit_approval_event(&self, _owner: AccountId, _spender: AccountId, _amount: Balance) {}
+
+    impl MyToken {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            let caller = Self::env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            psp22::Internal::_mint_to(&mut instance, caller, total_supply).unwrap();
+            instance
+        }
+    }
+
+    fn decode_events() -> Vec<Event> {
+        ink::env::test::recorded_events()
+            .map(|event| {
+                <Event as scale::Decode>::decode(&mut &event.data[..])
+                    .expect("encountered invalid contract event data buffer")
+            })
+            .collect()
+    }
+
+    #[ink::test]
+    fn default_events_are_emitted() {
+        let accounts = accounts();
+        let mut token = MyToken::new(100);
+
+        assert!(PSP22::transfer(&mut token, accounts.bob, 10, vec![]).is_ok());
+        assert!(Ownable::transfer_ownership(&mut token, accounts.bob).is_ok());
+
+        let events = decode_events();
+        assert_eq!(events.len(), 4);
+        assert!(matches!(
+            &events[0],
+            Event::OwnershipTransferred(OwnershipTransferred { previous: None, new })
+                if *new == Some(accounts.alice)
+        ));
+        assert!(matches!(
+            &events[1],
+            Event::Transfer(Transfer { from: None, to, value: 100 }) if *to == Some(accounts.alice)
+        ));
+        assert!(matches!(
+            &events[2],
+            Event::Transfer(Transfer { from, to, value: 10 })
+                if *from == Some(accounts.alice) && *to == Some(accounts.bob)
+        ));
+        assert!(matches!(
+            &events[3],
+            Event::OwnershipTransferred(OwnershipTransferred { previous, new })
+                if *previous == Some(accounts.alice) && *new == Some(accounts.bob)
+        ));
+    }
+
+    #[ink::test]
+    fn overridden_hook_is_not_replaced() {
+        let accounts = accounts();
+        let mut token = MyToken::new(100);
+
+        assert!(PSP22::approve(&mut token, accounts.bob, 10).is_ok());
+
+        assert_eq!(decode_events().len(), 2);
+    }
+}
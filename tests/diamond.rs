@@ -0,0 +1,315 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "diamond", feature = "psp61"))]
+#[openbrush::implementation(Ownable, Diamond, DiamondLoupe, PSP61)]
+#[openbrush::contract]
+mod diamond {
+    use openbrush::{
+        contracts::supported_interfaces,
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    const STORAGE_KEY: u32 = 10;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct DiamondContract {
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        diamond: diamond::Data,
+        #[storage_field]
+        diamond_loupe: diamond_loupe::Data,
+        require_facet_info: bool,
+    }
+
+    supported_interfaces!(DiamondContract);
+
+    type Event = <DiamondContract as ::ink::reflect::ContractEventBase>::Type;
+
+    impl DiamondContract {
+        #[ink(constructor)]
+        pub fn new(owner: AccountId) -> Self {
+            let mut instance = Self::default();
+
+            ownable::Internal::_init_with_owner(&mut instance, owner);
+
+            instance
+        }
+
+        #[ink(message, payable, selector = _)]
+        pub fn forward(&self) {
+            diamond::Internal::_fallback(self)
+        }
+    }
+
+    // The off-chain environment doesn't support delegate calls, so facets are described here
+    #[overrider(diamond::Internal)]
+    fn _facet_info(&self, code_hash: Hash) -> Result<FacetInfo, DiamondError> {
+        let facet = code_hash.as_ref()[0];
+        match facet {
+            1 | 2 => {
+                Ok(FacetInfo {
+                    selectors: vec![[facet; 4], [facet + 10; 4]],
+                    storage_keys: vec![STORAGE_KEY, STORAGE_KEY + u32::from(facet)],
+                })
+            }
+            3 => {
+                Ok(FacetInfo {
+                    selectors: vec![[facet; 4], [1u8; 4]],
+                    storage_keys: vec![STORAGE_KEY + u32::from(facet)],
+                })
+            }
+            _ => Err(DiamondError::FacetInfoUnavailable(code_hash)),
+        }
+    }
+
+    #[overrider(diamond::Internal)]
+    fn _require_facet_info(&self) -> bool {
+        self.require_facet_info
+    }
+
+    fn facet_cut(facet: u8, selectors: Vec<Selector>) -> FacetCut {
+        FacetCut {
+            hash: [facet; 32].into(),
+            selectors,
+        }
+    }
+
+    #[ink::test]
+    fn diamond_cut_with_exposed_selectors_works() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        let cuts = vec![facet_cut(1, vec![[1u8; 4], [11u8; 4]]), facet_cut(3, vec![[3u8; 4]])];
+        assert_eq!(Diamond::diamond_cut(&mut diamond, cuts.clone(), None), Ok(()));
+        assert_eq!(DiamondLoupe::facets(&diamond), cuts);
+    }
+
+    #[ink::test]
+    fn diamond_cut_should_fail_selector_not_exposed() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[1u8; 4], [2u8; 4]])], None),
+            Err(DiamondError::SelectorNotExposed([2u8; 4]))
+        );
+        assert_eq!(DiamondLoupe::facets(&diamond), vec![]);
+    }
+
+    #[ink::test]
+    fn diamond_cut_without_facet_info_works() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        let cuts = vec![facet_cut(4, vec![[4u8; 4], [5u8; 4]])];
+        assert_eq!(Diamond::diamond_cut(&mut diamond, cuts.clone(), None), Ok(()));
+        assert_eq!(DiamondLoupe::facets(&diamond), cuts);
+    }
+
+    #[ink::test]
+    fn diamond_cut_should_fail_facet_info_unavailable() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        diamond.require_facet_info = true;
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(4, vec![[4u8; 4]])], None),
+            Err(DiamondError::FacetInfoUnavailable([4u8; 32].into()))
+        );
+    }
+
+    #[ink::test]
+    fn diamond_cut_should_fail_storage_collision() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[1u8; 4]])], None),
+            Ok(())
+        );
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(2, vec![[2u8; 4]])], None),
+            Err(DiamondError::StorageCollision(STORAGE_KEY, [1u8; 32].into()))
+        );
+        assert_eq!(DiamondLoupe::facets(&diamond), vec![facet_cut(1, vec![[1u8; 4]])]);
+    }
+
+    #[ink::test]
+    fn diamond_cut_same_facet_keeps_storage() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[1u8; 4]])], None),
+            Ok(())
+        );
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[1u8; 4], [11u8; 4]])], None),
+            Ok(())
+        );
+        assert_eq!(
+            diamond.diamond.storage_key_to_hash.get(&STORAGE_KEY),
+            Some([1u8; 32].into())
+        );
+    }
+
+    #[ink::test]
+    fn removed_facet_releases_storage() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[1u8; 4]])], None),
+            Ok(())
+        );
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![])], None),
+            Ok(())
+        );
+        assert_eq!(diamond.diamond.storage_key_to_hash.get(&STORAGE_KEY), None);
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(2, vec![[2u8; 4]])], None),
+            Ok(())
+        );
+        assert_eq!(
+            diamond.diamond.storage_key_to_hash.get(&STORAGE_KEY),
+            Some([2u8; 32].into())
+        );
+    }
+
+    #[ink::test]
+    fn diamond_cut_emits_event() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        let cuts = vec![facet_cut(1, vec![[1u8; 4]])];
+        assert_eq!(Diamond::diamond_cut(&mut diamond, cuts.clone(), None), Ok(()));
+
+        // the first event is `OwnershipTransferred` emitted by the constructor
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 2);
+        match <Event as scale::Decode>::decode(&mut &emitted_events[1].data[..])
+            .expect("encountered invalid contract event data buffer")
+        {
+            Event::DiamondCut(DiamondCut {
+                cuts: emitted_cuts,
+                init,
+            }) => {
+                assert_eq!(emitted_cuts, cuts);
+                assert_eq!(init, None);
+            }
+            _ => panic!("Expected DiamondCut event"),
+        }
+    }
+
+    #[ink::test]
+    fn immutable_selector_can_not_be_removed() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[1u8; 4], [11u8; 4]])], None),
+            Ok(())
+        );
+        assert_eq!(
+            diamond::Internal::_make_selectors_immutable(&mut diamond, &[[1u8; 4]]),
+            Ok(())
+        );
+
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[11u8; 4]])], None),
+            Err(DiamondError::ImmutableSelector([1u8; 4]))
+        );
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![])], None),
+            Err(DiamondError::ImmutableSelector([1u8; 4]))
+        );
+        // mutable selectors of the facet still can be removed
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[1u8; 4]])], None),
+            Ok(())
+        );
+        assert_eq!(DiamondLoupe::facets(&diamond), vec![facet_cut(1, vec![[1u8; 4]])]);
+    }
+
+    #[ink::test]
+    fn immutable_selector_can_not_be_replaced() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[1u8; 4]])], None),
+            Ok(())
+        );
+        assert_eq!(
+            diamond::Internal::_make_selectors_immutable(&mut diamond, &[[1u8; 4]]),
+            Ok(())
+        );
+
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(3, vec![[1u8; 4]])], None),
+            Err(DiamondError::ImmutableSelector([1u8; 4]))
+        );
+    }
+
+    #[ink::test]
+    fn make_unregistered_selector_immutable_should_fail() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+
+        assert_eq!(
+            diamond::Internal::_make_selectors_immutable(&mut diamond, &[[1u8; 4]]),
+            Err(DiamondError::FunctionDoesNotExist)
+        );
+    }
+
+    #[ink::test]
+    fn diamond_cut_registers_interfaces() {
+        let accounts = accounts();
+        let mut diamond = DiamondContract::new(accounts.alice);
+        let loupe_id = diamond_loupe::diamondloupe_external::TRAIT_ID;
+        let psp61_id = psp61::psp61_external::TRAIT_ID;
+
+        assert_eq!(diamond::Internal::_supported_interfaces(&diamond), Vec::<u32>::new());
+
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(1, vec![[1u8; 4]])], None),
+            Ok(())
+        );
+        assert_eq!(
+            Diamond::diamond_cut(&mut diamond, vec![facet_cut(3, vec![[3u8; 4]])], None),
+            Ok(())
+        );
+
+        assert_eq!(
+            diamond::Internal::_supported_interfaces(&diamond),
+            vec![loupe_id, psp61_id]
+        );
+        assert!(PSP61::supports_interface(&diamond, loupe_id));
+        assert!(PSP61::supports_interface(&diamond, psp61_id));
+        let interfaces = PSP61::supported_interfaces(&diamond);
+        assert_eq!(interfaces.iter().filter(|id| **id == psp61_id).count(), 1);
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "diamond", feature = "ownable"))]
+#[openbrush::implementation(PSP22, Ownable, DiamondFacet)]
+#[openbrush::contract]
+mod diamond_facet {
+    use ink::reflect::{
+        ContractMessageDecoder,
+        DecodeDispatch,
+        DispatchError,
+    };
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Facet {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    #[overrider(diamond_facet::Internal)]
+    fn _facet_storage_keys(&self) -> Vec<u32> {
+        vec![psp22::STORAGE_KEY_DATA_SUPPLY, psp22::STORAGE_KEY_DATA_BALANCES]
+    }
+
+    impl PSP22Facet {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn init_psp22(&mut self) -> Result<(), PSP22Error> {
+            psp22::Internal::_mint_to(self, Self::env().caller(), 1000)
+        }
+
+        #[ink(message, selector = 0xCAFEBABE)]
+        pub fn custom_selector(&self) {}
+    }
+
+    #[ink::test]
+    fn facet_info_contains_all_messages() {
+        let facet = PSP22Facet::new();
+        let selectors = DiamondFacet::facet_info(&facet).selectors;
+
+        assert!(selectors.contains(&ink::selector_bytes!("PSP22::transfer")));
+        assert!(selectors.contains(&ink::selector_bytes!("PSP22::balance_of")));
+        assert!(selectors.contains(&ink::selector_bytes!("Ownable::transfer_ownership")));
+        assert!(selectors.contains(&ink::selector_bytes!("DiamondFacet::facet_info")));
+        assert!(selectors.contains(&ink::selector_bytes!("init_psp22")));
+        assert!(selectors.contains(&[0xCA, 0xFE, 0xBA, 0xBE]));
+        assert!(!selectors.contains(&ink::selector_bytes!("custom_selector")));
+    }
+
+    #[ink::test]
+    fn facet_info_selectors_are_dispatchable() {
+        let facet = PSP22Facet::new();
+        let selectors = DiamondFacet::facet_info(&facet).selectors;
+
+        // PSP22 and Ownable messages + 2 inherent messages + `facet_info`
+        assert_eq!(selectors.len(), 14);
+        for selector in selectors {
+            let result =
+                <<PSP22Facet as ContractMessageDecoder>::Type as DecodeDispatch>::decode_dispatch(&mut &selector[..]);
+            assert!(!matches!(result, Err(DispatchError::UnknownSelector)));
+        }
+    }
+
+    #[ink::test]
+    fn facet_info_contains_storage_keys() {
+        let facet = PSP22Facet::new();
+
+        assert_eq!(
+            DiamondFacet::facet_info(&facet).storage_keys,
+            vec![psp22::STORAGE_KEY_DATA_SUPPLY, psp22::STORAGE_KEY_DATA_BALANCES]
+        );
+    }
+}
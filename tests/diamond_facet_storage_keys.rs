@@ -0,0 +1,70 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "diamond", feature = "ownable"))]
+#[openbrush::implementation(PSP22, Ownable, DiamondFacet)]
+#[openbrush::contract]
+mod diamond_facet_storage_keys {
+    use openbrush::traits::Storage;
+
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item]
+    pub struct Counters {
+        #[lazy]
+        pub total: u32,
+        pub last: u32,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Facet {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        counters: Counters,
+        not_storage_field: u32,
+    }
+
+    impl PSP22Facet {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[ink::test]
+    fn facet_info_contains_storage_keys_of_storage_fields() {
+        let facet = PSP22Facet::new();
+
+        assert_eq!(
+            DiamondFacet::facet_info(&facet).storage_keys,
+            vec![
+                psp22::STORAGE_KEY_DATA_SUPPLY,
+                psp22::STORAGE_KEY_DATA_BALANCES,
+                psp22::STORAGE_KEY_DATA_ALLOWANCES,
+                ownable::STORAGE_KEY_DATA_OWNER,
+                STORAGE_KEY_COUNTERS_TOTAL,
+            ]
+        );
+    }
+}
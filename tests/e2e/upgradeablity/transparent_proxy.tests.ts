@@ -0,0 +1,96 @@
+import { expect, getSelectorByName, getSigners } from '../helpers'
+import { ApiPromise } from '@polkadot/api'
+
+import ContractProxy from '../../../typechain-generated/contracts/my_transparent_proxy'
+import ConstructorProxy from '../../../typechain-generated/constructors/my_transparent_proxy'
+
+import ContractCounterV1 from '../../../typechain-generated/contracts/my_counter_v1'
+import ConstructorCounterV1 from '../../../typechain-generated/constructors/my_counter_v1'
+
+import ContractCounterV2 from '../../../typechain-generated/contracts/my_counter_v2'
+import ConstructorCounterV2 from '../../../typechain-generated/constructors/my_counter_v2'
+
+describe('MY_TRANSPARENT_PROXY', () => {
+  async function setup() {
+    const api = await ApiPromise.create()
+
+    const signers = getSigners()
+    const admin = signers[2]
+    const bob = signers[1]
+
+    const counterV1 = new ContractCounterV1((await new ConstructorCounterV1(api, admin).new()).address, admin, api)
+    const counterV2 = new ContractCounterV2((await new ConstructorCounterV2(api, admin).new()).address, admin, api)
+
+    const hashV1 = counterV1.abi.info.source.wasmHash.toString()
+    const hashV2 = counterV2.abi.info.source.wasmHash.toString()
+
+    const proxy = new ContractProxy((await new ConstructorProxy(api, admin).new(hashV1)).address, admin, api)
+
+    return {
+      api,
+      admin,
+      bob,
+      proxy,
+      counterV1,
+      counterV2,
+      hashV1,
+      hashV2,
+      close: async () => {
+        await api.disconnect()
+      }
+    }
+  }
+
+  function setupProxy<T>(contract: T, proxyAddress: string): T {
+    // eslint-disable-next-line @typescript-eslint/ban-ts-comment
+    // @ts-ignore
+    // TODO: Add interface for Contract
+    return contract.withAddress(proxyAddress)
+  }
+
+  it('MY_TRANSPARENT_PROXY - calls of not admin are forwarded to the implementation', async () => {
+    const { proxy, counterV1, bob, hashV1, close } = await setup()
+
+    await expect(proxy.query.proxyImplementation()).to.have.output(hashV1)
+
+    const counter = setupProxy(counterV1, proxy.address).withSigner(bob)
+    await counter.tx.set(7)
+    await expect(counter.query.get()).to.have.output(7)
+
+    await close()
+  })
+
+  it('MY_TRANSPARENT_PROXY - upgrade_to_and_call runs the initializer of the new code', async () => {
+    const { proxy, counterV1, counterV2, bob, hashV2, close } = await setup()
+
+    await setupProxy(counterV1, proxy.address).withSigner(bob).tx.set(7)
+
+    const init = getSelectorByName(counterV2.abi.messages, 'initialize_v2')
+    await proxy.tx.upgradeToAndCall(hashV2, { hash: hashV2, selector: init, input: [] })
+
+    await expect(proxy.query.proxyImplementation()).to.have.output(hashV2)
+
+    // the initializer of v2 was executed on the storage of the proxy
+    const counter = setupProxy(counterV2, proxy.address).withSigner(bob)
+    await expect(counter.query.initialized()).to.have.output(true)
+    await expect(counter.query.get()).to.have.output(70)
+
+    // the initializer is not executed on the storage of the implementation
+    await expect(counterV2.query.initialized()).to.have.output(false)
+
+    await close()
+  })
+
+  it('MY_TRANSPARENT_PROXY - only admin can upgrade', async () => {
+    const { proxy, counterV1, counterV2, bob, hashV1, hashV2, close } = await setup()
+
+    const init = getSelectorByName(counterV2.abi.messages, 'initialize_v2')
+    // the call of not admin is forwarded to v1, which doesn't have such message
+    await expect(proxy.withSigner(bob).tx.upgradeToAndCall(hashV2, { hash: hashV2, selector: init, input: [] })).to.eventually.be.rejected
+
+    await expect(proxy.query.proxyImplementation()).to.have.output(hashV1)
+    await expect(setupProxy(counterV1, proxy.address).withSigner(bob).query.get()).to.have.output(0)
+
+    await close()
+  })
+})
@@ -0,0 +1,109 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+#[cfg(feature = "psp22")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum CustomEnvironment {}
+
+#[cfg(feature = "psp22")]
+impl ink::env::Environment for CustomEnvironment {
+    const MAX_EVENT_TOPICS: usize = 3;
+
+    type AccountId = <ink::env::DefaultEnvironment as ink::env::Environment>::AccountId;
+    type Balance = <ink::env::DefaultEnvironment as ink::env::Environment>::Balance;
+    type Hash = <ink::env::DefaultEnvironment as ink::env::Environment>::Hash;
+    type BlockNumber = <ink::env::DefaultEnvironment as ink::env::Environment>::BlockNumber;
+    type Timestamp = <ink::env::DefaultEnvironment as ink::env::Environment>::Timestamp;
+
+    type ChainExtension = ink::env::NoChainExtension;
+}
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22)]
+#[openbrush::contract(env = crate::CustomEnvironment)]
+mod environment {
+    use ink::env::call::{
+        utils::Set,
+        Call,
+        CallBuilder,
+    };
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            CallOptions,
+            DefaultEnv,
+            Storage,
+        },
+    };
+
+    #[openbrush::wrapper]
+    pub type CustomPSP22Ref = dyn PSP22;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct CustomEnvContract {
+        #[storage_field]
+        psp22: psp22::Data,
+    }
+
+    impl CustomEnvContract {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            psp22::Internal::_mint_to(&mut instance, <Self as DefaultEnv>::env().caller(), total_supply)
+                .expect("Should mint");
+            instance
+        }
+
+        #[ink(message)]
+        pub fn block_timestamp(&self) -> Timestamp {
+            <Self as DefaultEnv>::env().block_timestamp()
+        }
+    }
+
+    #[ink::test]
+    fn modules_work_with_compatible_environment() {
+        let accounts = accounts();
+        let mut instance = CustomEnvContract::new(100);
+
+        assert!(PSP22::transfer(&mut instance, accounts.bob, 10, Vec::<u8>::new()).is_ok());
+        assert_eq!(PSP22::balance_of(&instance, accounts.alice), 90);
+        assert_eq!(PSP22::balance_of(&instance, accounts.bob), 10);
+        assert_eq!(instance.block_timestamp(), 0);
+    }
+
+    #[ink::test]
+    fn wrapper_uses_environment_of_contract() {
+        let accounts = accounts();
+
+        let builder: CallBuilder<crate::CustomEnvironment, Set<Call<crate::CustomEnvironment>>, _, _> =
+            CallOptions::new()
+                .gas_limit(1_000)
+                .apply(CustomPSP22Ref::transfer_builder(
+                    &accounts.bob,
+                    accounts.charlie,
+                    100,
+                    Vec::new(),
+                ));
+
+        assert_eq!(builder.params().gas_limit(), 1_000);
+    }
+}
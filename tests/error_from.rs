@@ -0,0 +1,177 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::implementation(PSP22, Ownable, no_events)]
+#[openbrush::contract]
+mod error_from {
+    use openbrush::{
+        contracts::traits::errors::{
+            AccessControlError,
+            DiamondError,
+            FlashLenderError,
+            GovernanceError,
+            InitializableError,
+            MultisigError,
+            PausableError,
+            ProxyError,
+            ReentrancyGuardError,
+            UpgradeableError,
+        },
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            ErrorFrom,
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode, ErrorFrom)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum TokenError {
+        #[from]
+        Ownable(OwnableError),
+        #[from]
+        PSP22(PSP22Error),
+        Custom(String),
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MyToken {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl MyToken {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+            instance
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(only_owner)]
+        pub fn burn(&mut self, from: AccountId, amount: Balance) -> Result<(), TokenError> {
+            psp22::Internal::_burn_from(self, from, amount)?;
+            Ok(())
+        }
+    }
+
+    #[ink::test]
+    fn absorbs_module_errors() {
+        let accounts = accounts();
+        let mut token = MyToken::new();
+
+        assert_eq!(
+            token.burn(accounts.alice, 10),
+            Err(TokenError::PSP22(PSP22Error::InsufficientBalance))
+        );
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            token.burn(accounts.alice, 10),
+            Err(TokenError::Ownable(OwnableError::CallerIsNotOwner))
+        );
+    }
+
+    #[ink::test]
+    fn module_errors_are_nested() {
+        assert_eq!(
+            PSP22Error::from(OwnableError::CallerIsNotOwner),
+            PSP22Error::Ownable(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(
+            PSP22Error::from(PausableError::Paused),
+            PSP22Error::Pausable(PausableError::Paused)
+        );
+        assert_eq!(
+            GovernanceError::from(ReentrancyGuardError::ReentrantCall),
+            GovernanceError::ReentrancyGuard(ReentrancyGuardError::ReentrantCall)
+        );
+        assert_eq!(
+            MultisigError::from(AccessControlError::MissingRole),
+            MultisigError::AccessControl(AccessControlError::MissingRole)
+        );
+        assert_eq!(
+            ProxyError::from(OwnableError::CallerIsNotOwner),
+            ProxyError::Ownable(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(
+            DiamondError::from(OwnableError::CallerIsNotOwner),
+            DiamondError::Ownable(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(
+            UpgradeableError::from(AccessControlError::MissingRole),
+            UpgradeableError::AccessControl(AccessControlError::MissingRole)
+        );
+    }
+
+    #[ink::test]
+    fn call_errors_are_nested_in_governance_error() {
+        assert_eq!(
+            GovernanceError::from(ink::LangError::CouldNotReadInput),
+            GovernanceError::LangError(ink::LangError::CouldNotReadInput)
+        );
+        assert_eq!(
+            GovernanceError::from(ink::env::Error::CalleeReverted),
+            GovernanceError::CallFailed
+        );
+    }
+
+    #[ink::test]
+    fn call_errors_are_nested_in_psp22_error() {
+        assert_eq!(
+            PSP22Error::from(ink::LangError::CouldNotReadInput),
+            PSP22Error::LangError(ink::LangError::CouldNotReadInput)
+        );
+        assert_eq!(
+            PSP22Error::from(ink::env::Error::CalleeReverted),
+            PSP22Error::CallFailed
+        );
+    }
+
+    #[ink::test]
+    fn psp22_errors_are_nested_in_flash_lender_error() {
+        assert_eq!(
+            FlashLenderError::from(PSP22Error::InsufficientBalance),
+            FlashLenderError::PSP22(PSP22Error::InsufficientBalance)
+        );
+        assert_eq!(
+            FlashLenderError::from(PSP22Error::Ownable(OwnableError::CallerIsNotOwner)),
+            FlashLenderError::PSP22(PSP22Error::Ownable(OwnableError::CallerIsNotOwner))
+        );
+        assert_eq!(
+            FlashLenderError::from(PSP22Error::Custom(String::from("Custom"))),
+            FlashLenderError::PSP22(PSP22Error::Custom(String::from("Custom")))
+        );
+        assert_eq!(
+            FlashLenderError::from(InitializableError::InvalidInitialization),
+            FlashLenderError::Initializable(InitializableError::InvalidInitialization)
+        );
+    }
+}
@@ -0,0 +1,218 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "governance")]
+#[openbrush::implementation(
+    Governor,
+    GovernorSettings,
+    GovernorQuorum,
+    GovernorVotes,
+    GovernorCountingFractional
+)]
+#[openbrush::contract]
+mod governor_counting_fractional {
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+    use scale::Encode;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        governor: governor::Data,
+        #[storage_field]
+        governor_counting: governor_counting::Data,
+        #[storage_field]
+        governor_counting_fractional: governor_counting_fractional::Data,
+        #[storage_field]
+        governor_votes: governor_votes::Data,
+        #[storage_field]
+        settings: governor_settings::Data,
+        #[storage_field]
+        quorum: governor_quorum::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    impl TimestampProvider for Contract {}
+
+    const PROPOSAL_ID: ProposalId = [1; 32];
+    const WEIGHT: Balance = 100;
+
+    fn split(against_votes: Balance, for_votes: Balance, abstain_votes: Balance) -> Vec<u8> {
+        ProposalVote {
+            against_votes,
+            for_votes,
+            abstain_votes,
+        }
+        .encode()
+    }
+
+    #[ink::test]
+    fn nominal_vote_uses_all_votes() {
+        let accounts = accounts();
+        let mut governor = Contract::new();
+
+        assert_eq!(
+            CountingInternal::_count_vote(
+                &mut governor,
+                PROPOSAL_ID,
+                accounts.alice,
+                VoteType::For,
+                WEIGHT,
+                Vec::new()
+            ),
+            Ok(())
+        );
+
+        assert!(GovernorCounting::has_voted(&governor, PROPOSAL_ID, accounts.alice));
+        assert_eq!(
+            GovernorCountingFractional::used_votes(&governor, PROPOSAL_ID, accounts.alice),
+            WEIGHT
+        );
+        assert_eq!(
+            GovernorCounting::proposal_votes(&governor, PROPOSAL_ID),
+            Ok(ProposalVote {
+                against_votes: 0,
+                for_votes: WEIGHT,
+                abstain_votes: 0,
+            })
+        );
+        assert_eq!(
+            CountingInternal::_count_vote(
+                &mut governor,
+                PROPOSAL_ID,
+                accounts.alice,
+                VoteType::Against,
+                WEIGHT,
+                Vec::new()
+            ),
+            Err(GovernanceError::AlreadyCastVote)
+        );
+    }
+
+    #[ink::test]
+    fn fractional_votes_can_be_split_across_several_votes() {
+        let accounts = accounts();
+        let mut governor = Contract::new();
+
+        assert_eq!(
+            CountingInternal::_count_vote(
+                &mut governor,
+                PROPOSAL_ID,
+                accounts.alice,
+                VoteType::For,
+                WEIGHT,
+                split(10, 20, 30)
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            GovernorCountingFractional::used_votes(&governor, PROPOSAL_ID, accounts.alice),
+            60
+        );
+
+        // The remaining votes are cast for the `support` side
+        assert_eq!(
+            CountingInternal::_count_vote(
+                &mut governor,
+                PROPOSAL_ID,
+                accounts.alice,
+                VoteType::Against,
+                WEIGHT,
+                Vec::new()
+            ),
+            Ok(())
+        );
+
+        assert_eq!(
+            GovernorCountingFractional::used_votes(&governor, PROPOSAL_ID, accounts.alice),
+            WEIGHT
+        );
+        assert_eq!(
+            GovernorCounting::proposal_votes(&governor, PROPOSAL_ID),
+            Ok(ProposalVote {
+                against_votes: 50,
+                for_votes: 20,
+                abstain_votes: 30,
+            })
+        );
+    }
+
+    #[ink::test]
+    fn fractional_vote_fails_if_exceeds_remaining_votes() {
+        let accounts = accounts();
+        let mut governor = Contract::new();
+
+        assert_eq!(
+            CountingInternal::_count_vote(
+                &mut governor,
+                PROPOSAL_ID,
+                accounts.alice,
+                VoteType::For,
+                WEIGHT,
+                split(50, 0, 0)
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            CountingInternal::_count_vote(
+                &mut governor,
+                PROPOSAL_ID,
+                accounts.alice,
+                VoteType::For,
+                WEIGHT,
+                split(0, 40, 11)
+            ),
+            Err(GovernanceError::ExceedsRemainingVotes)
+        );
+        assert_eq!(
+            GovernorCountingFractional::used_votes(&governor, PROPOSAL_ID, accounts.alice),
+            50
+        );
+    }
+
+    #[ink::test]
+    fn fractional_vote_fails_with_invalid_params() {
+        let accounts = accounts();
+        let mut governor = Contract::new();
+
+        assert_eq!(
+            CountingInternal::_count_vote(
+                &mut governor,
+                PROPOSAL_ID,
+                accounts.alice,
+                VoteType::For,
+                WEIGHT,
+                vec![1, 2, 3]
+            ),
+            Err(GovernanceError::InvalidVoteParams)
+        );
+        assert!(!GovernorCounting::has_voted(&governor, PROPOSAL_ID, accounts.alice));
+    }
+}
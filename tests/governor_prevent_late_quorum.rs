@@ -0,0 +1,199 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "governance")]
+#[openbrush::implementation(
+    Governor,
+    GovernorSettings,
+    GovernorQuorum,
+    GovernorVotes,
+    GovernorCounting,
+    GovernorPreventLateQuorum
+)]
+#[openbrush::contract]
+mod governor_prevent_late_quorum {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        governor: governor::Data,
+        #[storage_field]
+        governor_counting: governor_counting::Data,
+        #[storage_field]
+        governor_votes: governor_votes::Data,
+        #[storage_field]
+        settings: governor_settings::Data,
+        #[storage_field]
+        quorum: governor_quorum::Data,
+        #[storage_field]
+        prevent_late_quorum: governor_prevent_late_quorum::Data,
+        mock_timestamp: Timestamp,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(vote_extension: Timestamp) -> Self {
+            let mut instance = Self::default();
+
+            instance._init_governor_settings(0, 10, 0).unwrap();
+            instance._init_prevent_late_quorum(vote_extension).unwrap();
+
+            instance
+        }
+    }
+
+    type Event = <Contract as ::ink::reflect::ContractEventBase>::Type;
+
+    impl TimestampProvider for Contract {
+        fn block_timestamp(&self) -> Timestamp {
+            self.mock_timestamp
+        }
+    }
+
+    const PROPOSAL_ID: ProposalId = [1; 32];
+    const VOTE_EXTENSION: Timestamp = 5;
+
+    #[ink::test]
+    fn init_works() {
+        let governor = Contract::new(VOTE_EXTENSION);
+
+        assert_eq!(
+            GovernorPreventLateQuorum::late_quorum_vote_extension(&governor),
+            VOTE_EXTENSION
+        );
+    }
+
+    #[ink::test]
+    fn set_late_quorum_vote_extension_works_only_through_governance() {
+        let accounts = accounts();
+        let mut governor = Contract::new(VOTE_EXTENSION);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            GovernorPreventLateQuorum::set_late_quorum_vote_extension(&mut governor, 10),
+            Err(GovernanceError::OnlyExecutor)
+        );
+
+        change_caller(ink::env::account_id::<ink::env::DefaultEnvironment>());
+        assert_eq!(
+            GovernorPreventLateQuorum::set_late_quorum_vote_extension(&mut governor, 10),
+            Ok(())
+        );
+        assert_eq!(GovernorPreventLateQuorum::late_quorum_vote_extension(&governor), 10);
+    }
+
+    #[ink::test]
+    fn settings_events_are_emitted() {
+        let mut governor = Contract::new(VOTE_EXTENSION);
+
+        change_caller(ink::env::account_id::<ink::env::DefaultEnvironment>());
+        assert_eq!(
+            GovernorPreventLateQuorum::set_late_quorum_vote_extension(&mut governor, 10),
+            Ok(())
+        );
+
+        let events = ink::env::test::recorded_events()
+            .map(|event| {
+                <Event as scale::Decode>::decode(&mut &event.data[..])
+                    .expect("encountered invalid contract event data buffer")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(events.len(), 5);
+        assert!(matches!(
+            events[0],
+            Event::VotingDelaySet(VotingDelaySet {
+                old_voting_delay: None,
+                new_voting_delay: 0
+            })
+        ));
+        assert!(matches!(
+            events[1],
+            Event::VotingPeriodSet(VotingPeriodSet {
+                old_voting_period: None,
+                new_voting_period: 10
+            })
+        ));
+        assert!(matches!(
+            events[2],
+            Event::ProposalThresholdSet(ProposalThresholdSet {
+                old_proposal_threshold: None,
+                new_proposal_threshold: 0
+            })
+        ));
+        assert!(matches!(
+            events[3],
+            Event::LateQuorumVoteExtensionSet(LateQuorumVoteExtensionSet {
+                old_vote_extension: None,
+                new_vote_extension: VOTE_EXTENSION
+            })
+        ));
+        assert!(matches!(
+            events[4],
+            Event::LateQuorumVoteExtensionSet(LateQuorumVoteExtensionSet {
+                old_vote_extension: Some(VOTE_EXTENSION),
+                new_vote_extension: 10
+            })
+        ));
+    }
+
+    #[ink::test]
+    fn extended_deadline_is_used_by_proposal_deadline_and_state() {
+        let accounts = accounts();
+        let mut governor = Contract::new(VOTE_EXTENSION);
+
+        governor
+            .governor
+            .proposals
+            .insert(&PROPOSAL_ID, &ProposalCore::new(accounts.alice, 0, 10));
+        assert_eq!(Governor::proposal_deadline(&governor, PROPOSAL_ID), Ok(10));
+
+        governor
+            .prevent_late_quorum
+            .extended_deadlines
+            .insert(&PROPOSAL_ID, &15);
+        assert_eq!(Governor::proposal_deadline(&governor, PROPOSAL_ID), Ok(15));
+
+        governor.mock_timestamp = 12;
+        assert_eq!(Governor::state(&governor, PROPOSAL_ID), Ok(ProposalState::Active));
+    }
+
+    #[ink::test]
+    fn extended_deadline_does_not_shorten_voting_period() {
+        let accounts = accounts();
+        let mut governor = Contract::new(VOTE_EXTENSION);
+
+        governor
+            .governor
+            .proposals
+            .insert(&PROPOSAL_ID, &ProposalCore::new(accounts.alice, 0, 10));
+        governor.prevent_late_quorum.extended_deadlines.insert(&PROPOSAL_ID, &7);
+
+        assert_eq!(Governor::proposal_deadline(&governor, PROPOSAL_ID), Ok(10));
+    }
+}
@@ -0,0 +1,256 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "governance")]
+#[openbrush::implementation(Governor, GovernorSettings, GovernorVotes, GovernorCounting, GovernorTimelock)]
+#[openbrush::contract]
+mod governor_timelock {
+    use openbrush::{
+        contracts::governance::extensions::governor_quorum::{
+            self,
+            QuorumEvents,
+            QuorumImpl,
+        },
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    const MIN_DELAY: Timestamp = 100;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        governor: governor::Data,
+        #[storage_field]
+        governor_counting: governor_counting::Data,
+        #[storage_field]
+        governor_votes: governor_votes::Data,
+        #[storage_field]
+        settings: governor_settings::Data,
+        #[storage_field]
+        quorum: governor_quorum::Data,
+        #[storage_field]
+        governor_timelock: governor_timelock::Data,
+        eta: Timestamp,
+        executed_transactions: Vec<Transaction>,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new(timelock: AccountId) -> Self {
+            let mut instance = Self::default();
+
+            instance._init_governor_settings(0, 10, 0).unwrap();
+            GovernorTimelockInternal::_init_governor_timelock(&mut instance, timelock).unwrap();
+
+            instance
+        }
+    }
+
+    type Event = <Contract as ::ink::reflect::ContractEventBase>::Type;
+
+    impl TimestampProvider for Contract {}
+
+    // The quorum requires a cross-contract call to the votes token, so we use a zero quorum in tests
+    impl QuorumEvents for Contract {}
+
+    impl QuorumImpl for Contract {
+        fn quorum(&self, _timestamp: Timestamp) -> Result<u128, GovernanceError> {
+            Ok(0)
+        }
+    }
+
+    // We will mock the calls to the timelock, the timelock itself is covered by the timelock controller tests
+    #[overrider(GovernorTimelockInternal)]
+    fn _queue_operations(
+        &mut self,
+        proposal_id: ProposalId,
+        _transactions: Vec<Transaction>,
+        _description_hash: HashType,
+    ) -> Result<Timestamp, GovernanceError> {
+        self.eta = TimestampProvider::block_timestamp(self) + MIN_DELAY;
+        self.emit_proposal_queued(proposal_id, self.eta);
+        Ok(self.eta)
+    }
+
+    #[overrider(GovernorTimelockInternal)]
+    fn _timelock_eta(&self, _proposal_id: ProposalId) -> Timestamp {
+        self.eta
+    }
+
+    #[overrider(GovernorTimelockInternal)]
+    fn _execute_operations(
+        &mut self,
+        transactions: Vec<Transaction>,
+        _description_hash: HashType,
+    ) -> Result<(), GovernanceError> {
+        if self.eta == 0 || TimestampProvider::block_timestamp(self) < self.eta {
+            return Err(GovernanceError::ExecutionFailed)
+        }
+
+        self.eta = 0;
+        self.executed_transactions = transactions;
+        Ok(())
+    }
+
+    fn succeeded_proposal(governor: &mut Contract, transactions: Vec<Transaction>) -> ProposalId {
+        let accounts = accounts();
+        let proposal_id = GovernorImpl::hash_proposal(governor, transactions, [0; 32]).unwrap();
+
+        governor
+            .governor
+            .proposals
+            .insert(&proposal_id, &ProposalCore::new(accounts.alice, 0, 10));
+        CountingInternal::_count_vote(governor, proposal_id, accounts.alice, VoteType::For, 100, vec![]).unwrap();
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(11);
+
+        assert_eq!(GovernorImpl::state(governor, proposal_id), Ok(ProposalState::Succeeded));
+        proposal_id
+    }
+
+    #[ink::test]
+    fn init_sets_timelock_as_executor() {
+        let accounts = accounts();
+        let governor = Contract::new(accounts.bob);
+
+        assert_eq!(GovernorTimelock::timelock(&governor), Some(accounts.bob));
+        assert_eq!(GovernorInternal::_executor(&governor), accounts.bob);
+    }
+
+    #[ink::test]
+    fn update_timelock_works_only_from_timelock() {
+        let accounts = accounts();
+        let mut governor = Contract::new(accounts.bob);
+
+        change_caller(accounts.alice);
+        assert_eq!(
+            GovernorTimelock::update_timelock(&mut governor, accounts.charlie),
+            Err(GovernanceError::OnlyExecutor)
+        );
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            GovernorTimelock::update_timelock(&mut governor, accounts.charlie),
+            Ok(())
+        );
+        assert_eq!(GovernorTimelock::timelock(&governor), Some(accounts.charlie));
+    }
+
+    #[ink::test]
+    fn only_governance_accepts_timelock() {
+        let accounts = accounts();
+        let mut governor = Contract::new(accounts.bob);
+
+        change_caller(accounts.alice);
+        assert_eq!(
+            GovernorSettings::set_voting_delay(&mut governor, 5),
+            Err(GovernanceError::OnlyExecutor)
+        );
+
+        change_caller(accounts.bob);
+        assert_eq!(GovernorSettings::set_voting_delay(&mut governor, 5), Ok(()));
+        assert_eq!(GovernorSettings::voting_delay(&governor), 5);
+    }
+
+    #[ink::test]
+    fn queue_fails_for_nonexistent_proposal() {
+        let accounts = accounts();
+        let mut governor = Contract::new(accounts.bob);
+        let transactions = vec![Transaction::default()];
+        let proposal_id = GovernorImpl::hash_proposal(&governor, transactions.clone(), [0; 32]).unwrap();
+
+        assert_eq!(GovernorTimelock::proposal_eta(&governor, proposal_id), 0);
+        assert_eq!(
+            GovernorTimelock::queue(&mut governor, transactions, [0; 32]),
+            Err(GovernanceError::NonexistentProposal)
+        );
+    }
+
+    #[ink::test]
+    fn queue_and_execute_through_timelock_works() {
+        let accounts = accounts();
+        let mut governor = Contract::new(accounts.bob);
+        let transactions = vec![Transaction {
+            callee: Some(accounts.charlie),
+            ..Default::default()
+        }];
+        let proposal_id = succeeded_proposal(&mut governor, transactions.clone());
+
+        assert_eq!(
+            GovernorTimelock::queue(&mut governor, transactions.clone(), [0; 32]),
+            Ok(proposal_id)
+        );
+        assert_eq!(GovernorImpl::state(&governor, proposal_id), Ok(ProposalState::Queued));
+        assert_eq!(GovernorTimelock::proposal_eta(&governor, proposal_id), 11 + MIN_DELAY);
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(11 + MIN_DELAY);
+        assert_eq!(
+            GovernorImpl::execute(&mut governor, transactions.clone(), [0; 32]),
+            Ok(proposal_id)
+        );
+        assert_eq!(GovernorImpl::state(&governor, proposal_id), Ok(ProposalState::Executed));
+        assert_eq!(governor.executed_transactions, transactions);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let events = emitted_events[emitted_events.len() - 2..]
+            .iter()
+            .map(|event| {
+                <Event as scale::Decode>::decode(&mut &event.data[..])
+                    .expect("encountered invalid contract event data buffer")
+            })
+            .collect::<Vec<_>>();
+        assert!(matches!(
+            &events[0],
+            Event::ProposalQueued(ProposalQueued { proposal_id: id, eta }) if *id == proposal_id && *eta == 11 + MIN_DELAY
+        ));
+        assert!(matches!(
+            &events[1],
+            Event::ProposalExecuted(ProposalExecuted { proposal_id: id }) if *id == proposal_id
+        ));
+    }
+
+    #[ink::test]
+    fn execute_fails_before_eta() {
+        let accounts = accounts();
+        let mut governor = Contract::new(accounts.bob);
+        let transactions = vec![Transaction {
+            callee: Some(accounts.charlie),
+            ..Default::default()
+        }];
+        let proposal_id = succeeded_proposal(&mut governor, transactions.clone());
+
+        assert_eq!(
+            GovernorTimelock::queue(&mut governor, transactions.clone(), [0; 32]),
+            Ok(proposal_id)
+        );
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10 + MIN_DELAY);
+        assert_eq!(
+            GovernorImpl::execute(&mut governor, transactions, [0; 32]),
+            Err(GovernanceError::ExecutionFailed)
+        );
+        assert!(governor.executed_transactions.is_empty());
+    }
+}
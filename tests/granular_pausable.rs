@@ -0,0 +1,201 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "pausable")]
+#[cfg(feature = "access_control")]
+#[openbrush::implementation(AccessControl, GranularPausable)]
+#[openbrush::contract]
+mod granular_pausable {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        modifiers,
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    const MINT: Feature = ink::selector_id!("MINT");
+    const TRANSFER: Feature = ink::selector_id!("TRANSFER");
+
+    /// Emitted when the pause of `feature` is triggered by `account`.
+    #[ink(event)]
+    pub struct FeaturePaused {
+        pub feature: Feature,
+        pub account: AccountId,
+    }
+
+    /// Emitted when the pause of `feature` is lifted by `account`.
+    #[ink(event)]
+    pub struct FeatureUnpaused {
+        pub feature: Feature,
+        pub account: AccountId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MyToken {
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        pause: granular_pausable::Data,
+        minted: u32,
+        transferred: u32,
+    }
+
+    type Event = <MyToken as ::ink::reflect::ContractEventBase>::Type;
+
+    #[overrider(granular_pausable::Internal)]
+    fn _emit_feature_paused_event(&self, feature: Feature, account: AccountId) {
+        self.env().emit_event(FeaturePaused { feature, account })
+    }
+
+    #[overrider(granular_pausable::Internal)]
+    fn _emit_feature_unpaused_event(&self, feature: Feature, account: AccountId) {
+        self.env().emit_event(FeatureUnpaused { feature, account })
+    }
+
+    impl MyToken {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            let caller = Self::env().caller();
+            access_control::Internal::_init_with_admin(&mut instance, Some(caller));
+            AccessControl::grant_role(&mut instance, PAUSER_ROLE, Some(caller)).expect("Should grant PAUSER_ROLE");
+            instance
+        }
+
+        #[ink(message)]
+        #[modifiers(when_not_paused_feature(MINT))]
+        pub fn mint(&mut self) -> Result<(), PausableError> {
+            self.minted += 1;
+            Ok(())
+        }
+
+        #[ink(message)]
+        #[modifiers(when_not_paused_feature(TRANSFER))]
+        pub fn transfer(&mut self) -> Result<(), PausableError> {
+            self.transferred += 1;
+            Ok(())
+        }
+    }
+
+    fn assert_feature_paused_event(
+        event: &ink::env::test::EmittedEvent,
+        expected_feature: Feature,
+        expected_account: AccountId,
+    ) {
+        if let Event::FeaturePaused(FeaturePaused { feature, account }) =
+            <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(feature, expected_feature);
+            assert_eq!(account, expected_account);
+        } else {
+            panic!("encountered unexpected event kind: expected a FeaturePaused event")
+        }
+    }
+
+    fn assert_feature_unpaused_event(
+        event: &ink::env::test::EmittedEvent,
+        expected_feature: Feature,
+        expected_account: AccountId,
+    ) {
+        if let Event::FeatureUnpaused(FeatureUnpaused { feature, account }) =
+            <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(feature, expected_feature);
+            assert_eq!(account, expected_account);
+        } else {
+            panic!("encountered unexpected event kind: expected a FeatureUnpaused event")
+        }
+    }
+
+    #[ink::test]
+    fn pause_feature_blocks_only_that_feature() {
+        let accounts = accounts();
+        let mut inst = MyToken::new();
+
+        assert!(GranularPausable::pause_feature(&mut inst, MINT).is_ok());
+        assert!(GranularPausable::feature_paused(&inst, MINT));
+        assert!(!GranularPausable::feature_paused(&inst, TRANSFER));
+
+        assert_eq!(inst.mint(), Err(PausableError::Paused));
+        assert!(inst.transfer().is_ok());
+        assert_eq!(inst.minted, 0);
+        assert_eq!(inst.transferred, 1);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_feature_paused_event(emitted_events.last().unwrap(), MINT, accounts.alice);
+    }
+
+    #[ink::test]
+    fn unpause_feature_works() {
+        let accounts = accounts();
+        let mut inst = MyToken::new();
+
+        assert!(GranularPausable::pause_feature(&mut inst, MINT).is_ok());
+        assert!(GranularPausable::unpause_feature(&mut inst, MINT).is_ok());
+        assert!(!GranularPausable::feature_paused(&inst, MINT));
+        assert!(inst.mint().is_ok());
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_feature_unpaused_event(emitted_events.last().unwrap(), MINT, accounts.alice);
+    }
+
+    #[ink::test]
+    fn pause_feature_twice_fails() {
+        let mut inst = MyToken::new();
+
+        assert!(GranularPausable::pause_feature(&mut inst, MINT).is_ok());
+        assert_eq!(
+            GranularPausable::pause_feature(&mut inst, MINT),
+            Err(GranularPausableError::Pausable(PausableError::Paused))
+        );
+        assert_eq!(
+            GranularPausable::unpause_feature(&mut inst, TRANSFER),
+            Err(GranularPausableError::Pausable(PausableError::NotPaused))
+        );
+    }
+
+    #[ink::test]
+    fn pause_feature_requires_pauser_role() {
+        let accounts = accounts();
+        let mut inst = MyToken::new();
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            GranularPausable::pause_feature(&mut inst, MINT),
+            Err(GranularPausableError::AccessControl(AccessControlError::MissingRole))
+        );
+
+        change_caller(accounts.alice);
+        assert!(AccessControl::grant_role(&mut inst, PAUSER_ROLE, Some(accounts.bob)).is_ok());
+
+        change_caller(accounts.bob);
+        assert!(GranularPausable::pause_feature(&mut inst, MINT).is_ok());
+    }
+}
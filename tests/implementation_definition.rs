@@ -0,0 +1,154 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+pub mod counter {
+    use openbrush::traits::Storage;
+
+    #[openbrush::trait_definition]
+    pub trait Counter {
+        #[ink(message)]
+        fn get(&self) -> u32;
+
+        #[ink(message)]
+        fn increment(&mut self);
+    }
+
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item]
+    pub struct Data {
+        #[lazy]
+        pub value: u32,
+    }
+
+    pub trait Internal {
+        fn _emit_incremented_event(&self, value: u32);
+    }
+
+    pub trait InternalImpl: Internal {
+        fn _emit_incremented_event(&self, _value: u32) {}
+    }
+
+    pub trait CounterImpl: Storage<Data> + Internal {
+        fn get(&self) -> u32 {
+            self.data().value.get_or_default()
+        }
+
+        fn increment(&mut self) {
+            let value = self.data().value.get_or_default() + 1;
+            self.data().value.set(&value);
+            Internal::_emit_incremented_event(self, value);
+        }
+    }
+}
+
+#[openbrush::implementation_definition(Counter)]
+mod counter_implementation {
+    use crate::counter::{
+        self,
+        *,
+    };
+
+    type Data = counter::Data;
+
+    impl counter::InternalImpl for Contract {}
+
+    impl counter::Internal for Contract {
+        fn _emit_incremented_event(&self, value: u32) {
+            counter::InternalImpl::_emit_incremented_event(self, value)
+        }
+    }
+
+    impl CounterImpl for Contract {}
+
+    impl Counter for Contract {
+        #[ink(message)]
+        fn get(&self) -> u32 {
+            CounterImpl::get(self)
+        }
+
+        #[ink(message)]
+        fn increment(&mut self) {
+            CounterImpl::increment(self)
+        }
+    }
+}
+
+#[openbrush::implementation(Counter)]
+#[openbrush::contract]
+mod implementation_definition {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::traits::Storage;
+
+    #[ink(event)]
+    pub struct Incremented {
+        value: u32,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct CounterContract {
+        #[storage_field]
+        counter: counter::Data,
+        offset: u32,
+    }
+
+    #[overrider(counter::Internal)]
+    fn _emit_incremented_event(&self, value: u32) {
+        self.env().emit_event(Incremented { value });
+    }
+
+    #[overrider(Counter)]
+    fn get(&self) -> u32 {
+        CounterImpl::get(self) + self.offset
+    }
+
+    impl CounterContract {
+        #[ink(constructor)]
+        pub fn new(offset: u32) -> Self {
+            Self {
+                offset,
+                ..Default::default()
+            }
+        }
+    }
+
+    #[ink::test]
+    fn registered_implementation_works() {
+        let mut instance = CounterContract::new(0);
+
+        Counter::increment(&mut instance);
+        Counter::increment(&mut instance);
+
+        assert_eq!(CounterImpl::get(&instance), 2);
+    }
+
+    #[ink::test]
+    fn registered_implementation_can_be_overridden() {
+        let mut instance = CounterContract::new(100);
+
+        Counter::increment(&mut instance);
+
+        assert_eq!(Counter::get(&instance), 101);
+        assert_eq!(ink::env::test::recorded_events().count(), 1);
+    }
+}
@@ -0,0 +1,168 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "initializable")]
+#[openbrush::implementation(Initializable)]
+#[openbrush::contract]
+mod initializable {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        modifiers,
+        traits::Storage,
+    };
+
+    /// Emitted when the contract is initialized with the `version`.
+    #[ink(event)]
+    pub struct Initialized {
+        pub version: u64,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MyContract {
+        #[storage_field]
+        initializable: Data,
+        value: u32,
+        extra: u32,
+    }
+
+    type Event = <MyContract as ::ink::reflect::ContractEventBase>::Type;
+
+    #[overrider(initializable::Internal)]
+    fn _emit_initialized_event(&self, version: u64) {
+        self.env().emit_event(Initialized { version })
+    }
+
+    impl MyContract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        #[modifiers(initializer)]
+        pub fn init(&mut self, value: u32) -> Result<(), InitializableError> {
+            self.value = value;
+            self.init_extra(value)
+        }
+
+        #[ink(message)]
+        #[modifiers(initializer)]
+        pub fn init_fails(&mut self) -> Result<(), InitializableError> {
+            Err(InitializableError::NotInitializing)
+        }
+
+        #[ink(message)]
+        #[modifiers(reinitializer(2))]
+        pub fn init_v2(&mut self, extra: u32) -> Result<(), InitializableError> {
+            self.init_extra(extra)
+        }
+
+        #[ink(message)]
+        #[modifiers(only_initializing)]
+        pub fn init_extra(&mut self, extra: u32) -> Result<(), InitializableError> {
+            self.extra = extra;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn disable(&mut self) -> Result<(), InitializableError> {
+            initializable::Internal::_disable_initializers(self)
+        }
+    }
+
+    fn assert_initialized_event(event: &ink::env::test::EmittedEvent, expected_version: u64) {
+        let Event::Initialized(Initialized { version }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer");
+        assert_eq!(version, expected_version, "Versions were not equal");
+    }
+
+    #[ink::test]
+    fn initializer_works() {
+        let mut instance = MyContract::new();
+
+        assert_eq!(Initializable::initialized_version(&instance), 0);
+        assert_eq!(instance.init(5), Ok(()));
+        assert_eq!(instance.value, 5);
+        assert_eq!(instance.extra, 5);
+        assert_eq!(Initializable::initialized_version(&instance), 1);
+        assert!(!initializable::Internal::_is_initializing(&instance));
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 1);
+        assert_initialized_event(&emitted_events[0], 1);
+    }
+
+    #[ink::test]
+    fn initializer_called_twice_fails() {
+        let mut instance = MyContract::new();
+
+        assert_eq!(instance.init(5), Ok(()));
+        assert_eq!(instance.init(6), Err(InitializableError::InvalidInitialization));
+        assert_eq!(instance.value, 5);
+    }
+
+    #[ink::test]
+    fn failed_initializer_can_be_retried() {
+        let mut instance = MyContract::new();
+
+        assert_eq!(instance.init_fails(), Err(InitializableError::NotInitializing));
+        assert_eq!(Initializable::initialized_version(&instance), 0);
+        assert_eq!(instance.init(5), Ok(()));
+    }
+
+    #[ink::test]
+    fn reinitializer_works() {
+        let mut instance = MyContract::new();
+
+        assert_eq!(instance.init(5), Ok(()));
+        assert_eq!(instance.init_v2(7), Ok(()));
+        assert_eq!(instance.extra, 7);
+        assert_eq!(Initializable::initialized_version(&instance), 2);
+        assert_eq!(instance.init_v2(8), Err(InitializableError::InvalidInitialization));
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_initialized_event(&emitted_events[1], 2);
+    }
+
+    #[ink::test]
+    fn only_initializing_fails_outside_of_initialization() {
+        let mut instance = MyContract::new();
+
+        assert_eq!(instance.init_extra(1), Err(InitializableError::NotInitializing));
+    }
+
+    #[ink::test]
+    fn disable_initializers_works() {
+        let mut instance = MyContract::new();
+
+        assert_eq!(instance.disable(), Ok(()));
+        assert_eq!(Initializable::initialized_version(&instance), u64::MAX);
+        assert_eq!(instance.init(5), Err(InitializableError::InvalidInitialization));
+        assert_eq!(instance.init_v2(5), Err(InitializableError::InvalidInitialization));
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_initialized_event(&emitted_events[0], u64::MAX);
+    }
+}
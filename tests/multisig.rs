@@ -0,0 +1,336 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "multisig")]
+#[openbrush::implementation(Multisig)]
+#[openbrush::contract]
+mod multisig {
+    use ::ink::env::DefaultEnvironment;
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    /// Emitted when an owner is added.
+    #[ink(event)]
+    pub struct OwnerAdded {
+        #[ink(topic)]
+        pub owner: AccountId,
+    }
+
+    /// Emitted when an owner is removed.
+    #[ink(event)]
+    pub struct OwnerRemoved {
+        #[ink(topic)]
+        pub owner: AccountId,
+    }
+
+    /// Emitted when the threshold is changed.
+    #[ink(event)]
+    pub struct ThresholdChanged {
+        pub old_threshold: u32,
+        pub new_threshold: u32,
+    }
+
+    /// Emitted when `owner` approves transaction `id`.
+    #[ink(event)]
+    pub struct TransactionApproved {
+        #[ink(topic)]
+        pub id: TransactionId,
+        #[ink(topic)]
+        pub owner: AccountId,
+    }
+
+    /// Emitted when `owner` revokes the approval of transaction `id`.
+    #[ink(event)]
+    pub struct ApprovalRevoked {
+        #[ink(topic)]
+        pub id: TransactionId,
+        #[ink(topic)]
+        pub owner: AccountId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MultisigStruct {
+        #[storage_field]
+        multisig: multisig::Data,
+    }
+
+    type Event = <MultisigStruct as ::ink::reflect::ContractEventBase>::Type;
+
+    #[overrider(multisig::Internal)]
+    fn _emit_owner_added_event(&self, owner: AccountId) {
+        self.env().emit_event(OwnerAdded { owner })
+    }
+
+    #[overrider(multisig::Internal)]
+    fn _emit_owner_removed_event(&self, owner: AccountId) {
+        self.env().emit_event(OwnerRemoved { owner })
+    }
+
+    #[overrider(multisig::Internal)]
+    fn _emit_threshold_changed_event(&self, old_threshold: u32, new_threshold: u32) {
+        self.env().emit_event(ThresholdChanged {
+            old_threshold,
+            new_threshold,
+        })
+    }
+
+    #[overrider(multisig::Internal)]
+    fn _emit_transaction_approved_event(&self, id: TransactionId, owner: AccountId) {
+        self.env().emit_event(TransactionApproved { id, owner })
+    }
+
+    #[overrider(multisig::Internal)]
+    fn _emit_approval_revoked_event(&self, id: TransactionId, owner: AccountId) {
+        self.env().emit_event(ApprovalRevoked { id, owner })
+    }
+
+    impl MultisigStruct {
+        #[ink(constructor)]
+        pub fn new(owners: Vec<AccountId>, threshold: u32) -> Self {
+            let mut instance = Self::default();
+            multisig::Internal::_init(&mut instance, owners, threshold).expect("Should init");
+            instance
+        }
+    }
+
+    fn assert_threshold_changed_event(
+        event: &ink::env::test::EmittedEvent,
+        expected_old_threshold: u32,
+        expected_new_threshold: u32,
+    ) {
+        if let Event::ThresholdChanged(ThresholdChanged {
+            old_threshold,
+            new_threshold,
+        }) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(
+                old_threshold, expected_old_threshold,
+                "Old threshold was not equal to expected"
+            );
+            assert_eq!(
+                new_threshold, expected_new_threshold,
+                "New threshold was not equal to expected"
+            );
+        } else {
+            panic!("encountered unexpected event kind: expected a ThresholdChanged event")
+        }
+    }
+
+    fn assert_transaction_approved_event(
+        event: &ink::env::test::EmittedEvent,
+        expected_id: TransactionId,
+        expected_owner: AccountId,
+    ) {
+        if let Event::TransactionApproved(TransactionApproved { id, owner }) =
+            <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(id, expected_id, "Id was not equal to expected");
+            assert_eq!(owner, expected_owner, "Owner was not equal to expected");
+        } else {
+            panic!("encountered unexpected event kind: expected a TransactionApproved event")
+        }
+    }
+
+    fn contract_account() -> AccountId {
+        ink::env::account_id::<DefaultEnvironment>()
+    }
+
+    #[ink::test]
+    fn should_init_owners_and_threshold() {
+        let accounts = accounts();
+        let instance = MultisigStruct::new(vec![accounts.bob, accounts.charlie, accounts.django], 2);
+
+        assert_eq!(
+            Multisig::owners(&instance),
+            vec![accounts.bob, accounts.charlie, accounts.django]
+        );
+        assert!(Multisig::is_owner(&instance, accounts.charlie));
+        assert!(!Multisig::is_owner(&instance, accounts.eve));
+        assert_eq!(Multisig::threshold(&instance), 2);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(emitted_events.len(), 4);
+        assert_threshold_changed_event(&emitted_events[3], 0, 2);
+    }
+
+    #[ink::test]
+    fn init_fails_with_invalid_threshold() {
+        let accounts = accounts();
+        let mut instance = MultisigStruct::default();
+
+        assert_eq!(
+            multisig::Internal::_init(&mut instance, vec![accounts.bob, accounts.charlie], 3),
+            Err(MultisigError::InvalidThreshold)
+        );
+        assert_eq!(
+            multisig::Internal::_init(&mut instance, vec![accounts.django], 0),
+            Err(MultisigError::InvalidThreshold)
+        );
+    }
+
+    #[ink::test]
+    fn propose_approve_and_revoke_works() {
+        let accounts = accounts();
+        let mut instance = MultisigStruct::new(vec![accounts.bob, accounts.charlie], 2);
+
+        change_caller(accounts.bob);
+        let id = Multisig::propose(&mut instance, Transaction::default()).unwrap();
+        assert_eq!(Multisig::transaction(&instance, id), Some(Transaction::default()));
+        assert!(Multisig::has_approved(&instance, id, accounts.bob));
+        assert_eq!(Multisig::approval_count(&instance, id), 1);
+        assert_eq!(
+            Multisig::approve(&mut instance, id),
+            Err(MultisigError::AlreadyApproved)
+        );
+
+        change_caller(accounts.charlie);
+        assert_eq!(Multisig::approve(&mut instance, id), Ok(()));
+        assert_eq!(Multisig::approval_count(&instance, id), 2);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_transaction_approved_event(emitted_events.last().unwrap(), id, accounts.charlie);
+
+        assert_eq!(Multisig::revoke(&mut instance, id), Ok(()));
+        assert_eq!(Multisig::approval_count(&instance, id), 1);
+        assert_eq!(Multisig::revoke(&mut instance, id), Err(MultisigError::NotApproved));
+        assert_eq!(
+            Multisig::approve(&mut instance, id + 1),
+            Err(MultisigError::TransactionNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn only_owners_can_propose_and_approve() {
+        let accounts = accounts();
+        let mut instance = MultisigStruct::new(vec![accounts.bob], 1);
+
+        change_caller(accounts.bob);
+        let id = Multisig::propose(&mut instance, Transaction::default()).unwrap();
+
+        change_caller(accounts.eve);
+        assert_eq!(
+            Multisig::propose(&mut instance, Transaction::default()),
+            Err(MultisigError::CallerIsNotOwner)
+        );
+        assert_eq!(
+            Multisig::approve(&mut instance, id),
+            Err(MultisigError::CallerIsNotOwner)
+        );
+        assert_eq!(
+            Multisig::revoke(&mut instance, id),
+            Err(MultisigError::CallerIsNotOwner)
+        );
+        assert_eq!(
+            Multisig::execute(&mut instance, id),
+            Err(MultisigError::CallerIsNotOwner)
+        );
+    }
+
+    #[ink::test]
+    fn execute_fails_without_enough_approvals() {
+        let accounts = accounts();
+        let mut instance = MultisigStruct::new(vec![accounts.bob, accounts.charlie], 2);
+
+        change_caller(accounts.bob);
+        let id = Multisig::propose(&mut instance, Transaction::default()).unwrap();
+
+        assert_eq!(
+            Multisig::execute(&mut instance, id),
+            Err(MultisigError::NotEnoughApprovals)
+        );
+        assert_eq!(
+            Multisig::execute(&mut instance, id + 1),
+            Err(MultisigError::TransactionNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn owner_management_must_be_called_by_multisig() {
+        let accounts = accounts();
+        let mut instance = MultisigStruct::new(vec![accounts.bob, accounts.charlie], 1);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            Multisig::add_owner(&mut instance, accounts.eve),
+            Err(MultisigError::CallerMustBeMultisig)
+        );
+        assert_eq!(
+            Multisig::remove_owner(&mut instance, accounts.charlie),
+            Err(MultisigError::CallerMustBeMultisig)
+        );
+        assert_eq!(
+            Multisig::change_threshold(&mut instance, 2),
+            Err(MultisigError::CallerMustBeMultisig)
+        );
+
+        change_caller(contract_account());
+        assert_eq!(Multisig::add_owner(&mut instance, accounts.eve), Ok(()));
+        assert_eq!(
+            Multisig::add_owner(&mut instance, accounts.eve),
+            Err(MultisigError::OwnerAlreadyExists)
+        );
+        assert_eq!(Multisig::change_threshold(&mut instance, 3), Ok(()));
+        assert_eq!(Multisig::threshold(&instance), 3);
+        assert_eq!(
+            Multisig::remove_owner(&mut instance, accounts.charlie),
+            Err(MultisigError::InvalidThreshold)
+        );
+        assert_eq!(Multisig::change_threshold(&mut instance, 2), Ok(()));
+        assert_eq!(Multisig::remove_owner(&mut instance, accounts.charlie), Ok(()));
+        assert_eq!(
+            Multisig::remove_owner(&mut instance, accounts.charlie),
+            Err(MultisigError::OwnerNotFound)
+        );
+        assert!(!Multisig::is_owner(&instance, accounts.charlie));
+    }
+
+    #[ink::test]
+    fn approvals_of_removed_owners_are_not_counted() {
+        let accounts = accounts();
+        let mut instance = MultisigStruct::new(vec![accounts.bob, accounts.charlie], 1);
+
+        change_caller(accounts.charlie);
+        let id = Multisig::propose(&mut instance, Transaction::default()).unwrap();
+        assert_eq!(Multisig::approval_count(&instance, id), 1);
+
+        change_caller(contract_account());
+        assert_eq!(Multisig::remove_owner(&mut instance, accounts.charlie), Ok(()));
+        assert_eq!(Multisig::approval_count(&instance, id), 0);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            Multisig::execute(&mut instance, id),
+            Err(MultisigError::NotEnoughApprovals)
+        );
+    }
+}
@@ -75,18 +75,21 @@ mod proxy {
         expected_previous_code_hash: Option<Hash>,
         expected_new_code_hash: Option<Hash>,
     ) {
-        let Event::CodeHashChanged(CodeHashChanged { previous, new }) =
+        if let Event::CodeHashChanged(CodeHashChanged { previous, new }) =
             <Event as scale::Decode>::decode(&mut &event.data[..])
-                .expect("encountered invalid contract event data buffer");
-
-        assert_eq!(
-            previous, expected_previous_code_hash,
-            "Previous code hash was not equal to expected previous code hash."
-        );
-        assert_eq!(
-            new, expected_new_code_hash,
-            "New code hash was not equal to expected new code hash."
-        );
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(
+                previous, expected_previous_code_hash,
+                "Previous code hash was not equal to expected previous code hash."
+            );
+            assert_eq!(
+                new, expected_new_code_hash,
+                "New code hash was not equal to expected new code hash."
+            );
+        } else {
+            panic!("encountered unexpected event kind: expected a CodeHashChanged event")
+        }
     }
 
     #[ink::test]
@@ -95,9 +98,18 @@ mod proxy {
         let instance = MyProxy::new(hash);
 
         let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
-        assert_eq!(1, emitted_events.len());
+        assert_eq!(2, emitted_events.len());
 
-        assert_code_changed_event(&emitted_events[0], None, Some(Proxy::get_delegate_code(&instance)))
+        assert_code_changed_event(&emitted_events[0], None, Some(Proxy::get_delegate_code(&instance)));
+        if let Event::OwnershipTransferred(OwnershipTransferred { previous, new }) =
+            <Event as scale::Decode>::decode(&mut &emitted_events[1].data[..])
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(previous, None);
+            assert_eq!(new, Ownable::owner(&instance));
+        } else {
+            panic!("encountered unexpected event kind: expected a OwnershipTransferred event")
+        }
     }
 
     #[ink::test]
@@ -115,9 +127,10 @@ mod proxy {
         assert!(Proxy::change_delegate_code(&mut my_proxy, new_hash).is_ok());
         assert_eq!(Proxy::get_delegate_code(&my_proxy), new_hash);
         let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
-        assert_eq!(2, emitted_events.len());
+        // the second event is `OwnershipTransferred` emitted by the constructor
+        assert_eq!(3, emitted_events.len());
         assert_code_changed_event(&emitted_events[0], None, Some(hash));
-        assert_code_changed_event(&emitted_events[1], Some(hash), Some(new_hash));
+        assert_code_changed_event(&emitted_events[2], Some(hash), Some(new_hash));
     }
 
     #[ink::test]
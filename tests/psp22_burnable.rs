@@ -58,7 +58,25 @@ mod psp22_burnable {
         return_err_on_after: bool,
     }
 
-    type Event = <PSP22Struct as ::ink::reflect::ContractEventBase>::Type;
+    type ContractEvent = <PSP22Struct as ::ink::reflect::ContractEventBase>::Type;
+
+    /// The events checked by the tests, `transfer_events` skips the default `Approval` event
+    #[derive(scale::Decode)]
+    enum Event {
+        Transfer(Transfer),
+    }
+
+    /// Events emitted on transfers, without the default `Approval` event
+    fn transfer_events() -> Vec<ink::env::test::EmittedEvent> {
+        ink::env::test::recorded_events()
+            .filter(|event| {
+                matches!(
+                    <ContractEvent as scale::Decode>::decode(&mut &event.data[..]),
+                    Ok(ContractEvent::Transfer(_))
+                )
+            })
+            .collect()
+    }
 
     #[overrider(psp22::Internal)]
     fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, amount: Balance) {
@@ -181,7 +199,7 @@ mod psp22_burnable {
 
         assert!(PSP22Burnable::burn(&mut psp22, accounts.alice, amount_to_burn).is_ok());
 
-        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let emitted_events = transfer_events();
         assert_eq!(emitted_events.len(), 2);
         // Check first transfer event related to PSP-20 instantiation.
         assert_transfer_event(
@@ -0,0 +1,110 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[cfg(feature = "reentrancy_guard")]
+#[openbrush::implementation(PSP22, Flashmint, ReentrancyGuard, guard_calls)]
+#[openbrush::contract]
+mod psp22_flashmint_non_reentrant_overrider {
+    use ink::codegen::Env;
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22FlashMintStruct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+        entered_on_flashloan: bool,
+        reenter: bool,
+        reentrant_call_rejected: bool,
+    }
+
+    // the message is already guarded, so `guard_calls` must not add a second guard
+    #[overrider(FlashLender)]
+    #[openbrush::modifiers(non_reentrant)]
+    fn flashloan(
+        &mut self,
+        receiver_account: AccountId,
+        token: AccountId,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), FlashLenderError> {
+        FlashLenderImpl::flashloan(self, receiver_account, token, amount, data)
+    }
+
+    #[overrider(flashmint::Internal)]
+    fn _on_flashloan(
+        &mut self,
+        receiver_account: AccountId,
+        token: AccountId,
+        _fee: Balance,
+        amount: Balance,
+        data: Vec<u8>,
+    ) -> Result<(), FlashLenderError> {
+        self.entered_on_flashloan = ReentrancyGuard::reentrancy_guard_entered(self);
+
+        if self.reenter {
+            self.reentrant_call_rejected = FlashLender::flashloan(self, receiver_account, token, amount, data)
+                == Err(FlashLenderError::ReentrancyGuard(ReentrancyGuardError::ReentrantCall));
+        }
+        Ok(())
+    }
+
+    impl PSP22FlashMintStruct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            assert!(psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).is_ok());
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn overridden_non_reentrant_flashloan_works() {
+        let mut instance = PSP22FlashMintStruct::new(1000);
+
+        let receiver = AccountId::from([0x1; 32]);
+        let token = instance.env().account_id();
+        let fee = flashmint::Internal::_get_fee(&instance, 100);
+
+        assert!(PSP22::approve(&mut instance, token, 100 + fee).is_ok());
+        assert!(FlashLender::flashloan(&mut instance, receiver, token, 100, Vec::<u8>::new()).is_ok());
+        assert!(instance.entered_on_flashloan);
+        assert!(!ReentrancyGuard::reentrancy_guard_entered(&instance));
+    }
+
+    #[ink::test]
+    fn overridden_non_reentrant_flashloan_rejects_reentrant_call() {
+        let mut instance = PSP22FlashMintStruct::new(1000);
+
+        let receiver = AccountId::from([0x1; 32]);
+        let token = instance.env().account_id();
+        let fee = flashmint::Internal::_get_fee(&instance, 100);
+
+        instance.reenter = true;
+        assert!(PSP22::approve(&mut instance, token, 100 + fee).is_ok());
+        assert!(FlashLender::flashloan(&mut instance, receiver, token, 100, Vec::<u8>::new()).is_ok());
+        assert!(instance.reentrant_call_rejected);
+        assert!(!ReentrancyGuard::reentrancy_guard_entered(&instance));
+    }
+}
@@ -57,7 +57,25 @@ mod psp22_mintable {
         return_err_on_after: bool,
     }
 
-    type Event = <PSP22Struct as ::ink::reflect::ContractEventBase>::Type;
+    type ContractEvent = <PSP22Struct as ::ink::reflect::ContractEventBase>::Type;
+
+    /// The events checked by the tests, `transfer_events` skips the default `Approval` event
+    #[derive(scale::Decode)]
+    enum Event {
+        Transfer(Transfer),
+    }
+
+    /// Events emitted on transfers, without the default `Approval` event
+    fn transfer_events() -> Vec<ink::env::test::EmittedEvent> {
+        ink::env::test::recorded_events()
+            .filter(|event| {
+                matches!(
+                    <ContractEvent as scale::Decode>::decode(&mut &event.data[..]),
+                    Ok(ContractEvent::Transfer(_))
+                )
+            })
+            .collect()
+    }
 
     #[overrider(psp22::Internal)]
     fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, amount: Balance) {
@@ -164,7 +182,7 @@ mod psp22_mintable {
 
         assert!(PSP22Mintable::mint(&mut psp22, accounts.bob, amount_to_mint).is_ok());
 
-        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let emitted_events = transfer_events();
         assert_eq!(emitted_events.len(), 2);
         // Check first transfer event related to PSP22 instantiation.
         assert_transfer_event(
@@ -0,0 +1,131 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Snapshot, PSP22Mintable, PSP22Burnable)]
+#[openbrush::contract]
+mod psp22_snapshot {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        snapshot: snapshot::Data,
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+
+            assert!(psp22::Internal::_mint_to(&mut instance, Self::env().caller(), total_supply).is_ok());
+
+            instance
+        }
+
+        #[ink(message)]
+        pub fn snapshot(&mut self) -> SnapshotId {
+            snapshot::Internal::_snapshot(self)
+        }
+    }
+
+    #[ink::test]
+    fn no_snapshot_by_default() {
+        let token = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        assert_eq!(PSP22Snapshot::current_snapshot_id(&token), 0);
+        assert_eq!(
+            PSP22Snapshot::total_supply_at(&token, 0),
+            Err(PSP22Error::Custom(String::from("Snapshot id is 0")))
+        );
+        assert_eq!(
+            PSP22Snapshot::balance_of_at(&token, accounts.alice, 1),
+            Err(PSP22Error::Custom(String::from("Nonexistent snapshot id")))
+        );
+    }
+
+    #[ink::test]
+    fn snapshot_increments_id() {
+        let mut token = PSP22Struct::new(100);
+
+        assert_eq!(token.snapshot(), 1);
+        assert_eq!(token.snapshot(), 2);
+        assert_eq!(PSP22Snapshot::current_snapshot_id(&token), 2);
+    }
+
+    #[ink::test]
+    fn unchanged_values_are_current_values() {
+        let mut token = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        let id = token.snapshot();
+
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.alice, id), Ok(100));
+        assert_eq!(PSP22Snapshot::total_supply_at(&token, id), Ok(100));
+    }
+
+    #[ink::test]
+    fn transfer_keeps_snapshotted_balances() {
+        let mut token = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        let first = token.snapshot();
+        assert!(PSP22::transfer(&mut token, accounts.bob, 10, vec![]).is_ok());
+        let second = token.snapshot();
+        assert!(PSP22::transfer(&mut token, accounts.bob, 20, vec![]).is_ok());
+
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.alice, first), Ok(100));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, first), Ok(0));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.alice, second), Ok(90));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, second), Ok(10));
+        assert_eq!(PSP22::balance_of(&token, accounts.alice), 70);
+        assert_eq!(PSP22Snapshot::total_supply_at(&token, first), Ok(100));
+    }
+
+    #[ink::test]
+    fn mint_and_burn_update_total_supply_snapshots() {
+        let mut token = PSP22Struct::new(100);
+        let accounts = accounts();
+
+        let first = token.snapshot();
+        assert!(PSP22Mintable::mint(&mut token, accounts.bob, 50).is_ok());
+        let second = token.snapshot();
+        assert!(PSP22Burnable::burn(&mut token, accounts.alice, 30).is_ok());
+
+        assert_eq!(PSP22Snapshot::total_supply_at(&token, first), Ok(100));
+        assert_eq!(PSP22Snapshot::total_supply_at(&token, second), Ok(150));
+        assert_eq!(PSP22::total_supply(&token), 120);
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.alice, second), Ok(100));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, first), Ok(0));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, second), Ok(50));
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Vault)]
+#[openbrush::contract]
+mod psp22_vault {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22VaultStruct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        vault: vault::Data,
+        contract_balance: Balance,
+    }
+
+    /// We will override cross-contract vault calls in tests
+    /// The cross-contract interaction will be tested in integration tests
+    #[overrider(vault::Internal)]
+    fn _transfer_asset_in(&mut self, _from: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        self.contract_balance += amount;
+        Ok(())
+    }
+
+    #[overrider(vault::Internal)]
+    fn _transfer_asset_out(&mut self, _to: AccountId, amount: Balance) -> Result<(), PSP22Error> {
+        self.contract_balance -= amount;
+        Ok(())
+    }
+
+    #[overrider(vault::Internal)]
+    fn _total_assets(&self) -> Balance {
+        self.contract_balance
+    }
+
+    impl PSP22VaultStruct {
+        #[ink(constructor)]
+        pub fn new(asset: AccountId) -> Self {
+            let mut instance = Self::default();
+            vault::Internal::_init(&mut instance, asset);
+            instance
+        }
+
+        /// Simulates the yield earned by the vault
+        #[ink(message)]
+        pub fn earn(&mut self, amount: Balance) {
+            self.contract_balance += amount;
+        }
+    }
+
+    #[ink::test]
+    fn initializing_works() {
+        let asset = AccountId::from([0x1; 32]);
+        let vault = PSP22VaultStruct::new(asset);
+
+        assert_eq!(PSP22Vault::asset(&vault), Some(asset));
+        assert_eq!(PSP22Vault::total_assets(&vault), 0);
+        assert_eq!(PSP22Vault::convert_to_shares(&vault, 100), Ok(100));
+    }
+
+    #[ink::test]
+    fn deposit_and_redeem_work() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]));
+
+        assert_eq!(PSP22Vault::deposit(&mut vault, 100, accounts.alice), Ok(100));
+        assert_eq!(PSP22::balance_of(&vault, accounts.alice), 100);
+        assert_eq!(PSP22Vault::total_assets(&vault), 100);
+
+        assert_eq!(PSP22Vault::redeem(&mut vault, 40, accounts.bob, accounts.alice), Ok(40));
+        assert_eq!(PSP22::balance_of(&vault, accounts.alice), 60);
+        assert_eq!(PSP22Vault::total_assets(&vault), 60);
+    }
+
+    #[ink::test]
+    fn rounding_favours_the_vault() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]));
+
+        assert_eq!(PSP22Vault::deposit(&mut vault, 100, accounts.alice), Ok(100));
+        vault.earn(100);
+
+        // 200 assets + 1 virtual asset for 100 shares + 1 virtual share
+        assert_eq!(PSP22Vault::convert_to_assets(&vault, 100), Ok(199));
+        assert_eq!(PSP22Vault::preview_deposit(&vault, 10), Ok(5));
+        assert_eq!(PSP22Vault::preview_mint(&vault, 10), Ok(20));
+        assert_eq!(PSP22Vault::preview_withdraw(&vault, 10), Ok(6));
+        assert_eq!(PSP22Vault::preview_redeem(&vault, 10), Ok(19));
+
+        assert_eq!(PSP22Vault::mint(&mut vault, 10, accounts.bob), Ok(20));
+        assert_eq!(PSP22::balance_of(&vault, accounts.bob), 10);
+        assert_eq!(PSP22Vault::total_assets(&vault), 220);
+    }
+
+    #[ink::test]
+    fn conversion_works_with_large_amounts() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]));
+        // 1_000_000 tokens with 18 decimals
+        let amount: Balance = 1_000_000 * 10u128.pow(18);
+
+        assert_eq!(PSP22Vault::deposit(&mut vault, amount, accounts.alice), Ok(amount));
+        vault.earn(amount);
+
+        // `amount * (2 * amount + 1)` doesn't fit into `u128`
+        assert_eq!(
+            PSP22Vault::convert_to_assets(&vault, amount),
+            Ok(1_999_999_999_999_999_999_999_999)
+        );
+        assert_eq!(
+            PSP22Vault::preview_deposit(&vault, amount),
+            Ok(500_000_000_000_000_000_000_000)
+        );
+        assert_eq!(
+            PSP22Vault::preview_mint(&vault, amount),
+            Ok(2_000_000_000_000_000_000_000_000)
+        );
+        assert_eq!(
+            PSP22Vault::preview_withdraw(&vault, amount),
+            Ok(500_000_000_000_000_000_000_001)
+        );
+        assert_eq!(
+            PSP22Vault::preview_redeem(&vault, amount),
+            Ok(1_999_999_999_999_999_999_999_999)
+        );
+
+        assert_eq!(
+            PSP22Vault::redeem(&mut vault, amount, accounts.alice, accounts.alice),
+            Ok(1_999_999_999_999_999_999_999_999)
+        );
+        assert_eq!(PSP22::balance_of(&vault, accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn conversion_fails_if_result_overflows() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]));
+
+        assert_eq!(PSP22Vault::deposit(&mut vault, 100, accounts.alice), Ok(100));
+        // the vault lost all of its assets, so each asset is worth 101 shares
+        vault.contract_balance = 0;
+
+        assert_eq!(
+            PSP22Vault::convert_to_shares(&vault, Balance::MAX),
+            Err(PSP22Error::Custom(String::from("Overflow")))
+        );
+    }
+
+    #[ink::test]
+    fn withdraw_works() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]));
+
+        assert_eq!(PSP22Vault::deposit(&mut vault, 100, accounts.alice), Ok(100));
+        assert_eq!(PSP22Vault::max_withdraw(&vault, accounts.alice), Ok(100));
+
+        assert_eq!(
+            PSP22Vault::withdraw(&mut vault, 30, accounts.alice, accounts.alice),
+            Ok(30)
+        );
+        assert_eq!(PSP22::balance_of(&vault, accounts.alice), 70);
+        assert_eq!(PSP22Vault::total_assets(&vault), 70);
+    }
+
+    #[ink::test]
+    fn withdraw_more_than_max_fails() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]));
+
+        assert_eq!(PSP22Vault::deposit(&mut vault, 100, accounts.alice), Ok(100));
+
+        assert_eq!(
+            PSP22Vault::withdraw(&mut vault, 101, accounts.alice, accounts.alice),
+            Err(PSP22Error::Custom(String::from("Withdraw more than max")))
+        );
+        assert_eq!(
+            PSP22Vault::redeem(&mut vault, 101, accounts.alice, accounts.alice),
+            Err(PSP22Error::Custom(String::from("Redeem more than max")))
+        );
+    }
+
+    #[ink::test]
+    fn withdraw_from_other_owner_spends_allowance() {
+        let accounts = accounts();
+        let mut vault = PSP22VaultStruct::new(AccountId::from([0x1; 32]));
+
+        assert_eq!(PSP22Vault::deposit(&mut vault, 100, accounts.alice), Ok(100));
+        assert!(PSP22::approve(&mut vault, accounts.bob, 50).is_ok());
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            PSP22Vault::redeem(&mut vault, 60, accounts.bob, accounts.alice),
+            Err(PSP22Error::InsufficientAllowance)
+        );
+        assert_eq!(PSP22Vault::redeem(&mut vault, 50, accounts.bob, accounts.alice), Ok(50));
+        assert_eq!(PSP22::allowance(&vault, accounts.alice, accounts.bob), 0);
+        assert_eq!(PSP22::balance_of(&vault, accounts.alice), 50);
+    }
+}
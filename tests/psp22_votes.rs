@@ -0,0 +1,100 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp22", feature = "governance"))]
+#[openbrush::implementation(PSP22, PSP22Votes, Nonces)]
+#[openbrush::contract]
+mod psp22_votes {
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        votes: votes::Data,
+        #[storage_field]
+        nonces: nonces::Data,
+        mock_timestamp: Timestamp,
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _after_token_transfer(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        VotesInternal::_transfer_voting_units(self, &from.copied(), &to.copied(), *amount)
+            .map_err(|_| PSP22Error::Custom(String::from("Voting units are not transferred")))
+    }
+
+    impl TimestampProvider for Contract {
+        fn block_timestamp(&self) -> Timestamp {
+            self.mock_timestamp
+        }
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[ink::test]
+    fn total_supply_checkpoints_are_stored() {
+        let accounts = accounts();
+        let mut token = Contract::new();
+
+        token.mock_timestamp = 10;
+        assert!(psp22::Internal::_mint_to(&mut token, accounts.alice, 100).is_ok());
+        token.mock_timestamp = 20;
+        assert!(psp22::Internal::_mint_to(&mut token, accounts.bob, 50).is_ok());
+        token.mock_timestamp = 30;
+        assert!(psp22::Internal::_burn_from(&mut token, accounts.alice, 30).is_ok());
+
+        assert_eq!(Votes::get_past_total_supply(&token, 5), Ok(0));
+        assert_eq!(Votes::get_past_total_supply(&token, 10), Ok(100));
+        assert_eq!(Votes::get_past_total_supply(&token, 25), Ok(150));
+        assert_eq!(Votes::get_past_total_supply(&token, 30), Ok(120));
+    }
+
+    #[ink::test]
+    fn delegate_checkpoints_are_stored() {
+        let accounts = accounts();
+        let mut token = Contract::new();
+
+        token.mock_timestamp = 10;
+        assert!(psp22::Internal::_mint_to(&mut token, accounts.alice, 100).is_ok());
+        assert_eq!(Votes::delegate(&mut token, accounts.bob), Ok(()));
+        token.mock_timestamp = 20;
+        assert!(psp22::Internal::_mint_to(&mut token, accounts.alice, 50).is_ok());
+
+        assert_eq!(Votes::get_votes(&token, accounts.bob), 150);
+        assert_eq!(Votes::get_past_votes(&token, accounts.bob, 15), Ok(100));
+        assert_eq!(PSP22Votes::num_checkpoints(&token, accounts.bob), 2);
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34Royalty)]
+#[openbrush::contract]
+mod psp34_royalty {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        royalty: royalty::Data,
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(receiver: AccountId, fee_numerator: u16) -> Self {
+            let mut instance = Self::default();
+            assert!(royalty::Internal::_set_default_royalty(&mut instance, receiver, fee_numerator).is_ok());
+            instance
+        }
+
+        #[ink(message)]
+        pub fn set_token_royalty(&mut self, id: Id, receiver: AccountId, fee_numerator: u16) -> Result<(), PSP34Error> {
+            royalty::Internal::_set_token_royalty(self, id, receiver, fee_numerator)
+        }
+
+        #[ink(message)]
+        pub fn delete_token_royalty(&mut self, id: Id) {
+            royalty::Internal::_delete_token_royalty(self, id)
+        }
+
+        #[ink(message)]
+        pub fn delete_default_royalty(&mut self) {
+            royalty::Internal::_delete_default_royalty(self)
+        }
+    }
+
+    #[ink::test]
+    fn default_royalty_works() {
+        let accounts = accounts();
+        let nft = PSP34Struct::new(accounts.alice, 250);
+
+        assert_eq!(
+            PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000),
+            Some((accounts.alice, 250))
+        );
+        assert_eq!(PSP34Royalty::royalty_info(&nft, Id::U8(2), 999), Some((accounts.alice, 24)));
+    }
+
+    #[ink::test]
+    fn token_royalty_overrides_default() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.alice, 250);
+
+        assert!(nft.set_token_royalty(Id::U8(1), accounts.bob, 1000).is_ok());
+
+        assert_eq!(PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000), Some((accounts.bob, 1000)));
+        assert_eq!(
+            PSP34Royalty::royalty_info(&nft, Id::U8(2), 10_000),
+            Some((accounts.alice, 250))
+        );
+
+        nft.delete_token_royalty(Id::U8(1));
+        assert_eq!(
+            PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000),
+            Some((accounts.alice, 250))
+        );
+    }
+
+    #[ink::test]
+    fn no_royalty_after_deleting_default() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.alice, 250);
+
+        nft.delete_default_royalty();
+        assert_eq!(PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000), None);
+    }
+
+    #[ink::test]
+    fn royalty_fee_exceeding_sale_price_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.alice, 10_000);
+
+        assert_eq!(
+            nft.set_token_royalty(Id::U8(1), accounts.bob, 10_001),
+            Err(PSP34Error::Custom(String::from("Royalty fee will exceed sale price")))
+        );
+        assert_eq!(
+            PSP34Royalty::royalty_info(&nft, Id::U8(1), Balance::MAX),
+            Some((accounts.alice, Balance::MAX))
+        );
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+
+#[cfg(feature = "psp37")]
+#[openbrush::implementation(PSP37, PSP37Capped, PSP37Mintable, PSP37Burnable)]
+#[openbrush::contract]
+mod psp37_capped {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct PSP37Struct {
+        #[storage_field]
+        psp37: psp37::Data,
+        #[storage_field]
+        capped: capped::Data,
+    }
+
+    impl PSP37Struct {
+        #[ink(constructor)]
+        pub fn new(id: Id, max_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            assert!(capped::Internal::_set_max_supply(&mut instance, &id, max_supply).is_ok());
+            instance
+        }
+
+        #[ink(message)]
+        pub fn set_max_supply(&mut self, id: Id, max_supply: Balance) -> Result<(), PSP37Error> {
+            capped::Internal::_set_max_supply(self, &id, max_supply)
+        }
+    }
+
+    const MAX_SUPPLY: Balance = 10;
+
+    #[ink::test]
+    fn exists_works() {
+        let accounts = accounts();
+        let token_1 = Id::U8(1);
+        let mut nft = PSP37Struct::new(token_1.clone(), MAX_SUPPLY);
+
+        assert!(!PSP37Capped::exists(&nft, token_1.clone()));
+        assert!(PSP37Mintable::mint(&mut nft, accounts.alice, vec![(token_1.clone(), 1)]).is_ok());
+        assert!(PSP37Capped::exists(&nft, token_1.clone()));
+        assert!(PSP37Burnable::burn(&mut nft, accounts.alice, vec![(token_1.clone(), 1)]).is_ok());
+        assert!(!PSP37Capped::exists(&nft, token_1));
+    }
+
+    #[ink::test]
+    fn max_supply_works() {
+        let token_1 = Id::U8(1);
+        let token_2 = Id::U8(2);
+        let nft = PSP37Struct::new(token_1.clone(), MAX_SUPPLY);
+
+        assert_eq!(PSP37Capped::max_supply(&nft, token_1), Some(MAX_SUPPLY));
+        assert_eq!(PSP37Capped::max_supply(&nft, token_2), None);
+    }
+
+    #[ink::test]
+    fn mint_above_max_supply_fails() {
+        let accounts = accounts();
+        let token_1 = Id::U8(1);
+        let token_2 = Id::U8(2);
+        let mut nft = PSP37Struct::new(token_1.clone(), MAX_SUPPLY);
+
+        assert!(PSP37Mintable::mint(&mut nft, accounts.alice, vec![(token_1.clone(), MAX_SUPPLY)]).is_ok());
+        assert_eq!(
+            PSP37Mintable::mint(&mut nft, accounts.bob, vec![(token_1.clone(), 1)]),
+            Err(PSP37Error::MaxSupplyExceeded)
+        );
+        assert!(PSP37Mintable::mint(&mut nft, accounts.bob, vec![(token_2, MAX_SUPPLY + 1)]).is_ok());
+        assert_eq!(PSP37::total_supply(&nft, Some(token_1)), MAX_SUPPLY);
+    }
+
+    #[ink::test]
+    fn batch_mint_of_same_id_above_max_supply_fails() {
+        let accounts = accounts();
+        let token_1 = Id::U8(1);
+        let mut nft = PSP37Struct::new(token_1.clone(), MAX_SUPPLY);
+
+        assert_eq!(
+            PSP37Mintable::mint(
+                &mut nft,
+                accounts.alice,
+                vec![(token_1.clone(), MAX_SUPPLY), (token_1.clone(), 1)]
+            ),
+            Err(PSP37Error::MaxSupplyExceeded)
+        );
+        assert!(!PSP37Capped::exists(&nft, token_1));
+    }
+
+    #[ink::test]
+    fn max_supply_below_total_supply_fails() {
+        let accounts = accounts();
+        let token_1 = Id::U8(1);
+        let mut nft = PSP37Struct::new(token_1.clone(), MAX_SUPPLY);
+
+        assert!(PSP37Mintable::mint(&mut nft, accounts.alice, vec![(token_1.clone(), 5)]).is_ok());
+        assert_eq!(
+            nft.set_max_supply(token_1.clone(), 4),
+            Err(PSP37Error::Custom(String::from("Max supply is below total supply")))
+        );
+        assert!(nft.set_max_supply(token_1, 5).is_ok());
+    }
+}
@@ -20,6 +20,7 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 #[cfg(feature = "reentrancy_guard")]
+#[openbrush::implementation(ReentrancyGuard)]
 #[openbrush::contract]
 mod reentrancy_guard {
     use openbrush::{
@@ -55,6 +56,24 @@ mod reentrancy_guard {
         pub fn call_flip_after_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
             self.flip()
         }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant_view)]
+        pub fn get_flipped(&self) -> Result<bool, ReentrancyGuardError> {
+            Ok(self.flipped)
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant)]
+        pub fn call_get_flipped_after_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
+            self.get_flipped()
+        }
+
+        #[ink(message)]
+        #[openbrush::modifiers(non_reentrant)]
+        pub fn entered_after_lock(&mut self) -> Result<bool, ReentrancyGuardError> {
+            Ok(ReentrancyGuard::reentrancy_guard_entered(self))
+        }
     }
 
     #[ink::test]
@@ -75,4 +94,32 @@ mod reentrancy_guard {
             instance.call_flip_after_lock()
         );
     }
+
+    #[ink::test]
+    fn get_flipped_works() {
+        let mut instance = MyFlipper::new();
+
+        assert_eq!(Ok(false), instance.get_flipped());
+        assert_eq!(Ok(false), instance.flip());
+        assert_eq!(Ok(true), instance.get_flipped());
+    }
+
+    #[ink::test]
+    fn call_get_flipped_after_lock_fails() {
+        let mut instance = MyFlipper::new();
+
+        assert_eq!(
+            Err(ReentrancyGuardError::ReentrantCall),
+            instance.call_get_flipped_after_lock()
+        );
+    }
+
+    #[ink::test]
+    fn reentrancy_guard_entered_works() {
+        let mut instance = MyFlipper::new();
+
+        assert!(!ReentrancyGuard::reentrancy_guard_entered(&instance));
+        assert_eq!(Ok(true), instance.entered_after_lock());
+        assert!(!ReentrancyGuard::reentrancy_guard_entered(&instance));
+    }
 }
@@ -149,6 +149,8 @@ mod timelock_controller {
                 "New delays were not equal: encountered delay {:?}, expected delay {:?}",
                 new_delay, expected_new_delay
             );
+        } else {
+            panic!("encountered unexpected event kind: expected a MinDelayChange event")
         }
     }
 
@@ -194,6 +196,8 @@ mod timelock_controller {
                 "Delay were not equal: encountered {:?}, expected {:?}",
                 delay, expected_delay
             );
+        } else {
+            panic!("encountered unexpected event kind: expected a CallScheduled event")
         }
     }
 
@@ -206,9 +210,23 @@ mod timelock_controller {
                 "Ids were not equal: encountered {:?}, expected {:?}",
                 id, expected_id
             );
+        } else {
+            panic!("encountered unexpected event kind: expected a Cancelled event")
         }
     }
 
+    /// Events emitted by the timelock, without the role events emitted by `AccessControl`
+    fn timelock_events() -> Vec<ink::env::test::EmittedEvent> {
+        ink::env::test::recorded_events()
+            .filter(|event| {
+                !matches!(
+                    <Event as scale::Decode>::decode(&mut &event.data[..]),
+                    Ok(Event::RoleAdminChanged(_) | Event::RoleGranted(_) | Event::RoleRevoked(_))
+                )
+            })
+            .collect()
+    }
+
     fn setup() -> DefaultAccounts<DefaultEnvironment> {
         let accounts = accounts();
 
@@ -254,8 +272,33 @@ mod timelock_controller {
         ));
         assert!(!AccessControl::has_role(&timelock, EXECUTOR_ROLE, Some(accounts.bob)));
 
-        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let emitted_events = timelock_events();
+        assert_eq!(emitted_events.len(), 1);
         assert_min_delay_change_event(&emitted_events[0], 0, 10);
+
+        let role_granted_events = ink::env::test::recorded_events()
+            .filter_map(|event| {
+                match <Event as scale::Decode>::decode(&mut &event.data[..]) {
+                    Ok(Event::RoleGranted(RoleGranted { role, grantee, .. })) => Some((role, grantee)),
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        // `AccessControl` grants the default admin role to the admin. The timelock admin role is granted
+        // to the contract itself and to the admin, but both are alice in the off-chain environment,
+        // so the role is granted only once.
+        assert_eq!(timelock.env().account_id(), accounts.alice);
+        assert_eq!(
+            role_granted_events,
+            vec![
+                (access_control::DEFAULT_ADMIN_ROLE, Some(accounts.alice)),
+                (TIMELOCK_ADMIN_ROLE, Some(accounts.alice)),
+                (PROPOSER_ROLE, Some(accounts.bob)),
+                (PROPOSER_ROLE, Some(accounts.charlie)),
+                (EXECUTOR_ROLE, Some(accounts.eve)),
+                (EXECUTOR_ROLE, Some(accounts.charlie)),
+            ]
+        );
     }
 
     #[ink::test]
@@ -274,7 +317,7 @@ mod timelock_controller {
         assert!(TimelockController::is_operation_pending(&mut timelock, id));
         assert_eq!(TimelockController::get_timestamp(&mut timelock, id), min_delay + 1);
 
-        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let emitted_events = timelock_events();
         assert_call_scheduled_event(&emitted_events[1], id, 0, Transaction::default(), None, min_delay + 1);
     }
 
@@ -285,9 +328,7 @@ mod timelock_controller {
         let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![], vec![]);
 
         assert_eq!(
-            Err(TimelockControllerError::AccessControlError(
-                AccessControlError::MissingRole
-            )),
+            Err(TimelockControllerError::AccessControl(AccessControlError::MissingRole)),
             TimelockController::schedule(&mut timelock, Transaction::default(), None, [0; 32], min_delay + 1)
         );
     }
@@ -337,7 +378,7 @@ mod timelock_controller {
         assert!(TimelockController::is_operation_pending(&mut timelock, id));
         assert_eq!(TimelockController::get_timestamp(&mut timelock, id), min_delay + 1);
 
-        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let emitted_events = timelock_events();
 
         assert_eq!(emitted_events.len(), 3);
         for (i, transaction) in transactions.into_iter().enumerate() {
@@ -353,9 +394,7 @@ mod timelock_controller {
         let transactions = vec![Transaction::default(), Transaction::default()];
 
         assert_eq!(
-            Err(TimelockControllerError::AccessControlError(
-                AccessControlError::MissingRole
-            )),
+            Err(TimelockControllerError::AccessControl(AccessControlError::MissingRole)),
             TimelockController::schedule_batch(&mut timelock, transactions.clone(), None, [0; 32], min_delay + 1)
         );
     }
@@ -372,7 +411,7 @@ mod timelock_controller {
         );
         assert!(TimelockController::cancel(&mut timelock, id).is_ok());
 
-        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let emitted_events = timelock_events();
         assert_call_scheduled_event(&emitted_events[1], id, 0, Transaction::default(), None, min_delay + 1);
         assert_cancelled_event(&emitted_events[2], id);
     }
@@ -388,14 +427,12 @@ mod timelock_controller {
             TimelockController::schedule(&mut timelock, Transaction::default(), None, [0; 32], min_delay + 1).is_ok()
         );
 
-        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        let emitted_events = timelock_events();
         assert_call_scheduled_event(&emitted_events[1], id, 0, Transaction::default(), None, min_delay + 1);
 
         assert!(AccessControl::revoke_role(&mut timelock, PROPOSER_ROLE, Some(accounts.alice)).is_ok());
         assert_eq!(
-            Err(TimelockControllerError::AccessControlError(
-                AccessControlError::MissingRole
-            )),
+            Err(TimelockControllerError::AccessControl(AccessControlError::MissingRole)),
             TimelockController::cancel(&mut timelock, id)
         );
     }
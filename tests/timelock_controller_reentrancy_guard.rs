@@ -0,0 +1,108 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "timelock_controller")]
+#[cfg(feature = "reentrancy_guard")]
+#[openbrush::implementation(TimelockController, AccessControl, ReentrancyGuard, guard_calls)]
+#[openbrush::contract]
+mod timelock_controller_reentrancy_guard {
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct TimelockControllerStruct {
+        #[storage_field]
+        access_control: access_control::Data,
+        #[storage_field]
+        timelock: timelock_controller::Data,
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+        entered_on_call: bool,
+        reentrant_call_rejected: bool,
+    }
+
+    // we remove cross contract call in test, and execute the operation again from the call,
+    // like a malicious callee would do
+    #[overrider(timelock_controller::Internal)]
+    fn _call(&mut self, _id: OperationId, _i: u8, transaction: Transaction) -> Result<(), TimelockControllerError> {
+        self.entered_on_call = ReentrancyGuard::reentrancy_guard_entered(self);
+
+        let reentrant_call_results = [
+            TimelockController::execute(self, transaction.clone(), None, [0; 32]),
+            TimelockController::execute_batch(self, vec![transaction], None, [0; 32]),
+        ];
+        self.reentrant_call_rejected = reentrant_call_results.iter().all(|result| {
+            *result
+                == Err(TimelockControllerError::ReentrancyGuard(
+                    ReentrancyGuardError::ReentrantCall,
+                ))
+        });
+        Ok(())
+    }
+
+    impl TimelockControllerStruct {
+        #[ink(constructor)]
+        pub fn new(admin: AccountId) -> Self {
+            let mut instance = Self::default();
+            access_control::Internal::_init_with_admin(&mut instance, Some(admin));
+            timelock_controller::Internal::_init_with_admin(&mut instance, Some(admin), 0, vec![admin], vec![admin]);
+            instance
+        }
+    }
+
+    fn transaction() -> Transaction {
+        Transaction {
+            callee: Some(accounts().bob),
+            ..Default::default()
+        }
+    }
+
+    #[ink::test]
+    fn execute_is_non_reentrant() {
+        let accounts = accounts();
+        let mut instance = TimelockControllerStruct::new(accounts.alice);
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10);
+        assert!(TimelockController::schedule(&mut instance, transaction(), None, [0; 32], 0).is_ok());
+        assert!(TimelockController::execute(&mut instance, transaction(), None, [0; 32]).is_ok());
+
+        assert!(instance.entered_on_call);
+        assert!(instance.reentrant_call_rejected);
+        assert!(!ReentrancyGuard::reentrancy_guard_entered(&instance));
+    }
+
+    #[ink::test]
+    fn execute_batch_is_non_reentrant() {
+        let accounts = accounts();
+        let mut instance = TimelockControllerStruct::new(accounts.alice);
+
+        ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10);
+        assert!(TimelockController::schedule_batch(&mut instance, vec![transaction()], None, [0; 32], 0).is_ok());
+        assert!(TimelockController::execute_batch(&mut instance, vec![transaction()], None, [0; 32]).is_ok());
+
+        assert!(instance.entered_on_call);
+        assert!(instance.reentrant_call_rejected);
+        assert!(!ReentrancyGuard::reentrancy_guard_entered(&instance));
+    }
+}
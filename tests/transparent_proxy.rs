@@ -0,0 +1,228 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "proxy")]
+#[openbrush::implementation(TransparentProxy)]
+#[openbrush::contract]
+mod transparent_proxy {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct AdminChanged {
+        previous: Option<AccountId>,
+        new: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct DelegateCodeChanged {
+        #[ink(topic)]
+        previous: Option<Hash>,
+        #[ink(topic)]
+        new: Option<Hash>,
+    }
+
+    const CODE_HASH_0: [u8; 32] = [0u8; 32];
+    const CODE_HASH_1: [u8; 32] = [1u8; 32];
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MyProxy {
+        #[storage_field]
+        proxy: transparent_proxy::Data,
+    }
+
+    type Event = <MyProxy as ::ink::reflect::ContractEventBase>::Type;
+
+    impl MyProxy {
+        #[ink(constructor)]
+        pub fn new(forward_to: Hash) -> Self {
+            let mut inst = Self::default();
+            transparent_proxy::Internal::_init_with_admin(&mut inst, Self::env().caller(), forward_to);
+            inst
+        }
+
+        #[ink(message, payable, selector = _)]
+        pub fn forward(&self) {
+            transparent_proxy::Internal::_fallback(self)
+        }
+    }
+
+    #[overrider(transparent_proxy::Internal)]
+    fn _emit_admin_changed_event(&self, previous: Option<AccountId>, new: Option<AccountId>) {
+        self.env().emit_event(AdminChanged { previous, new })
+    }
+
+    #[overrider(transparent_proxy::Internal)]
+    fn _emit_delegate_code_changed_event(&self, previous: Option<Hash>, new: Option<Hash>) {
+        self.env().emit_event(DelegateCodeChanged { previous, new })
+    }
+
+    // we remove the delegate calls in tests, but still check that the admin can't fallback
+    #[overrider(transparent_proxy::Internal)]
+    fn _fallback(&self) -> ! {
+        if self.proxy.admin.get_or_default() == Some(self.env().caller()) {
+            transparent_proxy::InternalImpl::_fallback(self)
+        }
+        panic!("forwarded to the implementation")
+    }
+
+    #[overrider(transparent_proxy::Internal)]
+    fn _init_call(&self, call: InitCall) -> ! {
+        assert_eq!(self.proxy.forward_to.get_or_default(), call.hash);
+        panic!("init call with selector {:?}", call.selector)
+    }
+
+    fn assert_admin_changed_event(
+        event: &ink::env::test::EmittedEvent,
+        expected_previous: Option<AccountId>,
+        expected_new: Option<AccountId>,
+    ) {
+        if let Event::AdminChanged(AdminChanged { previous, new }) =
+            <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(previous, expected_previous, "Previous admin was not equal to expected.");
+            assert_eq!(new, expected_new, "New admin was not equal to expected.");
+        } else {
+            panic!("encountered unexpected event kind: expected a AdminChanged event")
+        }
+    }
+
+    fn assert_code_changed_event(
+        event: &ink::env::test::EmittedEvent,
+        expected_previous_code_hash: Option<Hash>,
+        expected_new_code_hash: Option<Hash>,
+    ) {
+        if let Event::DelegateCodeChanged(DelegateCodeChanged { previous, new }) =
+            <Event as scale::Decode>::decode(&mut &event.data[..])
+                .expect("encountered invalid contract event data buffer")
+        {
+            assert_eq!(
+                previous, expected_previous_code_hash,
+                "Previous code hash was not equal to expected previous code hash."
+            );
+            assert_eq!(
+                new, expected_new_code_hash,
+                "New code hash was not equal to expected new code hash."
+            );
+        } else {
+            panic!("encountered unexpected event kind: expected a DelegateCodeChanged event")
+        }
+    }
+
+    #[ink::test]
+    fn constructor_works() {
+        let hash = Hash::from(CODE_HASH_0);
+        let instance = MyProxy::new(hash);
+
+        assert_eq!(TransparentProxy::proxy_admin(&instance), Some(accounts().alice));
+        assert_eq!(TransparentProxy::proxy_implementation(&instance), hash);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(2, emitted_events.len());
+        assert_admin_changed_event(&emitted_events[0], None, Some(accounts().alice));
+        assert_code_changed_event(&emitted_events[1], None, Some(hash));
+    }
+
+    #[ink::test]
+    fn upgrade_to_works() {
+        let hash = Hash::from(CODE_HASH_0);
+        let new_hash = Hash::from(CODE_HASH_1);
+        let mut instance = MyProxy::new(hash);
+
+        assert!(TransparentProxy::upgrade_to(&mut instance, new_hash).is_ok());
+        assert_eq!(TransparentProxy::proxy_implementation(&instance), new_hash);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_code_changed_event(&emitted_events[2], Some(hash), Some(new_hash));
+    }
+
+    #[ink::test]
+    fn change_proxy_admin_works() {
+        let accounts = accounts();
+        let mut instance = MyProxy::new(Hash::from(CODE_HASH_0));
+
+        assert!(TransparentProxy::change_proxy_admin(&mut instance, accounts.bob).is_ok());
+        assert_eq!(instance.proxy.admin.get_or_default(), Some(accounts.bob));
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_admin_changed_event(&emitted_events[2], Some(accounts.alice), Some(accounts.bob));
+    }
+
+    #[ink::test]
+    fn change_proxy_admin_to_zero_fails() {
+        let mut instance = MyProxy::new(Hash::from(CODE_HASH_0));
+
+        assert_eq!(
+            TransparentProxy::change_proxy_admin(&mut instance, AccountId::from([0u8; 32])),
+            Err(ProxyError::NewAdminIsZero)
+        );
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "the admin can't fallback to the implementation of the proxy")]
+    fn admin_fallback_fails() {
+        let instance = MyProxy::new(Hash::from(CODE_HASH_0));
+
+        instance.forward();
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "forwarded to the implementation")]
+    fn not_admin_fallback_works() {
+        let instance = MyProxy::new(Hash::from(CODE_HASH_0));
+
+        change_caller(accounts().bob);
+        instance.forward();
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "forwarded to the implementation")]
+    fn not_admin_call_is_forwarded() {
+        let mut instance = MyProxy::new(Hash::from(CODE_HASH_0));
+
+        change_caller(accounts().bob);
+        let _ = TransparentProxy::upgrade_to(&mut instance, Hash::from(CODE_HASH_1));
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "init call with selector [1, 2, 3, 4]")]
+    fn upgrade_to_and_call_works() {
+        let mut instance = MyProxy::new(Hash::from(CODE_HASH_0));
+        let init = InitCall {
+            hash: Hash::from(CODE_HASH_1),
+            selector: [1, 2, 3, 4],
+            input: vec![],
+        };
+
+        let _ = TransparentProxy::upgrade_to_and_call(&mut instance, Hash::from(CODE_HASH_1), init);
+    }
+}
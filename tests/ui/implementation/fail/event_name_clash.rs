@@ -0,0 +1,23 @@
+#[openbrush::implementation(PSP22, PSP34)]
+#[openbrush::contract]
+mod event_name_clash {
+    use openbrush::traits::Storage;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        psp34: psp34::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+}
+
+fn main() {}
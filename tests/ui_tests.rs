@@ -8,6 +8,8 @@ fn ui_tests() {
     t.pass("tests/ui/contract/pass/*.rs");
     t.compile_fail("tests/ui/contract/fail/*.rs");
 
+    t.compile_fail("tests/ui/implementation/fail/*.rs");
+
     t.pass("tests/ui/modifier_definition/pass/*.rs");
     t.compile_fail("tests/ui/modifier_definition/fail/*.rs");
 
@@ -0,0 +1,82 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::contract]
+mod wrapper {
+    use ink::env::CallFlags;
+    use openbrush::{
+        contracts::psp22::*,
+        test_utils::accounts,
+        traits::CallOptions,
+    };
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct Caller {}
+
+    impl Caller {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn transfer(&mut self, token: AccountId, to: AccountId, value: Balance) -> Result<(), PSP22Error> {
+            PSP22Ref::try_transfer(&token, to, value, Vec::new())??
+        }
+    }
+
+    #[ink::test]
+    fn call_options_are_applied() {
+        let accounts = accounts();
+        let options = CallOptions::new()
+            .gas_limit(1_000)
+            .transferred_value(10)
+            .call_flags(CallFlags::default().set_allow_reentry(true));
+
+        let params = options
+            .apply(PSP22Ref::transfer_builder(
+                &accounts.bob,
+                accounts.charlie,
+                100,
+                Vec::new(),
+            ))
+            .params();
+
+        assert_eq!(params.gas_limit(), 1_000);
+        assert_eq!(*params.transferred_value(), 10);
+        assert!(params.call_flags().allow_reentry());
+    }
+
+    #[ink::test]
+    fn empty_call_options_keep_builder_defaults() {
+        let accounts = accounts();
+
+        let params = CallOptions::new()
+            .apply(PSP22Ref::balance_of_builder(&accounts.bob, accounts.charlie))
+            .params();
+
+        assert_eq!(params.gas_limit(), 0);
+        assert_eq!(*params.transferred_value(), 0);
+        assert!(!params.call_flags().allow_reentry());
+    }
+}